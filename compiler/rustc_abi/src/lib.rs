@@ -894,10 +894,15 @@ pub fn from_size(size: Size) -> Result<Self, String> {
 pub enum Field {
     Bls12381Base,
     Bls12381Scalar,
+    Bn254Base,
+    Bn254Scalar,
+    Secp256k1Base,
+    Secp256k1Scalar,
     Curve25519Base,
     Curve25519Scalar,
     PallasBase,
     PallasScalar,
+    Bls12381Gt,
 }
 
 impl Field {
@@ -906,10 +911,15 @@ pub fn size(self) -> Size {
         match self {
             Field::Bls12381Base => Size::from_bytes(48),
             Field::Bls12381Scalar => Size::from_bytes(32),
+            Field::Bn254Base => Size::from_bytes(32),
+            Field::Bn254Scalar => Size::from_bytes(32),
+            Field::Secp256k1Base => Size::from_bytes(32),
+            Field::Secp256k1Scalar => Size::from_bytes(32),
             Field::Curve25519Base => Size::from_bytes(32),
             Field::Curve25519Scalar => Size::from_bytes(32),
             Field::PallasBase => Size::from_bytes(32),
             Field::PallasScalar => Size::from_bytes(32),
+            Field::Bls12381Gt => Field::Bls12381Base.size() * 12,
         }
     }
 
@@ -920,13 +930,25 @@ pub fn real_bits(self) -> u64 {
         match self {
             Field::Bls12381Base => 381,
             Field::Bls12381Scalar => 255,
+            Field::Bn254Base => 254,
+            Field::Bn254Scalar => 254,
+            Field::Secp256k1Base => 256,
+            Field::Secp256k1Scalar => 256,
             Field::Curve25519Base => 255,
             Field::Curve25519Scalar => 253,
             Field::PallasBase => 255,
             Field::PallasScalar => 255,
+            Field::Bls12381Gt => Field::Bls12381Base.real_bits() * 12,
         }
     }
 
+    /// Return `true` for extension fields. Their values are laid out as consecutive
+    /// coefficients from the prime subfield and have no immediate representation.
+    #[inline]
+    pub fn is_extension(self) -> bool {
+        matches!(self, Field::Bls12381Gt)
+    }
+
     pub fn align() -> AbiAndPrefAlign {
         AbiAndPrefAlign::new(Align::from_bytes(0).unwrap())
     }
@@ -936,32 +958,50 @@ pub fn align() -> AbiAndPrefAlign {
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, HashStable_Generic)]
 pub enum Curve {
     Bls12381,
+    Bn254,
+    Secp256k1,
     Curve25519,
     Pallas,
     Vesta,
+    Bls12381G2,
 }
 
 impl Curve {
+    /// Return the field of curve point coordinates.
+    /// Curve values are laid out as two consecutive coordinates: `x` and then `y`.
+    /// Each coordinate takes `self.degree()` values of this field.
     #[inline]
-    pub fn size(self) -> Size {
+    pub fn base_field(self) -> Field {
         match self {
-            Curve::Bls12381 => Field::Bls12381Base.size() * 2,
-            Curve::Curve25519 => Field::Curve25519Base.size() * 2,
-            Curve::Pallas => Field::PallasBase.size() * 2,
-            Curve::Vesta => Field::PallasScalar.size() * 2,
+            Curve::Bls12381 => Field::Bls12381Base,
+            Curve::Bn254 => Field::Bn254Base,
+            Curve::Secp256k1 => Field::Secp256k1Base,
+            Curve::Curve25519 => Field::Curve25519Base,
+            Curve::Pallas => Field::PallasBase,
+            Curve::Vesta => Field::PallasScalar,
+            Curve::Bls12381G2 => Field::Bls12381Base,
         }
     }
 
+    /// Return the degree of the field of point coordinates over `self.base_field()`.
+    #[inline]
+    pub fn degree(self) -> u64 {
+        match self {
+            Curve::Bls12381G2 => 2,
+            _ => 1,
+        }
+    }
+
+    #[inline]
+    pub fn size(self) -> Size {
+        self.base_field().size() * 2 * self.degree()
+    }
+
     /// Return real size of field values.
     /// This should not be confused with `self.size().bits()`, which will return
     /// rounded bit size. This size is not byte-aligned.
     pub fn real_bits(self) -> u64 {
-        match self {
-            Curve::Bls12381 => Field::Bls12381Base.real_bits() * 2,
-            Curve::Curve25519 => Field::Curve25519Base.real_bits() * 2,
-            Curve::Pallas => Field::PallasBase.real_bits() * 2,
-            Curve::Vesta => Field::PallasScalar.real_bits() * 2,
-        }
+        self.base_field().real_bits() * 2 * self.degree()
     }
 
     pub fn align() -> AbiAndPrefAlign {
@@ -1911,27 +1911,36 @@ pub struct FnSig {
 #[derive(Encodable, Decodable, HashStable_Generic)]
 pub enum CurveTy {
     Bls12381,
+    Bn254,
+    Secp256k1,
     Curve25519,
     Pallas,
     Vesta,
+    Bls12381G2,
 }
 
 impl CurveTy {
     pub fn name_str(self) -> &'static str {
         match self {
             CurveTy::Bls12381 => "__zkllvm_curve_bls12381",
+            CurveTy::Bn254 => "__zkllvm_curve_bn254",
+            CurveTy::Secp256k1 => "__zkllvm_curve_secp256k1",
             CurveTy::Curve25519 => "__zkllvm_curve_curve25519",
             CurveTy::Pallas => "__zkllvm_curve_pallas",
             CurveTy::Vesta => "__zkllvm_curve_vesta",
+            CurveTy::Bls12381G2 => "__zkllvm_curve_bls12381_g2",
         }
     }
 
     pub fn name(self) -> Symbol {
         match self {
             CurveTy::Bls12381 => sym::__zkllvm_curve_bls12381,
+            CurveTy::Bn254 => sym::__zkllvm_curve_bn254,
+            CurveTy::Secp256k1 => sym::__zkllvm_curve_secp256k1,
             CurveTy::Curve25519 => sym::__zkllvm_curve_curve25519,
             CurveTy::Pallas => sym::__zkllvm_curve_pallas,
             CurveTy::Vesta => sym::__zkllvm_curve_vesta,
+            CurveTy::Bls12381G2 => sym::__zkllvm_curve_bls12381_g2,
         }
     }
 }
@@ -1942,10 +1951,15 @@ pub fn name(self) -> Symbol {
 pub enum FieldTy {
     Bls12381Base,
     Bls12381Scalar,
+    Bn254Base,
+    Bn254Scalar,
+    Secp256k1Base,
+    Secp256k1Scalar,
     Curve25519Base,
     Curve25519Scalar,
     PallasBase,
     PallasScalar,
+    Bls12381Gt,
 }
 
 impl FieldTy {
@@ -1953,10 +1967,15 @@ pub fn name_str(self) -> &'static str {
         match self {
             FieldTy::Bls12381Base => "__zkllvm_field_bls12381_base",
             FieldTy::Bls12381Scalar => "__zkllvm_field_bls12381_scalar",
+            FieldTy::Bn254Base => "__zkllvm_field_bn254_base",
+            FieldTy::Bn254Scalar => "__zkllvm_field_bn254_scalar",
+            FieldTy::Secp256k1Base => "__zkllvm_field_secp256k1_base",
+            FieldTy::Secp256k1Scalar => "__zkllvm_field_secp256k1_scalar",
             FieldTy::Curve25519Base => "__zkllvm_field_curve25519_base",
             FieldTy::Curve25519Scalar => "__zkllvm_field_curve25519_scalar",
             FieldTy::PallasBase => "__zkllvm_field_pallas_base",
             FieldTy::PallasScalar => "__zkllvm_field_pallas_scalar",
+            FieldTy::Bls12381Gt => "__zkllvm_field_bls12381_gt",
         }
     }
 
@@ -1964,10 +1983,15 @@ pub fn name(self) -> Symbol {
         match self {
             FieldTy::Bls12381Base => sym::__zkllvm_field_bls12381_base,
             FieldTy::Bls12381Scalar => sym::__zkllvm_field_bls12381_scalar,
+            FieldTy::Bn254Base => sym::__zkllvm_field_bn254_base,
+            FieldTy::Bn254Scalar => sym::__zkllvm_field_bn254_scalar,
+            FieldTy::Secp256k1Base => sym::__zkllvm_field_secp256k1_base,
+            FieldTy::Secp256k1Scalar => sym::__zkllvm_field_secp256k1_scalar,
             FieldTy::Curve25519Base => sym::__zkllvm_field_curve25519_base,
             FieldTy::Curve25519Scalar => sym::__zkllvm_field_curve25519_scalar,
             FieldTy::PallasBase => sym::__zkllvm_field_pallas_base,
             FieldTy::PallasScalar => sym::__zkllvm_field_pallas_scalar,
+            FieldTy::Bls12381Gt => sym::__zkllvm_field_bls12381_gt,
         }
     }
 }
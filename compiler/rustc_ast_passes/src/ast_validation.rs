@@ -318,6 +318,8 @@ fn check_decl_attrs(&self, fn_decl: &FnDecl) {
                     sym::deny,
                     sym::expect,
                     sym::forbid,
+                    sym::private_input,
+                    sym::public_input,
                     sym::warn,
                 ];
                 !arr.contains(&attr.name_or_empty()) && rustc_attr::is_builtin_attr(attr)
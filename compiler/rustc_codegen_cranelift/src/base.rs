@@ -716,6 +716,12 @@ fn is_fat_ptr<'tcx>(fx: &FunctionCx<'_, '_, 'tcx>, ty: Ty<'tcx>) -> bool {
                     let operand = codegen_operand(fx, operand);
                     lval.write_cvalue_transmute(fx, operand);
                 }
+                Rvalue::Cast(CastKind::IntToField, _, _) => {
+                    fx.tcx.sess.span_fatal(
+                        stmt.source_info.span,
+                        "cranelift doesn't support field types.",
+                    );
+                }
                 Rvalue::Discriminant(place) => {
                     let place = codegen_place(fx, place);
                     let value = place.to_cvalue(fx);
@@ -64,6 +64,13 @@ fn apply_attrs_to_callsite(
         ArgExtension::Zext => attrs.push(llvm::AttributeKind::ZExt.create_attr(cx.llcx)),
         ArgExtension::Sext => attrs.push(llvm::AttributeKind::SExt.create_attr(cx.llcx)),
     }
+    // Circuit input kinds are read by the assigner, LLVM itself ignores them.
+    if regular.contains(ArgAttribute::PublicInput) {
+        attrs.push(llvm::CreateAttrString(cx.llcx, "public_input"));
+    }
+    if regular.contains(ArgAttribute::PrivateInput) {
+        attrs.push(llvm::CreateAttrString(cx.llcx, "private_input"));
+    }
 
     // Only apply remaining attributes when optimizing
     if cx.sess().opts.optimize != config::OptLevel::No {
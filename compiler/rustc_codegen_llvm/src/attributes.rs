@@ -4,7 +4,7 @@
 use rustc_hir::def_id::DefId;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::ty::{self, TyCtxt};
-use rustc_session::config::OptLevel;
+use rustc_session::config::{CrateType, OptLevel};
 use rustc_span::symbol::sym;
 use rustc_target::spec::abi::Abi;
 use rustc_target::spec::{FramePointer, SanitizerSet, StackProbeType, StackProtector};
@@ -341,7 +341,13 @@ pub fn from_fn_attrs<'ll, 'tcx>(
     if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::NO_BUILTINS) {
         to_add.push(llvm::CreateAttrString(cx.llcx, "no-builtins"));
     }
-    if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::CIRCUIT) {
+    // The assigner expects a single circuit. A library marks all of its circuits, while an
+    // executable marks only its entry point: the circuits of its dependencies are linked in
+    // only if it picks one of them, see `maybe_create_entry_wrapper`.
+    if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::CIRCUIT)
+        && (!cx.tcx.crate_types().contains(&CrateType::Executable)
+            || cx.tcx.entry_fn(()).is_some_and(|(def_id, _)| def_id == instance.def_id()))
+    {
         to_add.push(AttributeKind::Circuit.create_attr(cx.llcx));
     }
     if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::COLD) {
@@ -847,6 +847,29 @@ fn intcast(&mut self, val: &'ll Value, dest_ty: &'ll Type, is_signed: bool) -> &
         }
     }
 
+    fn int_to_field(&mut self, val: &'ll Value, field_ty: Ty<'tcx>) -> &'ll Value {
+        if !self.sess().target.is_like_assigner {
+            // Fields are lowered to integers wider than any integer type.
+            let llty = self.layout_of(field_ty).immediate_llvm_type(self);
+            return self.zext(val, llty);
+        }
+        let val = self.intcast(val, self.type_i128(), false);
+        self.call_intrinsic(&format!("llvm.assigner.int.to.field.{field_ty}"), &[val])
+    }
+
+    fn field_to_int(
+        &mut self,
+        val: &'ll Value,
+        field_ty: Ty<'tcx>,
+        dest_ty: &'ll Type,
+    ) -> &'ll Value {
+        if !self.sess().target.is_like_assigner {
+            return self.trunc(val, dest_ty);
+        }
+        let val = self.call_intrinsic(&format!("llvm.assigner.field.to.int.{field_ty}"), &[val]);
+        self.intcast(val, dest_ty, false)
+    }
+
     fn pointercast(&mut self, val: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
         unsafe { llvm::LLVMBuildPointerCast(self.llbuilder, val, dest_ty, UNNAMED) }
     }
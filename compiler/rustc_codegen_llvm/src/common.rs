@@ -303,6 +303,12 @@ fn scalar_to_backend(&self, cv: Scalar, layout: abi::Scalar, llty: &'ll Type) ->
 
     fn field_to_backend(&self, sf: ScalarField, layout: abi::Field, llty: &'ll Type) -> &'ll Value {
         trace!("field_to_backend llty: {:?}", llty);
+        if !self.tcx.sess.target.is_like_assigner {
+            // Host targets store fields as plain integers of the field size.
+            return unsafe {
+                llvm::LLVMConstIntOfArbitraryPrecision(llty, 6, sf.words().as_ptr())
+            };
+        }
         unsafe {
             let llap_int = llvm::LLVMConstIntOfArbitraryPrecision(
                 self.type_ix(layout.real_bits()), // we need real bit size here
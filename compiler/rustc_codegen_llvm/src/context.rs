@@ -687,16 +687,25 @@ macro_rules! mk_struct {
         let t_token = self.type_token();
 
         let t_field_bls12381_base = self.type_field_bls12381_base();
+        let t_field_bls12381_scalar = self.type_field_bls12381_scalar();
+        let t_field_bn254_base = self.type_field_bn254_base();
+        let t_field_bn254_scalar = self.type_field_bn254_scalar();
+        let t_field_secp256k1_base = self.type_field_secp256k1_base();
+        let t_field_secp256k1_scalar = self.type_field_secp256k1_scalar();
         let t_field_curve25519_base = self.type_field_curve25519_base();
         let t_field_curve25519_scalar = self.type_field_curve25519_scalar();
         let t_field_pallas_base = self.type_field_pallas_base();
         let t_field_pallas_scalar = self.type_field_pallas_scalar();
         let t_curve_bls12381 = self.type_curve_bls12381();
+        let t_curve_bn254 = self.type_curve_bn254();
+        let t_curve_secp256k1 = self.type_curve_secp256k1();
         let t_curve_curve25519 = self.type_curve_curve25519();
         let t_curve_pallas = self.type_curve_pallas();
         let t_curve_vesta = self.type_curve_vesta();
         let t_fpb_v2 = self.type_vector(self.type_field_pallas_base(), 2);
+        let t_fpb_v3 = self.type_vector(self.type_field_pallas_base(), 3);
         let t_fpb_v4 = self.type_vector(self.type_field_pallas_base(), 4);
+        let t_fblss_v3 = self.type_vector(self.type_field_bls12381_scalar(), 3);
         let t_fblsb_v12 = self.type_vector(self.type_field_bls12381_base(), 12);
         let t_fblsb_v4 = self.type_vector(self.type_field_bls12381_base(), 4);
 
@@ -916,24 +925,80 @@ macro_rules! mk_struct {
 
         ifn!("llvm.assigner.exit.check", fn(i1) -> void);
         ifn!("llvm.assigner.curve.init.__zkllvm_curve_bls12381", fn(t_field_bls12381_base, t_field_bls12381_base) -> t_curve_bls12381);
+        ifn!("llvm.assigner.curve.init.__zkllvm_curve_bn254", fn(t_field_bn254_base, t_field_bn254_base) -> t_curve_bn254);
+        ifn!("llvm.assigner.curve.init.__zkllvm_curve_secp256k1", fn(t_field_secp256k1_base, t_field_secp256k1_base) -> t_curve_secp256k1);
         ifn!("llvm.assigner.curve.init.__zkllvm_curve_curve25519", fn(t_field_curve25519_base, t_field_curve25519_base) -> t_curve_curve25519);
         ifn!("llvm.assigner.curve.init.__zkllvm_curve_pallas", fn(t_field_pallas_base, t_field_pallas_base) -> t_curve_pallas);
         ifn!("llvm.assigner.curve.init.__zkllvm_curve_vesta", fn(t_field_pallas_scalar, t_field_pallas_scalar) -> t_curve_vesta);
+        ifn!("llvm.assigner.curve.x.__zkllvm_curve_bls12381", fn(t_curve_bls12381) -> t_field_bls12381_base);
+        ifn!("llvm.assigner.curve.y.__zkllvm_curve_bls12381", fn(t_curve_bls12381) -> t_field_bls12381_base);
+        ifn!("llvm.assigner.curve.x.__zkllvm_curve_bn254", fn(t_curve_bn254) -> t_field_bn254_base);
+        ifn!("llvm.assigner.curve.y.__zkllvm_curve_bn254", fn(t_curve_bn254) -> t_field_bn254_base);
+        ifn!("llvm.assigner.curve.x.__zkllvm_curve_secp256k1", fn(t_curve_secp256k1) -> t_field_secp256k1_base);
+        ifn!("llvm.assigner.curve.y.__zkllvm_curve_secp256k1", fn(t_curve_secp256k1) -> t_field_secp256k1_base);
+        ifn!("llvm.assigner.curve.x.__zkllvm_curve_curve25519", fn(t_curve_curve25519) -> t_field_curve25519_base);
+        ifn!("llvm.assigner.curve.y.__zkllvm_curve_curve25519", fn(t_curve_curve25519) -> t_field_curve25519_base);
+        ifn!("llvm.assigner.curve.x.__zkllvm_curve_pallas", fn(t_curve_pallas) -> t_field_pallas_base);
+        ifn!("llvm.assigner.curve.y.__zkllvm_curve_pallas", fn(t_curve_pallas) -> t_field_pallas_base);
+        ifn!("llvm.assigner.curve.x.__zkllvm_curve_vesta", fn(t_curve_vesta) -> t_field_pallas_scalar);
+        ifn!("llvm.assigner.curve.y.__zkllvm_curve_vesta", fn(t_curve_vesta) -> t_field_pallas_scalar);
 
         ifn!("llvm.assigner.sha2.256.v2__zkllvm_field_pallas_base", fn(t_fpb_v2, t_fpb_v2) -> t_fpb_v2);
+        ifn!("llvm.assigner.sha2.256.process.v2__zkllvm_field_pallas_base.v4__zkllvm_field_pallas_base",
+            fn(t_fpb_v2, t_fpb_v4) -> t_fpb_v2);
         ifn!("llvm.assigner.sha2.512.__zkllvm_field_curve25519_scalar.__zkllvm_curve_curve25519.v4__zkllvm_field_pallas_base",
             fn(t_curve_curve25519, t_curve_curve25519, t_fpb_v4) -> t_field_curve25519_scalar);
 
+        ifn!("llvm.assigner.poseidon.v3__zkllvm_field_pallas_base", fn(t_fpb_v3) -> t_fpb_v3);
+        ifn!("llvm.assigner.poseidon.v3__zkllvm_field_bls12381_scalar", fn(t_fblss_v3) -> t_fblss_v3);
+
         ifn!("llvm.assigner.sha2.256.bls12381.__zkllvm_field_bls12381_base",
             fn(t_field_bls12381_base) -> t_field_bls12381_base);
         ifn!("llvm.assigner.optimal.ate.pairing.v12__zkllvm_field_bls12381_base.__zkllvm_curve_bls12381.v4__zkllvm_field_bls12381_base",
             fn(t_curve_bls12381, t_fblsb_v4) -> t_fblsb_v12);
         ifn!("llvm.assigner.hash.to.curve.__zkllvm_curve_bls12381.__zkllvm_field_bls12381_base",
             fn(t_field_bls12381_base) -> t_curve_bls12381);
+        ifn!("llvm.assigner.is.on.curve.__zkllvm_curve_bls12381", fn(t_field_bls12381_base, t_field_bls12381_base) -> i1);
+        ifn!("llvm.assigner.is.on.curve.__zkllvm_curve_bn254", fn(t_field_bn254_base, t_field_bn254_base) -> i1);
+        ifn!("llvm.assigner.is.on.curve.__zkllvm_curve_secp256k1", fn(t_field_secp256k1_base, t_field_secp256k1_base) -> i1);
+        ifn!("llvm.assigner.is.on.curve.__zkllvm_curve_curve25519", fn(t_field_curve25519_base, t_field_curve25519_base) -> i1);
+        ifn!("llvm.assigner.is.on.curve.__zkllvm_curve_pallas", fn(t_field_pallas_base, t_field_pallas_base) -> i1);
+        ifn!("llvm.assigner.is.on.curve.__zkllvm_curve_vesta", fn(t_field_pallas_scalar, t_field_pallas_scalar) -> i1);
         ifn!("llvm.assigner.is.in.g1.check.__zkllvm_curve_bls12381", fn(t_curve_bls12381) -> i1);
         ifn!("llvm.assigner.is.in.g2.check.v4__zkllvm_field_bls12381_base", fn(t_fblsb_v4) -> i1);
         ifn!("llvm.assigner.gt.multiplication.v12__zkllvm_field_bls12381_base",
             fn(t_fblsb_v12, t_fblsb_v12) -> t_fblsb_v12);
+        ifn!("llvm.assigner.curve.decompress.__zkllvm_curve_curve25519",
+            fn(t_field_curve25519_base, i1) -> t_field_curve25519_base);
+        ifn!("llvm.assigner.ecdsa.verify.__zkllvm_curve_secp256k1",
+            fn(t_curve_secp256k1, t_field_secp256k1_scalar, t_field_secp256k1_scalar, t_field_secp256k1_scalar) -> i1);
+        ifn!("llvm.assigner.msm.__zkllvm_curve_bls12381", fn(ptr, ptr, t_isize) -> t_curve_bls12381);
+        ifn!("llvm.assigner.msm.__zkllvm_curve_bn254", fn(ptr, ptr, t_isize) -> t_curve_bn254);
+        ifn!("llvm.assigner.msm.__zkllvm_curve_secp256k1", fn(ptr, ptr, t_isize) -> t_curve_secp256k1);
+        ifn!("llvm.assigner.msm.__zkllvm_curve_curve25519", fn(ptr, ptr, t_isize) -> t_curve_curve25519);
+        ifn!("llvm.assigner.msm.__zkllvm_curve_pallas", fn(ptr, ptr, t_isize) -> t_curve_pallas);
+        ifn!("llvm.assigner.msm.__zkllvm_curve_vesta", fn(ptr, ptr, t_isize) -> t_curve_vesta);
+
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_bls12381_base", fn(t_i128) -> t_field_bls12381_base);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_bls12381_scalar", fn(t_i128) -> t_field_bls12381_scalar);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_bn254_base", fn(t_i128) -> t_field_bn254_base);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_bn254_scalar", fn(t_i128) -> t_field_bn254_scalar);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_secp256k1_base", fn(t_i128) -> t_field_secp256k1_base);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_secp256k1_scalar", fn(t_i128) -> t_field_secp256k1_scalar);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_curve25519_base", fn(t_i128) -> t_field_curve25519_base);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_curve25519_scalar", fn(t_i128) -> t_field_curve25519_scalar);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_pallas_base", fn(t_i128) -> t_field_pallas_base);
+        ifn!("llvm.assigner.int.to.field.__zkllvm_field_pallas_scalar", fn(t_i128) -> t_field_pallas_scalar);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_bls12381_base", fn(t_field_bls12381_base) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_bls12381_scalar", fn(t_field_bls12381_scalar) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_bn254_base", fn(t_field_bn254_base) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_bn254_scalar", fn(t_field_bn254_scalar) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_secp256k1_base", fn(t_field_secp256k1_base) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_secp256k1_scalar", fn(t_field_secp256k1_scalar) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_curve25519_base", fn(t_field_curve25519_base) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_curve25519_scalar", fn(t_field_curve25519_scalar) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_pallas_base", fn(t_field_pallas_base) -> t_i128);
+        ifn!("llvm.assigner.field.to.int.__zkllvm_field_pallas_scalar", fn(t_field_pallas_scalar) -> t_i128);
 
         // This isn't an "LLVM intrinsic", but LLVM's optimization passes
         // recognize it like one (including turning it into `bcmp` sometimes)
@@ -383,7 +383,7 @@ fn codegen_intrinsic_call(
             }
 
             _ if name.as_str().starts_with("assigner_") => {
-                assigner_intrinsic(self, name, args, ret_ty)
+                assigner_intrinsic(self, instance, name, args, ret_ty)
             }
 
             _ => bug!("unknown intrinsic '{}' -- should it have been lowered earlier?", name),
@@ -2159,10 +2159,60 @@ fn unpack_vector_into_array<'ll, 'tcx>(
 
 fn assigner_intrinsic<'ll, 'tcx>(
     bx: &mut Builder<'_, 'll, 'tcx>,
+    instance: ty::Instance<'tcx>,
     name: Symbol,
     args: &[OperandRef<'tcx, &'ll Value>],
     ret_ty: Ty<'tcx>,
 ) -> &'ll Value {
+    if name.as_str() == "assigner_field_to_int" {
+        // The builder knows how to convert on every target.
+        let int_ty = bx.type_i128();
+        return bx.field_to_int(args[0].immediate(), args[0].layout.ty, int_ty);
+    }
+    if !bx.sess().target.is_like_assigner {
+        // Outside the assigner a curve point is stored as `x` followed by `y`, and an
+        // extension field element as its coefficients, one after another.
+        let name = name.as_str();
+        if name.starts_with("assigner_curve_init_")
+            || name == "assigner_g2_init"
+            || name == "assigner_gt_init"
+        {
+            let place = PlaceRef::alloca(bx, bx.layout_of(ret_ty));
+            for (i, arg) in args.iter().enumerate() {
+                let offset = bx.const_usize(arg.layout.size.bytes() * i as u64);
+                let ptr = bx.inbounds_gep(bx.type_i8(), place.llval, &[offset]);
+                bx.store(arg.immediate(), ptr, arg.layout.align.abi);
+            }
+            return bx.load(place.layout.llvm_type(bx), place.llval, place.align);
+        }
+        let is_y = name.starts_with("assigner_curve_y_");
+        if is_y
+            || name.starts_with("assigner_curve_x_")
+            || name == "assigner_g2_coordinates"
+            || name == "assigner_gt_coefficients"
+        {
+            let place = PlaceRef::alloca(bx, args[0].layout);
+            args[0].val.store(bx, place);
+            let layout = bx.layout_of(ret_ty);
+            let ptr = if is_y {
+                let offset = bx.const_usize(layout.size.bytes());
+                bx.inbounds_gep(bx.type_i8(), place.llval, &[offset])
+            } else {
+                place.llval
+            };
+            return bx.load(layout.llvm_type(bx), ptr, layout.align.abi);
+        }
+        if let Some(runtime) = bx.tcx().assigner_is_on_curve_runtime(instance) {
+            // The runtime takes the coordinates by value, like the intrinsic.
+            let fn_abi = bx.fn_abi_of_instance(runtime, ty::List::empty());
+            let fn_ty = bx.fn_decl_backend_type(fn_abi);
+            let llfn = bx.get_fn_addr(runtime);
+            let llargs = [args[0].immediate(), args[1].immediate()];
+            return bx.call(fn_ty, None, Some(fn_abi), llfn, &llargs, None);
+        }
+        // Calls of other intrinsics are replaced with their software implementations.
+        bug!("assigner intrinsic '{}' should have been replaced with its fallback", name);
+    }
     if let Some(truncated_name) = name.as_str().strip_prefix("assigner_") {
         match truncated_name {
             "exit_check" => bx.call_intrinsic("llvm.assigner.exit.check", &[args[0].immediate()]),
@@ -2172,6 +2222,26 @@ fn assigner_intrinsic<'ll, 'tcx>(
                 let intr_name = format!("llvm.assigner.curve.init.{}", ret_ty);
                 bx.call_intrinsic(&intr_name, &[x, y])
             },
+            _ if truncated_name.starts_with("curve_x_") => {
+                let intr_name = format!("llvm.assigner.curve.x.{}", args[0].layout.ty);
+                bx.call_intrinsic(&intr_name, &[args[0].immediate()])
+            },
+            _ if truncated_name.starts_with("curve_y_") => {
+                let intr_name = format!("llvm.assigner.curve.y.{}", args[0].layout.ty);
+                bx.call_intrinsic(&intr_name, &[args[0].immediate()])
+            },
+            _ if truncated_name.starts_with("is_on_curve_") => {
+                let x = args[0].immediate();
+                let y = args[1].immediate();
+                let curve_name = &truncated_name["is_on_curve_".len()..];
+                let intr_name = format!("llvm.assigner.is.on.curve.__zkllvm_curve_{curve_name}");
+                bx.call_intrinsic(&intr_name, &[x, y])
+            },
+            _ if truncated_name.starts_with("msm_") => {
+                let args: Vec<_> = args.iter().map(|arg| arg.immediate()).collect();
+                let intr_name = format!("llvm.assigner.msm.{}", ret_ty);
+                bx.call_intrinsic(&intr_name, &args)
+            },
             "sha2_256" => {
                 let type_ = bx.type_field_pallas_base();
                 let x = pack_vector(bx, type_, &args[..2]);
@@ -2182,6 +2252,16 @@ fn assigner_intrinsic<'ll, 'tcx>(
                 );
                 unpack_vector_into_array(bx, type_, ret_vector, 2)
             },
+            "sha2_256_compress" => {
+                let type_ = bx.type_field_pallas_base();
+                let state = pack_vector(bx, type_, &args[..2]);
+                let block = pack_vector(bx, type_, &args[2..]);
+                let ret_vector = bx.call_intrinsic(
+                    "llvm.assigner.sha2.256.process.v2__zkllvm_field_pallas_base.v4__zkllvm_field_pallas_base",
+                    &[state, block],
+                );
+                unpack_vector_into_array(bx, type_, ret_vector, 2)
+            },
             "sha2_512" => {
                 let type_ = bx.type_field_pallas_base();
                 let x = args[0].immediate();
@@ -2192,21 +2272,26 @@ fn assigner_intrinsic<'ll, 'tcx>(
                     &[x, y, z],
                 )
             },
+            "poseidon_pallas_base" | "poseidon_bls12381_scalar" => {
+                let type_ = if truncated_name == "poseidon_pallas_base" {
+                    bx.type_field_pallas_base()
+                } else {
+                    bx.type_field_bls12381_scalar()
+                };
+                let intr_name = format!("llvm.assigner.poseidon.v3{}", args[0].layout.ty);
+                let state = pack_vector(bx, type_, args);
+                let ret_vector = bx.call_intrinsic(&intr_name, &[state]);
+                unpack_vector_into_array(bx, type_, ret_vector, 3)
+            },
             "sha2_256_bls12381" => bx.call_intrinsic(
                 "llvm.assigner.sha2.256.bls12381.__zkllvm_field_bls12381_base",
                 &[args[0].immediate()],
             ),
-            "bls12_optimal_ate_pairing" => {
-                let type_ = bx.type_field_pallas_base();
-                let x = args[0].immediate();
-                let y = pack_vector(bx, type_, &args[1..5]);
-
-                let ret_vector = bx.call_intrinsic(
-                    "llvm.assigner.optimal.ate.pairing.v12__zkllvm_field_bls12381_base.__zkllvm_curve_bls12381.v4__zkllvm_field_bls12381_base",
-                    &[x, y],
-                );
-                unpack_vector_into_array(bx, type_, ret_vector, 12)
-            },
+            // G2 points and GT elements already are the vectors these intrinsics expect.
+            "bls12_optimal_ate_pairing" => bx.call_intrinsic(
+                "llvm.assigner.optimal.ate.pairing.v12__zkllvm_field_bls12381_base.__zkllvm_curve_bls12381.v4__zkllvm_field_bls12381_base",
+                &[args[0].immediate(), args[1].immediate()],
+            ),
             "hash_to_curve" => bx.call_intrinsic(
                 "llvm.assigner.hash.to.curve.__zkllvm_curve_bls12381.__zkllvm_field_bls12381_base",
                 &[args[0].immediate()],
@@ -2215,23 +2300,38 @@ fn assigner_intrinsic<'ll, 'tcx>(
                 "llvm.assigner.is.in.g1.check.__zkllvm_curve_bls12381",
                 &[args[0].immediate()],
             ),
-            "is_in_g2_check" => {
+            "is_in_g2_check" => bx.call_intrinsic(
+                "llvm.assigner.is.in.g2.check.v4__zkllvm_field_bls12381_base",
+                &[args[0].immediate()],
+            ),
+            "gt_multiplication" => bx.call_intrinsic(
+                "llvm.assigner.gt.multiplication.v12__zkllvm_field_bls12381_base",
+                &[args[0].immediate(), args[1].immediate()],
+            ),
+            "curve_decompress_curve25519" => bx.call_intrinsic(
+                "llvm.assigner.curve.decompress.__zkllvm_curve_curve25519",
+                &[args[0].immediate(), args[1].immediate()],
+            ),
+            "ecdsa_verify" => bx.call_intrinsic(
+                "llvm.assigner.ecdsa.verify.__zkllvm_curve_secp256k1",
+                &[
+                    args[0].immediate(),
+                    args[1].immediate(),
+                    args[2].immediate(),
+                    args[3].immediate(),
+                ],
+            ),
+            "g2_init" | "gt_init" => {
                 let type_ = bx.type_field_bls12381_base();
-                let x = pack_vector(bx, type_, &args[0..4]);
-                bx.call_intrinsic(
-                    "llvm.assigner.is.in.g2.check.v4__zkllvm_field_bls12381_base",
-                    &[x],
-                )
+                pack_vector(bx, type_, args)
             },
-            "gt_multiplication" => {
+            "g2_coordinates" => {
                 let type_ = bx.type_field_bls12381_base();
-                let x = pack_vector(bx, type_, &args[0..12]);
-                let y = pack_vector(bx, type_, &args[12..24]);
-                let ret_vector = bx.call_intrinsic(
-                    "llvm.assigner.gt.multiplication.v12__zkllvm_field_bls12381_base",
-                    &[x, y],
-                );
-                unpack_vector_into_array(bx, type_, ret_vector, 12)
+                unpack_vector_into_array(bx, type_, args[0].immediate(), 4)
+            },
+            "gt_coefficients" => {
+                let type_ = bx.type_field_bls12381_base();
+                unpack_vector_into_array(bx, type_, args[0].immediate(), 12)
             },
             _ => bug!("unknown assigner intrinsic name: '{}'", name),
         }
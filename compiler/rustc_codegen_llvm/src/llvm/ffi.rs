@@ -867,11 +867,17 @@ pub struct AllocKindFlags : u64 {
     pub fn LLVMGaloisFieldVestascalarTypeInContext(C: &Context) -> &Type;
     pub fn LLVMGaloisFieldBLS12381baseTypeInContext(C: &Context) -> &Type;
     pub fn LLVMGaloisFieldBLS12381scalarTypeInContext(C: &Context) -> &Type;
+    pub fn LLVMGaloisFieldBN254baseTypeInContext(C: &Context) -> &Type;
+    pub fn LLVMGaloisFieldBN254scalarTypeInContext(C: &Context) -> &Type;
+    pub fn LLVMGaloisFieldSECP256K1baseTypeInContext(C: &Context) -> &Type;
+    pub fn LLVMGaloisFieldSECP256K1scalarTypeInContext(C: &Context) -> &Type;
     pub fn LLVMGaloisFieldCurve25519baseTypeInContext(C: &Context) -> &Type;
     pub fn LLVMGaloisFieldCurve25519scalarTypeInContext(C: &Context) -> &Type;
 
     // Operations of elliptic curve types
     pub fn LLVMEllipticCurveBLS12381TypeInContext(C: &Context) -> &Type;
+    pub fn LLVMEllipticCurveBN254TypeInContext(C: &Context) -> &Type;
+    pub fn LLVMEllipticCurveSECP256K1TypeInContext(C: &Context) -> &Type;
     pub fn LLVMEllipticCurveCurve25519TypeInContext(C: &Context) -> &Type;
     pub fn LLVMEllipticCurvePallasTypeInContext(C: &Context) -> &Type;
     pub fn LLVMEllipticCurveVestaTypeInContext(C: &Context) -> &Type;
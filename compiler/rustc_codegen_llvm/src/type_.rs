@@ -164,6 +164,22 @@ fn type_field_bls12381_scalar(&self) -> Self::Type {
         unsafe { llvm::LLVMGaloisFieldBLS12381scalarTypeInContext(self.llcx) }
     }
 
+    fn type_field_bn254_base(&self) -> Self::Type {
+        unsafe { llvm::LLVMGaloisFieldBN254baseTypeInContext(self.llcx) }
+    }
+
+    fn type_field_bn254_scalar(&self) -> Self::Type {
+        unsafe { llvm::LLVMGaloisFieldBN254scalarTypeInContext(self.llcx) }
+    }
+
+    fn type_field_secp256k1_base(&self) -> Self::Type {
+        unsafe { llvm::LLVMGaloisFieldSECP256K1baseTypeInContext(self.llcx) }
+    }
+
+    fn type_field_secp256k1_scalar(&self) -> Self::Type {
+        unsafe { llvm::LLVMGaloisFieldSECP256K1scalarTypeInContext(self.llcx) }
+    }
+
     fn type_field_curve25519_base(&self) -> Self::Type {
         unsafe { llvm::LLVMGaloisFieldCurve25519baseTypeInContext(self.llcx) }
     }
@@ -180,10 +196,24 @@ fn type_field_pallas_scalar(&self) -> Self::Type {
         unsafe { llvm::LLVMGaloisFieldPallasscalarTypeInContext(self.llcx) }
     }
 
+    fn type_field_bls12381_gt(&self) -> Self::Type {
+        // The assigner has no native extension field types, so an element is passed as
+        // the vector of its coefficients, which is what the pairing intrinsics expect.
+        self.type_vector(self.type_field_bls12381_base(), 12)
+    }
+
     fn type_curve_bls12381(&self) -> Self::Type {
         unsafe { llvm::LLVMEllipticCurveBLS12381TypeInContext(self.llcx) }
     }
 
+    fn type_curve_bn254(&self) -> Self::Type {
+        unsafe { llvm::LLVMEllipticCurveBN254TypeInContext(self.llcx) }
+    }
+
+    fn type_curve_secp256k1(&self) -> Self::Type {
+        unsafe { llvm::LLVMEllipticCurveSECP256K1TypeInContext(self.llcx) }
+    }
+
     fn type_curve_curve25519(&self) -> Self::Type {
         unsafe { llvm::LLVMEllipticCurveCurve25519TypeInContext(self.llcx) }
     }
@@ -196,6 +226,11 @@ fn type_curve_vesta(&self) -> Self::Type {
         unsafe { llvm::LLVMEllipticCurveVestaTypeInContext(self.llcx) }
     }
 
+    fn type_curve_bls12381_g2(&self) -> Self::Type {
+        // Same as for `type_field_bls12381_gt`: coefficients of `x` followed by those of `y`.
+        self.type_vector(self.type_field_bls12381_base(), 4)
+    }
+
     fn type_f32(&self) -> &'ll Type {
         unsafe { llvm::LLVMFloatTypeInContext(self.llcx) }
     }
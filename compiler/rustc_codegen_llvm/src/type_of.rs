@@ -235,11 +235,20 @@ fn llvm_type<'a>(&self, cx: &CodegenCx<'a, 'tcx>) -> &'a Type {
             return llty;
         }
         if let Abi::Field(field) = self.abi {
+            if !cx.tcx.sess.target.is_like_assigner {
+                // Only the assigner has native field types. Elsewhere fields are plain
+                // integers, and operations on them are calls into the software runtime.
+                return cx.type_ix(field.size().bits());
+            }
             // FIXME: (aleasims) cache types
             let llty = cx.type_from_field(field);
             return llty;
         }
         if let Abi::Curve(curve) = self.abi {
+            if !cx.tcx.sess.target.is_like_assigner {
+                // Same as for fields, a curve point is an integer wide enough for both coordinates.
+                return cx.type_ix(curve.size().bits());
+            }
             // FIXME: (aleasims) cache types
             let llty = cx.type_from_curve(curve);
             return llty;
@@ -13,6 +13,12 @@ codegen_ssa_binary_output_to_tty = option `-o` or `--emit` is used to write bina
 
 codegen_ssa_check_installed_visual_studio = please ensure that Visual Studio 2017 or later, or Build Tools for Visual Studio were installed with the Visual C++ option.
 
+codegen_ssa_circuit_abi_unsupported_type = cannot describe type `{$ty}` in the circuit ABI
+    .note = `{$unsupported}` cannot be flattened into field elements and integers
+
+codegen_ssa_circuit_input_template_unsupported_type = cannot write an input template for type `{$ty}`
+    .note = `{$unsupported}` has no representation in the input files of the assigner
+
 codegen_ssa_copy_path = could not copy {$from} to {$to}: {$error}
 
 codegen_ssa_copy_path_buf = unable to copy {$source_file} to {$output_path}: {$error}
@@ -166,6 +172,8 @@ codegen_ssa_multiple_external_func_decl = multiple declarations of external func
 codegen_ssa_multiple_main_functions = entry symbol `main` declared multiple times
     .help = did you use `#[no_mangle]` on `fn main`? Use `#[start]` instead
 
+codegen_ssa_no_circuit_function = the crate has no `#[circuit]` function, `--emit={$shorthand}` is not written
+
 codegen_ssa_no_natvis_directory = error enumerating natvis directory: {$error}
 
 codegen_ssa_option_gcc_only = option `-Z gcc-ld` is used even though linker flavor is not gcc
@@ -411,6 +411,13 @@ pub fn maybe_create_entry_wrapper<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
         return None;
     }
 
+    if entry_type == EntryFnType::Circuit {
+        // The circuit was picked from a dependency with `-Z circuit-entry`. It is already
+        // marked there, the executable only keeps it when the dependency is linked in.
+        cx.add_used_global(cx.get_fn_addr(instance));
+        return None;
+    }
+
     let main_llfn = cx.get_fn_addr(instance);
 
     let entry_fn = create_entry_fn::<Bx>(cx, main_llfn, main_def_id, entry_type);
@@ -0,0 +1,293 @@
+//! The circuit ABI manifest written by `--emit=circuit-abi` and the input file templates
+//! written by `--emit=circuit-input-template`.
+//!
+//! The assigner reads the arguments of the `#[circuit]` function as a flat list of field
+//! elements and integers. The manifest describes each parameter and the return value: its
+//! Rust type, whether it is a public or a private input, and the scalars it is flattened
+//! into, each with its path inside the value. Tools generating circuit inputs or verifier
+//! contracts can read the ABI from it instead of mirroring the Rust signature by hand.
+//!
+//! The templates are input files in the format the assigner reads, with every scalar set
+//! to zero, so that only the values have to be filled in.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_middle::middle::codegen_fn_attrs::{CircuitInput, CodegenFnAttrFlags};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{self, FnSig, Ty, TyCtxt};
+use rustc_session::config::{EntryFnType, OutFileName, OutputType};
+use rustc_span::Span;
+use serde_json::{json, Value};
+
+use crate::errors;
+
+/// Returns the circuit of the crate: the entry point of an executable, which may be defined
+/// in a dependency, or else the first `#[circuit]` function of the local crate.
+pub fn circuit_fn(tcx: TyCtxt<'_>) -> Option<DefId> {
+    if let Some((def_id, EntryFnType::Circuit)) = tcx.entry_fn(()) {
+        return Some(def_id);
+    }
+    let def_id = tcx.hir().body_owners().find(|&def_id| {
+        tcx.def_kind(def_id) == DefKind::Fn
+            && tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::CIRCUIT)
+    })?;
+    Some(def_id.to_def_id())
+}
+
+/// The signature of the circuit, with the spans to report unsupported types at.
+struct CircuitSignature<'tcx> {
+    def_id: DefId,
+    sig: FnSig<'tcx>,
+    param_spans: Vec<Span>,
+    ret_span: Span,
+}
+
+/// Returns the signature of the circuit, warning that `output_type` is not emitted if there
+/// is no circuit.
+fn circuit_signature(tcx: TyCtxt<'_>, output_type: OutputType) -> Option<CircuitSignature<'_>> {
+    let Some(def_id) = circuit_fn(tcx) else {
+        tcx.sess.emit_warning(errors::NoCircuitFunction { shorthand: output_type.shorthand() });
+        return None;
+    };
+    let sig = tcx.normalize_erasing_late_bound_regions(
+        ty::ParamEnv::reveal_all(),
+        tcx.fn_sig(def_id).instantiate_identity(),
+    );
+    // A circuit of a dependency has no declaration to point into.
+    let (param_spans, ret_span) = match def_id.as_local() {
+        Some(local_def_id) => {
+            let decl = tcx.hir().get_by_def_id(local_def_id).fn_decl().unwrap();
+            (decl.inputs.iter().map(|ty| ty.span).collect(), decl.output.span())
+        }
+        None => (vec![tcx.def_span(def_id); sig.inputs().len()], tcx.def_span(def_id)),
+    };
+    Some(CircuitSignature { def_id, sig, param_spans, ret_span })
+}
+
+/// Returns `true` if the parameter at `index` is a private input of the circuit.
+///
+/// Parameters without an attribute are public, as the assigner treats them.
+fn is_private_input(tcx: TyCtxt<'_>, def_id: DefId, index: usize) -> bool {
+    let circuit_inputs = &tcx.codegen_fn_attrs(def_id).circuit_inputs;
+    circuit_inputs.get(index) == Some(&Some(CircuitInput::Private))
+}
+
+/// Writes pretty-printed `value` on its own line.
+fn write_json(out: &mut impl Write, value: &Value) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value)?;
+    writeln!(out)
+}
+
+/// A scalar read by the circuit: a field element, an integer or a boolean.
+struct Element {
+    /// Where the scalar is inside the value, e.g. `points[1].x`.
+    path: String,
+    /// The name of the scalar type.
+    ty: &'static str,
+}
+
+/// Appends the scalars `ty` consists of to `elements`, in the order the circuit reads them.
+///
+/// `ty` must be supported, see [`TyCtxt::unsupported_circuit_ty`].
+fn flatten<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, path: String, elements: &mut Vec<Element>) {
+    match *ty.kind() {
+        ty::Bool => elements.push(Element { path, ty: "bool" }),
+        ty::Int(int) => elements.push(Element { path, ty: int.name_str() }),
+        ty::Uint(uint) => elements.push(Element { path, ty: uint.name_str() }),
+        // The target group is the only extension field, its elements are given by their
+        // coefficients over the prime subfield.
+        ty::Field(ty::FieldTy::Bls12381Gt) => {
+            for i in 0..ty::FieldTy::Bls12381Gt.degree() {
+                let ty = ty::FieldTy::Bls12381Base.name_str();
+                elements.push(Element { path: format!("{path}[{i}]"), ty });
+            }
+        }
+        ty::Field(field) => elements.push(Element { path, ty: field.name_str() }),
+        // Points are given by their affine coordinates, which are coefficient arrays for
+        // curves over an extension field.
+        ty::Curve(curve) => {
+            let ty = curve.base_field().name_str();
+            for coordinate in ["x", "y"] {
+                if curve.degree() == 1 {
+                    elements.push(Element { path: format!("{path}.{coordinate}"), ty });
+                } else {
+                    for i in 0..curve.degree() {
+                        elements.push(Element { path: format!("{path}.{coordinate}[{i}]"), ty });
+                    }
+                }
+            }
+        }
+        ty::Array(elem_ty, len) => {
+            for i in 0..len.eval_target_usize(tcx, ty::ParamEnv::reveal_all()) {
+                flatten(tcx, elem_ty, format!("{path}[{i}]"), elements);
+            }
+        }
+        ty::Tuple(_) | ty::Adt(..) => {
+            for (name, field_ty) in fields_by_offset(tcx, ty) {
+                flatten(tcx, field_ty, format!("{path}.{name}"), elements);
+            }
+        }
+        _ => bug!("unsupported circuit type `{ty}`"),
+    }
+}
+
+/// Returns the names and types of the fields of the tuple or struct `ty`, ordered by their
+/// offsets. Fields of a tuple may be reordered in memory, the circuit reads them in memory
+/// order rather than in the declared one.
+fn fields_by_offset<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Vec<(String, Ty<'tcx>)> {
+    let param_env = ty::ParamEnv::reveal_all();
+    let fields: Vec<_> = match *ty.kind() {
+        ty::Tuple(tys) => tys.iter().enumerate().map(|(i, ty)| (i.to_string(), ty)).collect(),
+        ty::Adt(adt_def, args) if adt_def.is_struct() => adt_def
+            .non_enum_variant()
+            .fields
+            .iter()
+            .map(|field| {
+                let field_ty = tcx.normalize_erasing_regions(param_env, field.ty(tcx, args));
+                (field.name.to_string(), field_ty)
+            })
+            .collect(),
+        _ => bug!("`{ty}` is neither a tuple nor a struct"),
+    };
+    let layout = tcx.layout_of(param_env.and(ty)).unwrap();
+    layout.fields.index_by_increasing_offset().map(|i| fields[i].clone()).collect()
+}
+
+/// Describes the value of type `ty` at `root`, reporting an error at `span` if the type
+/// cannot be flattened.
+fn describe<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, root: &str, span: Span) -> Option<Value> {
+    if let Some((unsupported, _)) = tcx.unsupported_circuit_ty(ty) {
+        tcx.sess.emit_err(errors::CircuitAbiUnsupportedType { span, ty, unsupported });
+        return None;
+    }
+    let mut elements = Vec::new();
+    flatten(tcx, ty, root.to_string(), &mut elements);
+    let elements: Vec<_> =
+        elements.into_iter().map(|e| json!({ "path": e.path, "type": e.ty })).collect();
+    Some(json!({
+        "type": with_no_trimmed_paths!(ty.to_string()),
+        "elements": elements,
+    }))
+}
+
+/// Returns a value of type `ty` in the input file format of the assigner, with every
+/// scalar set to zero.
+///
+/// `ty` must be supported, see [`TyCtxt::unsupported_circuit_ty`].
+fn input_template<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Value {
+    match *ty.kind() {
+        ty::Bool | ty::Int(_) | ty::Uint(_) => json!({ "int": 0 }),
+        // Extension field elements are given by their coefficients.
+        ty::Field(field) if field.degree() > 1 => {
+            json!({ "field": vec![0; field.degree() as usize] })
+        }
+        ty::Field(_) => json!({ "field": 0 }),
+        ty::Curve(curve) => {
+            let coordinate =
+                if curve.degree() > 1 { json!(vec![0; curve.degree() as usize]) } else { json!(0) };
+            json!({ "curve": [coordinate.clone(), coordinate] })
+        }
+        ty::Array(elem_ty, len) => {
+            let len = len.eval_target_usize(tcx, ty::ParamEnv::reveal_all());
+            json!({ "array": vec![input_template(tcx, elem_ty); len as usize] })
+        }
+        ty::Tuple(_) | ty::Adt(..) => {
+            let fields: Vec<_> = fields_by_offset(tcx, ty)
+                .into_iter()
+                .map(|(_, field_ty)| input_template(tcx, field_ty))
+                .collect();
+            json!({ "struct": fields })
+        }
+        _ => bug!("unsupported circuit type `{ty}`"),
+    }
+}
+
+/// Writes the ABI manifest of the `#[circuit]` function of the local crate.
+///
+/// Only warns if the crate has no circuit. Unsupported types are reported as errors,
+/// nothing is written then.
+pub fn emit_circuit_abi(tcx: TyCtxt<'_>) -> io::Result<()> {
+    let Some(CircuitSignature { def_id, sig, param_spans, ret_span }) =
+        circuit_signature(tcx, OutputType::CircuitAbi)
+    else {
+        return Ok(());
+    };
+
+    let mut params = Vec::new();
+    for (i, (name, &ty)) in tcx.fn_arg_names(def_id).iter().zip(sig.inputs()).enumerate() {
+        let input = if is_private_input(tcx, def_id, i) { "private" } else { "public" };
+        let mut param = describe(tcx, ty, name.as_str(), param_spans[i]);
+        if let Some(param) = &mut param {
+            param["name"] = json!(name.as_str());
+            param["input"] = json!(input);
+        }
+        params.push(param);
+    }
+    let ret = describe(tcx, sig.output(), "return", ret_span);
+    let (Some(params), Some(ret)) = (params.into_iter().collect::<Option<Vec<_>>>(), ret) else {
+        return Ok(());
+    };
+
+    let abi = json!({
+        "circuit": with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+        "params": params,
+        "return": ret,
+    });
+    match tcx.output_filenames(()).path(OutputType::CircuitAbi) {
+        OutFileName::Stdout => write_json(&mut io::stdout(), &abi),
+        OutFileName::Real(path) => write_json(&mut BufWriter::new(File::create(&path)?), &abi),
+    }
+}
+
+/// Writes templates of the public and the private input files of the `#[circuit]`
+/// function of the local crate.
+///
+/// The private inputs are written next to the public ones, to the same path with the
+/// extension replaced by `private.json`. Nothing is written for a circuit without private
+/// inputs. If the output is stdout, a single object is written there instead, with the
+/// public and the private inputs under the `public` and `private` keys.
+pub fn emit_circuit_input_template(tcx: TyCtxt<'_>) -> io::Result<()> {
+    let Some(CircuitSignature { def_id, sig, param_spans, .. }) =
+        circuit_signature(tcx, OutputType::CircuitInputTemplate)
+    else {
+        return Ok(());
+    };
+
+    let mut public = Vec::new();
+    let mut private = Vec::new();
+    let mut supported = true;
+    for (i, &ty) in sig.inputs().iter().enumerate() {
+        if let Some((unsupported, _)) = tcx.unsupported_circuit_ty(ty) {
+            let span = param_spans[i];
+            let err = errors::CircuitInputTemplateUnsupportedType { span, ty, unsupported };
+            tcx.sess.emit_err(err);
+            supported = false;
+        } else if is_private_input(tcx, def_id, i) {
+            private.push(input_template(tcx, ty));
+        } else {
+            public.push(input_template(tcx, ty));
+        }
+    }
+    if !supported {
+        return Ok(());
+    }
+
+    let (public, private) = (json!(public), json!(private));
+    let has_private = private.as_array().is_some_and(|private| !private.is_empty());
+    match tcx.output_filenames(()).path(OutputType::CircuitInputTemplate) {
+        OutFileName::Stdout => {
+            write_json(&mut io::stdout(), &json!({ "public": public, "private": private }))?;
+        }
+        OutFileName::Real(path) => {
+            write_json(&mut BufWriter::new(File::create(&path)?), &public)?;
+            if has_private {
+                let path = path.with_extension("private.json");
+                write_json(&mut BufWriter::new(File::create(&path)?), &private)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -5,7 +5,7 @@
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{DefId, LocalDefId, LOCAL_CRATE};
 use rustc_hir::{lang_items, weak_lang_items::WEAK_LANG_ITEMS, LangItem};
-use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
+use rustc_middle::middle::codegen_fn_attrs::{CircuitInput, CodegenFnAttrFlags, CodegenFnAttrs};
 use rustc_middle::mir::mono::Linkage;
 use rustc_middle::query::Providers;
 use rustc_middle::ty::{self as ty, TyCtxt};
@@ -567,6 +567,28 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: LocalDefId) -> CodegenFnAttrs {
         codegen_fn_attrs.inline = InlineAttr::Never;
     }
 
+    // Parameter attributes are validated by `check_attr`, here they are only collected.
+    if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::CIRCUIT)
+        && let Some(body) = tcx.hir().maybe_body_owned_by(did)
+    {
+        codegen_fn_attrs.circuit_inputs = tcx
+            .hir()
+            .body(body)
+            .params
+            .iter()
+            .map(|param| {
+                let attrs = tcx.hir().attrs(param.hir_id);
+                if attr::contains_name(attrs, sym::public_input) {
+                    Some(CircuitInput::Public)
+                } else if attr::contains_name(attrs, sym::private_input) {
+                    Some(CircuitInput::Private)
+                } else {
+                    None
+                }
+            })
+            .collect();
+    }
+
     // Weak lang items have the same semantics as "std internal" symbols in the
     // sense that they're preserved through all our LTO passes and only
     // strippable by the linker.
@@ -51,6 +51,32 @@ pub struct NoNatvisDirectory {
     pub error: Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_no_circuit_function)]
+pub struct NoCircuitFunction {
+    pub shorthand: &'static str,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_circuit_abi_unsupported_type)]
+#[note]
+pub struct CircuitAbiUnsupportedType<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub unsupported: Ty<'tcx>,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_circuit_input_template_unsupported_type)]
+#[note]
+pub struct CircuitInputTemplateUnsupportedType<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub unsupported: Ty<'tcx>,
+}
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_copy_path_buf)]
 pub struct CopyPathBuf {
@@ -45,6 +45,7 @@
 
 pub mod back;
 pub mod base;
+pub mod circuit_abi;
 pub mod codegen_attrs;
 pub mod common;
 pub mod debuginfo;
@@ -775,6 +775,8 @@ fn codegen_call_terminator(
             ty::FnPtr(_) => (None, Some(callee.immediate())),
             _ => bug!("{} is not callable", callee.layout.ty),
         };
+        // Outside the assigner its intrinsics are calls to their software implementations.
+        let instance = instance.map(|i| bx.tcx().assigner_intrinsic_fallback(i).unwrap_or(i));
         let def = instance.map(|i| i.def);
 
         if let Some(ty::InstanceDef::DropGlue(_, None)) = def {
@@ -7,6 +7,7 @@
 use crate::traits::*;
 use crate::MemFlags;
 
+use rustc_hir::lang_items::LangItem;
 use rustc_middle::mir;
 use rustc_middle::mir::Operand;
 use rustc_middle::ty::cast::{CastTy, IntTy};
@@ -561,6 +562,9 @@ pub fn codegen_rvalue_operand(
                         };
                         OperandValue::Immediate(newval)
                     }
+                    mir::CastKind::IntToField => {
+                        OperandValue::Immediate(bx.int_to_field(operand.immediate(), cast.ty))
+                    }
                     mir::CastKind::Transmute => {
                         self.codegen_transmute_operand(bx, operand, cast).unwrap_or_else(|| {
                             bug!("Unsupported transmute-as-operand of {operand:?} to {cast:?}");
@@ -600,6 +604,23 @@ pub fn codegen_rvalue_operand(
             mir::Rvalue::BinaryOp(op, box (ref lhs, ref rhs)) => {
                 let lhs = self.codegen_operand(bx, lhs);
                 let rhs = self.codegen_operand(bx, rhs);
+                if let Some((lang_item, ty, swap)) =
+                    bx.tcx().soft_zk_binop(op, lhs.layout.ty, rhs.layout.ty)
+                {
+                    let args = if swap { [rhs, lhs] } else { [lhs, rhs] };
+                    return self.codegen_soft_zk_call(bx, lang_item, ty, &args);
+                }
+                if op == mir::BinOp::Rem
+                    && lhs.layout.ty.is_field()
+                    && !bx.tcx().sess.target.is_like_assigner
+                {
+                    // Every non-zero field element divides any other one.
+                    let llty = bx.cx().immediate_backend_type(lhs.layout);
+                    return OperandRef {
+                        val: OperandValue::Immediate(bx.cx().const_null(llty)),
+                        layout: lhs.layout,
+                    };
+                }
                 let llresult = match (lhs.val, rhs.val) {
                     (
                         OperandValue::Pair(lhs_addr, lhs_extra),
@@ -642,6 +663,9 @@ pub fn codegen_rvalue_operand(
 
             mir::Rvalue::UnaryOp(op, ref operand) => {
                 let operand = self.codegen_operand(bx, operand);
+                if let Some(lang_item) = bx.tcx().soft_zk_unop(op, operand.layout.ty) {
+                    return self.codegen_soft_zk_call(bx, lang_item, operand.layout.ty, &[operand]);
+                }
                 let lloperand = operand.immediate();
                 let is_float = operand.layout.ty.is_floating_point();
                 let llval = match op {
@@ -770,6 +794,36 @@ fn codegen_place_to_pointer(
         OperandRef { val, layout: self.cx.layout_of(mk_ptr_ty(self.cx.tcx(), ty)) }
     }
 
+    /// Codegen a field or curve operation as a call into the software runtime,
+    /// see [`TyCtxt::soft_zk_binop`].
+    ///
+    /// Runtime functions take pointers to the result and to every operand.
+    fn codegen_soft_zk_call(
+        &mut self,
+        bx: &mut Bx,
+        lang_item: LangItem,
+        ty: Ty<'tcx>,
+        args: &[OperandRef<'tcx, Bx::Value>],
+    ) -> OperandRef<'tcx, Bx::Value> {
+        let tcx = bx.tcx();
+        let def_id = tcx.require_lang_item(lang_item, Some(self.mir.span));
+        let instance = Instance::new(def_id, tcx.mk_args(&[ty.into()]));
+        let fn_abi = bx.fn_abi_of_instance(instance, ty::List::empty());
+        let fn_ty = bx.fn_decl_backend_type(fn_abi);
+        let llfn = bx.get_fn_addr(instance);
+
+        let result = PlaceRef::alloca(bx, bx.cx().layout_of(ty));
+        let mut llargs = Vec::with_capacity(args.len() + 1);
+        llargs.push(result.llval);
+        for arg in args {
+            let scratch = PlaceRef::alloca(bx, arg.layout);
+            arg.val.store(bx, scratch);
+            llargs.push(scratch.llval);
+        }
+        bx.call(fn_ty, None, Some(fn_abi), llfn, &llargs, None);
+        bx.load_operand(result)
+    }
+
     pub fn codegen_scalar_binop(
         &mut self,
         bx: &mut Bx,
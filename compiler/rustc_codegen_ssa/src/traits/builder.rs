@@ -204,6 +204,16 @@ fn inbounds_gep(
     fn bitcast(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
     fn intcast(&mut self, val: Self::Value, dest_ty: Self::Type, is_signed: bool) -> Self::Value;
     fn pointercast(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
+    /// Converts an unsigned integer into an element of the field `field_ty`.
+    fn int_to_field(&mut self, val: Self::Value, field_ty: Ty<'tcx>) -> Self::Value;
+    /// Converts an element of the field `field_ty` into an unsigned integer,
+    /// truncating its canonical value.
+    fn field_to_int(
+        &mut self,
+        val: Self::Value,
+        field_ty: Ty<'tcx>,
+        dest_ty: Self::Type,
+    ) -> Self::Value;
 
     fn cast_float_to_int(
         &mut self,
@@ -22,15 +22,23 @@ pub trait BaseTypeMethods<'tcx>: Backend<'tcx> {
 
     fn type_field_bls12381_base(&self) -> Self::Type;
     fn type_field_bls12381_scalar(&self) -> Self::Type;
+    fn type_field_bn254_base(&self) -> Self::Type;
+    fn type_field_bn254_scalar(&self) -> Self::Type;
+    fn type_field_secp256k1_base(&self) -> Self::Type;
+    fn type_field_secp256k1_scalar(&self) -> Self::Type;
     fn type_field_curve25519_base(&self) -> Self::Type;
     fn type_field_curve25519_scalar(&self) -> Self::Type;
     fn type_field_pallas_base(&self) -> Self::Type;
     fn type_field_pallas_scalar(&self) -> Self::Type;
+    fn type_field_bls12381_gt(&self) -> Self::Type;
 
     fn type_curve_bls12381(&self) -> Self::Type;
+    fn type_curve_bn254(&self) -> Self::Type;
+    fn type_curve_secp256k1(&self) -> Self::Type;
     fn type_curve_curve25519(&self) -> Self::Type;
     fn type_curve_pallas(&self) -> Self::Type;
     fn type_curve_vesta(&self) -> Self::Type;
+    fn type_curve_bls12381_g2(&self) -> Self::Type;
 
     fn type_f32(&self) -> Self::Type;
     fn type_f64(&self) -> Self::Type;
@@ -80,10 +88,15 @@ fn type_from_field(&self, f: Field) -> Self::Type {
         match f {
             Bls12381Base => self.type_field_bls12381_base(),
             Bls12381Scalar => self.type_field_bls12381_scalar(),
+            Bn254Base => self.type_field_bn254_base(),
+            Bn254Scalar => self.type_field_bn254_scalar(),
+            Secp256k1Base => self.type_field_secp256k1_base(),
+            Secp256k1Scalar => self.type_field_secp256k1_scalar(),
             Curve25519Base => self.type_field_curve25519_base(),
             Curve25519Scalar => self.type_field_curve25519_scalar(),
             PallasBase => self.type_field_pallas_base(),
             PallasScalar => self.type_field_pallas_scalar(),
+            Bls12381Gt => self.type_field_bls12381_gt(),
         }
     }
 
@@ -91,9 +104,12 @@ fn type_from_curve(&self, c: Curve) -> Self::Type {
         use Curve::*;
         match c {
             Bls12381 => self.type_curve_bls12381(),
+            Bn254 => self.type_curve_bn254(),
+            Secp256k1 => self.type_curve_secp256k1(),
             Curve25519 => self.type_curve_curve25519(),
             Pallas => self.type_curve_pallas(),
             Vesta => self.type_curve_vesta(),
+            Bls12381G2 => self.type_curve_bls12381_g2(),
         }
     }
 
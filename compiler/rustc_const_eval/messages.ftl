@@ -38,6 +38,9 @@ const_eval_const_context = {$kind ->
 const_eval_copy_nonoverlapping_overlapping =
     `copy_nonoverlapping` called on overlapping ranges
 
+const_eval_curve_point_not_on_curve =
+    using {$point}, which is not a point of `{$ty}`
+
 const_eval_dangling_int_pointer =
     {$bad_pointer_message}: {$pointer} is a dangling pointer (it has no provenance)
 const_eval_dangling_null_pointer =
@@ -427,6 +430,7 @@ const_eval_validation_expected_int = expected an integer
 const_eval_validation_expected_raw_ptr = expected a raw pointer
 const_eval_validation_expected_ref = expected a reference
 const_eval_validation_expected_str = expected a string
+const_eval_validation_expected_curve = expected a curve point
 const_eval_validation_expected_field = expected a field
 
 const_eval_validation_front_matter_invalid_value = constructing invalid value
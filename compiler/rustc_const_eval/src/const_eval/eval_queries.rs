@@ -128,7 +128,7 @@ pub(super) fn op_to_const<'tcx>(
             },
             _ => false,
         },
-        Abi::Field(..) => true,
+        Abi::Field(f) => !f.is_extension(),
         _ => false,
     };
     let immediate = if try_as_immediate {
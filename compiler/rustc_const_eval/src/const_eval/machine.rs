@@ -559,6 +559,16 @@ fn assert_panic(
         _unwind: mir::UnwindAction,
     ) -> InterpResult<'tcx> {
         use rustc_middle::mir::AssertKind::*;
+        // Field values do not fit into a `ConstInt`, so division of a field element by zero
+        // is reported the same way the interpreter reports it for other operations.
+        if let DivisionByZero(op) | RemainderByZero(op) = msg
+            && ecx.eval_operand(op, None)?.layout.ty.is_field()
+        {
+            match msg {
+                DivisionByZero(_) => throw_ub!(DivisionByZero),
+                _ => throw_ub!(RemainderByZero),
+            }
+        }
         // Convert `AssertKind<Operand>` to `AssertKind<Scalar>`.
         let eval_to_int =
             |op| ecx.read_immediate(&ecx.eval_operand(op, None)?).map(|x| x.to_const_int());
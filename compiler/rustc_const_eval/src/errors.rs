@@ -749,6 +749,7 @@ fn add_range_arg<G: EmissionGuarantee>(
                     ExpectedKind::EnumTag => fluent::const_eval_validation_expected_enum_tag,
                     ExpectedKind::Str => fluent::const_eval_validation_expected_str,
                     ExpectedKind::Field => fluent::const_eval_validation_expected_field,
+                    ExpectedKind::Curve => fluent::const_eval_validation_expected_curve,
                 };
                 let msg = handler.eagerly_translate_to_string(msg, [].into_iter());
                 err.set_arg("expected", msg);
@@ -2,7 +2,7 @@
 
 use rustc_apfloat::ieee::{Double, Single};
 use rustc_apfloat::{Float, FloatConvert};
-use rustc_middle::mir::interpret::{InterpResult, PointerArithmetic, Scalar};
+use rustc_middle::mir::interpret::{InterpResult, PointerArithmetic, Scalar, ScalarField};
 use rustc_middle::mir::CastKind;
 use rustc_middle::ty::adjustment::PointerCoercion;
 use rustc_middle::ty::layout::{IntegerExt, LayoutOf, TyAndLayout};
@@ -55,6 +55,14 @@ pub fn cast(
                 self.write_immediate(res, dest)?;
             }
 
+            CastKind::IntToField => {
+                let src = self.read_immediate(src)?;
+                let bits = src.to_scalar().to_bits(src.layout.size)?;
+                let size = self.layout_of(cast_ty)?.size;
+                // Integers are never larger than the modulus, so there is nothing to reduce.
+                self.write_immediate(Immediate::Field(ScalarField::from_uint(bits, size)), dest)?;
+            }
+
             CastKind::FnPtrToPtr | CastKind::PtrToPtr => {
                 let src = self.read_immediate(src)?;
                 let res = self.ptr_to_ptr(&src, cast_ty)?;
@@ -214,7 +222,7 @@ pub fn ptr_to_ptr(
             assert!(src.layout.ty.is_unsafe_ptr());
             return match **src {
                 Immediate::ScalarPair(data, _) => Ok(data.into()),
-                Immediate::Scalar(..) => span_bug!(
+                Immediate::Scalar(..) | Immediate::Field(..) => span_bug!(
                     self.cur_span(),
                     "{:?} input to a fat-to-thin cast ({:?} -> {:?})",
                     *src,
@@ -222,7 +230,6 @@ pub fn ptr_to_ptr(
                     cast_ty
                 ),
                 Immediate::Uninit => throw_ub!(InvalidUninitBytes(None)),
-                Immediate::Field(_) => todo!(),
             };
         }
     }
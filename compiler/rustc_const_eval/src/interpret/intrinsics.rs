@@ -7,6 +7,7 @@
     self,
     interpret::{
         Allocation, ConstAllocation, ConstValue, GlobalId, InterpResult, PointerArithmetic, Scalar,
+        ScalarCurve,
     },
     BinOp, NonDivergingIntrinsic,
 };
@@ -18,8 +19,8 @@
 use rustc_target::abi::{Abi, Align, Primitive, Size};
 
 use super::{
-    util::ensure_monomorphic_enough, CheckInAllocMsg, ImmTy, InterpCx, Machine, OpTy, PlaceTy,
-    Pointer,
+    util::ensure_monomorphic_enough, CheckInAllocMsg, ImmTy, Immediate, InterpCx, Machine, OpTy,
+    PlaceTy, Pointer, Projectable,
 };
 
 use crate::fluent_generated as fluent;
@@ -469,6 +470,64 @@ pub fn emulate_intrinsic(
                 self.write_scalar(Scalar::from_target_usize(align.bytes(), self), dest)?;
             }
 
+            name if name.as_str().starts_with("assigner_curve_init_") => {
+                let &ty::Curve(curve_ty) = dest.layout.ty.kind() else {
+                    span_bug!(self.cur_span(), "{name} must return a curve type")
+                };
+                let x = self.read_immediate(&args[0])?.to_field();
+                let y = self.read_immediate(&args[1])?.to_field();
+                let point = ScalarCurve::new(x, y);
+                if !point.is_on_curve(curve_ty) {
+                    throw_ub_custom!(
+                        fluent::const_eval_curve_point_not_on_curve,
+                        point = format!("{point:?}"),
+                        ty = dest.layout.ty,
+                    );
+                }
+                self.write_curve(point, dest)?;
+            }
+            name if name.as_str().starts_with("assigner_curve_x_")
+                || name.as_str().starts_with("assigner_curve_y_") =>
+            {
+                let point = self.read_curve(&args[0])?;
+                let coordinate =
+                    if name.as_str().starts_with("assigner_curve_x_") { point.x() } else { point.y() };
+                self.write_immediate(Immediate::Field(coordinate), dest)?;
+            }
+            name if name.as_str() == "assigner_g2_init" || name.as_str() == "assigner_gt_init" => {
+                // Values of these types are their coefficients laid out one after another.
+                let dest = self.force_allocation(dest)?;
+                for (i, arg) in args.iter().enumerate() {
+                    let coefficient = dest.offset(arg.layout.size * i as u64, arg.layout, self)?;
+                    self.copy_op(arg, &coefficient, /*allow_transmute*/ false)?;
+                }
+            }
+            name if name.as_str() == "assigner_g2_coordinates"
+                || name.as_str() == "assigner_gt_coefficients" =>
+            {
+                self.copy_op(&args[0], dest, /*allow_transmute*/ true)?;
+            }
+            name if name.as_str() == "assigner_field_to_int" => {
+                let value = self.read_immediate(&args[0])?.to_field();
+                let size = dest.layout.size;
+                self.write_scalar(Scalar::from_uint(value.truncate_to_uint(size), size), dest)?;
+            }
+            name if name.as_str().starts_with("assigner_is_on_curve_") => {
+                let curve_ty = match &name.as_str()["assigner_is_on_curve_".len()..] {
+                    "bls12381" => ty::CurveTy::Bls12381,
+                    "bn254" => ty::CurveTy::Bn254,
+                    "secp256k1" => ty::CurveTy::Secp256k1,
+                    "curve25519" => ty::CurveTy::Curve25519,
+                    "pallas" => ty::CurveTy::Pallas,
+                    "vesta" => ty::CurveTy::Vesta,
+                    _ => span_bug!(self.cur_span(), "unknown curve in {name}"),
+                };
+                let x = self.read_immediate(&args[0])?.to_field();
+                let y = self.read_immediate(&args[1])?.to_field();
+                let on_curve = ScalarCurve::new(x, y).is_on_curve(curve_ty);
+                self.write_scalar(Scalar::from_bool(on_curve), dest)?;
+            }
+
             _ => return Ok(false),
         }
 
@@ -18,7 +18,7 @@
     InterpCx, InterpResult, MPlaceTy, Machine, MemPlace, MemPlaceMeta, PlaceTy, Pointer,
     Projectable, Provenance, Scalar,
 };
-use super::ScalarField;
+use super::{ScalarCurve, ScalarField};
 
 /// An `Immediate` represents a single immediate self-contained Rust value.
 ///
@@ -480,7 +480,8 @@ fn read_immediate_from_mplace_raw(
                 )?;
                 Some(ImmTy { imm: Immediate::ScalarPair(a_val, b_val), layout: mplace.layout })
             }
-            Abi::Field(f) => {
+            // Extension field elements are kept in memory, like curve points.
+            Abi::Field(f) if !f.is_extension() => {
                 let size = f.size();
                 assert_eq!(size, mplace.layout.size, "abi::Scalar size does not match layout size");
                 let field = alloc.read_field(alloc_range(Size::ZERO, size))?;
@@ -529,7 +530,7 @@ pub fn read_immediate(
             op.layout().abi,
             Abi::Scalar(abi::Scalar::Initialized { .. })
                 | Abi::ScalarPair(abi::Scalar::Initialized { .. }, abi::Scalar::Initialized { .. })
-                | Abi::Field(..) | Abi::Curve(..)
+                | Abi::Field(..)
         ) {
             span_bug!(
                 self.cur_span(),
@@ -552,6 +553,33 @@ pub fn read_scalar(
         Ok(self.read_immediate(op)?.to_scalar())
     }
 
+    /// Read a curve point from a place.
+    ///
+    /// Curve values never fit into an `Immediate`, so they always live in memory
+    /// as two consecutive base field coordinates.
+    pub fn read_curve(
+        &self,
+        op: &impl Readable<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx, ScalarCurve> {
+        let Abi::Curve(c) = op.layout().abi else {
+            span_bug!(self.cur_span(), "curve read not possible for type: {:?}", op.layout().ty)
+        };
+        let mplace = match op.as_mplace_or_imm() {
+            Left(mplace) => mplace,
+            Right(imm) => match *imm {
+                Immediate::Uninit => throw_ub!(InvalidUninitBytes(None)),
+                _ => span_bug!(self.cur_span(), "curve value stored as immediate: {imm:?}"),
+            },
+        };
+        let Some(alloc) = self.get_place_alloc(&mplace)? else {
+            span_bug!(self.cur_span(), "curve types are never zero-sized")
+        };
+        let size = c.base_field().size();
+        let x = alloc.read_field(alloc_range(Size::ZERO, size))?;
+        let y = alloc.read_field(alloc_range(size, size))?;
+        Ok(ScalarCurve::new(x, y))
+    }
+
     // Pointer-sized reads are fairly common and need target layout access, so we wrap them in
     // convenience functions.
 
@@ -1,12 +1,12 @@
 use rustc_apfloat::Float;
 use rustc_middle::mir;
-use rustc_middle::mir::interpret::{InterpResult, Scalar};
+use rustc_middle::mir::interpret::{InterpResult, Scalar, ScalarCurve, ScalarField};
 use rustc_middle::ty::layout::{LayoutOf, TyAndLayout};
-use rustc_middle::ty::{self, FloatTy, Ty};
+use rustc_middle::ty::{self, CurveTy, FieldTy, FloatTy, Ty};
 use rustc_span::symbol::sym;
 use rustc_target::abi::Abi;
 
-use super::{ImmTy, Immediate, InterpCx, Machine, PlaceTy};
+use super::{ImmTy, Immediate, InterpCx, Machine, OpTy, PlaceTy};
 
 use crate::fluent_generated as fluent;
 
@@ -54,10 +54,117 @@ pub fn binop_ignore_overflow(
         right: &ImmTy<'tcx, M::Provenance>,
         dest: &PlaceTy<'tcx, M::Provenance>,
     ) -> InterpResult<'tcx> {
+        if left.layout.ty.is_field() {
+            // Field arithmetic produces a field immediate rather than a scalar.
+            let res = self.field_binary_op(op, left, right)?;
+            assert_eq!(res.layout.ty, dest.layout.ty, "type mismatch for result of {op:?}");
+            return self.write_immediate(*res, dest);
+        }
         let (val, _overflowed, ty) = self.overflowing_binary_op(op, left, right)?;
         assert_eq!(ty, dest.layout.ty, "type mismatch for result of {op:?}");
         self.write_scalar(val, dest)
     }
+
+    /// Applies the binary operation `op` to the arguments, at least one of which is a curve
+    /// point, and writes the result to the destination.
+    ///
+    /// Curve values do not fit into an `Immediate`, so unlike other binary operations this one
+    /// works on operands and places directly.
+    pub fn curve_binop(
+        &mut self,
+        op: mir::BinOp,
+        left: &OpTy<'tcx, M::Provenance>,
+        right: &OpTy<'tcx, M::Provenance>,
+        dest: &PlaceTy<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx> {
+        use rustc_middle::mir::BinOp::*;
+
+        trace!("Running curve binary op {:?}: {:?}, {:?}", op, left.layout.ty, right.layout.ty);
+
+        let res = match (op, left.layout.ty.kind(), right.layout.ty.kind()) {
+            (Eq | Ne, ty::Curve(_), ty::Curve(_)) => {
+                let l = self.read_curve(left)?;
+                let r = self.read_curve(right)?;
+                return self.write_scalar(Scalar::from_bool((l == r) == (op == Eq)), dest);
+            }
+            (Add, &ty::Curve(curve_ty), ty::Curve(_)) => {
+                let l = self.read_curve_point(left, curve_ty)?;
+                let r = self.read_curve_point(right, curve_ty)?;
+                l.add(r, curve_ty)
+            }
+            (Sub, &ty::Curve(curve_ty), ty::Curve(_)) => {
+                let l = self.read_curve_point(left, curve_ty)?;
+                let r = self.read_curve_point(right, curve_ty)?;
+                l.sub(r, curve_ty)
+            }
+            (Mul, &ty::Curve(curve_ty), ty::Field(_)) => {
+                let l = self.read_curve_point(left, curve_ty)?;
+                let r = self.read_immediate(right)?.to_field();
+                l.mul_scalar(r, curve_ty)
+            }
+            (Mul, ty::Field(_), &ty::Curve(curve_ty)) => {
+                let l = self.read_immediate(left)?.to_field();
+                let r = self.read_curve_point(right, curve_ty)?;
+                r.mul_scalar(l, curve_ty)
+            }
+            (Div, &ty::Curve(curve_ty), ty::Field(_)) => {
+                let l = self.read_curve_point(left, curve_ty)?;
+                let r = self.read_immediate(right)?.to_field();
+                let Some(inv) = r.inv_mod(curve_ty.scalar_field()) else {
+                    throw_ub!(DivisionByZero)
+                };
+                l.mul_scalar(inv, curve_ty)
+            }
+            _ => span_bug!(
+                self.cur_span(),
+                "invalid curve op {:?}: {:?}, {:?}",
+                op,
+                left.layout.ty,
+                right.layout.ty,
+            ),
+        };
+        let res_ty = if left.layout.ty.is_curve() { left.layout.ty } else { right.layout.ty };
+        assert_eq!(res_ty, dest.layout.ty, "type mismatch for result of {op:?}");
+        self.write_curve(res, dest)
+    }
+
+    /// Applies the unary operation `op` to a curve point and writes the result to the
+    /// destination.
+    pub fn curve_unop(
+        &mut self,
+        op: mir::UnOp,
+        val: &OpTy<'tcx, M::Provenance>,
+        dest: &PlaceTy<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx> {
+        use rustc_middle::mir::UnOp::*;
+
+        let &ty::Curve(curve_ty) = val.layout.ty.kind() else {
+            span_bug!(self.cur_span(), "curve_unop called on non-curve type {:?}", val.layout.ty)
+        };
+        let res = match op {
+            Neg => self.read_curve_point(val, curve_ty)?.neg(curve_ty),
+            Not => span_bug!(self.cur_span(), "invalid curve op {:?}: {:?}", op, val.layout.ty),
+        };
+        assert_eq!(val.layout.ty, dest.layout.ty, "type mismatch for result of {op:?}");
+        self.write_curve(res, dest)
+    }
+
+    /// Reads an operand of a curve group operation, making sure it is a point of the curve.
+    fn read_curve_point(
+        &self,
+        op: &OpTy<'tcx, M::Provenance>,
+        curve_ty: CurveTy,
+    ) -> InterpResult<'tcx, ScalarCurve> {
+        let point = self.read_curve(op)?;
+        if !point.is_on_curve(curve_ty) {
+            throw_ub_custom!(
+                fluent::const_eval_curve_point_not_on_curve,
+                point = format!("{point:?}"),
+                ty = op.layout.ty,
+            );
+        }
+        Ok(point)
+    }
 }
 
 impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
@@ -320,6 +427,49 @@ fn binary_int_op(
         Ok((val, false, ty))
     }
 
+    fn binary_field_op(
+        &self,
+        bin_op: mir::BinOp,
+        ty: Ty<'tcx>,
+        field_ty: FieldTy,
+        l: ScalarField,
+        r: ScalarField,
+    ) -> InterpResult<'tcx, (Immediate<M::Provenance>, Ty<'tcx>)> {
+        use rustc_middle::mir::BinOp::*;
+
+        // Field values are always kept reduced, so comparing them compares
+        // their canonical representatives.
+        let (val, ty) = match bin_op {
+            Eq => (Scalar::from_bool(l.data() == r.data()).into(), self.tcx.types.bool),
+            Ne => (Scalar::from_bool(l.data() != r.data()).into(), self.tcx.types.bool),
+            Lt => (Scalar::from_bool(l.data() < r.data()).into(), self.tcx.types.bool),
+            Le => (Scalar::from_bool(l.data() <= r.data()).into(), self.tcx.types.bool),
+            Gt => (Scalar::from_bool(l.data() > r.data()).into(), self.tcx.types.bool),
+            Ge => (Scalar::from_bool(l.data() >= r.data()).into(), self.tcx.types.bool),
+
+            Add => (l.add_mod(r, field_ty).into(), ty),
+            Sub => (l.sub_mod(r, field_ty).into(), ty),
+            Mul => (l.mul_mod(r, field_ty).into(), ty),
+            Div => match l.div_mod(r, field_ty) {
+                Some(res) => (res.into(), ty),
+                None => throw_ub!(DivisionByZero),
+            },
+            // Every non-zero element of a field divides any other, so the remainder is zero.
+            Rem if r.is_zero() => throw_ub!(RemainderByZero),
+            Rem => (ScalarField::zero(l.size()).into(), ty),
+
+            _ => span_bug!(
+                self.cur_span(),
+                "invalid field op {:?}: {:?}, {:?} (both {:?})",
+                bin_op,
+                l,
+                r,
+                ty,
+            ),
+        };
+        Ok((val, ty))
+    }
+
     fn binary_ptr_op(
         &self,
         bin_op: mir::BinOp,
@@ -388,6 +538,19 @@ pub fn overflowing_binary_op(
                     }
                 })
             }
+            ty::Field(_) => {
+                use rustc_middle::mir::BinOp::*;
+
+                // Fields never overflow. Only comparisons produce a `Scalar` though,
+                // arithmetic has to go through `binary_op` or `field_binary_op`.
+                if !matches!(bin_op, Eq | Ne | Lt | Le | Gt | Ge) {
+                    throw_unsup_format!(
+                        "field operation {bin_op:?} does not produce a scalar and cannot overflow"
+                    );
+                }
+                let res = self.field_binary_op(bin_op, left, right)?;
+                Ok((res.to_scalar(), false, res.layout.ty))
+            }
             _ if left.layout.ty.is_integral() => {
                 // the RHS type can be different, e.g. for shifts -- but it has to be integral, too
                 assert!(
@@ -431,10 +594,35 @@ pub fn binary_op(
         left: &ImmTy<'tcx, M::Provenance>,
         right: &ImmTy<'tcx, M::Provenance>,
     ) -> InterpResult<'tcx, ImmTy<'tcx, M::Provenance>> {
+        if left.layout.ty.is_field() {
+            return self.field_binary_op(bin_op, left, right);
+        }
         let (val, _overflow, ty) = self.overflowing_binary_op(bin_op, left, right)?;
         Ok(ImmTy::from_scalar(val, self.layout_of(ty)?))
     }
 
+    /// Applies a binary operation to two field elements of the same type.
+    /// Arithmetic is performed modulo the field modulus.
+    pub fn field_binary_op(
+        &self,
+        bin_op: mir::BinOp,
+        left: &ImmTy<'tcx, M::Provenance>,
+        right: &ImmTy<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx, ImmTy<'tcx, M::Provenance>> {
+        let ty::Field(field_ty) = *left.layout.ty.kind() else {
+            span_bug!(self.cur_span(), "Invalid field op on non-field type {:?}", left.layout.ty)
+        };
+        assert_eq!(left.layout.ty, right.layout.ty);
+        let (val, ty) = self.binary_field_op(
+            bin_op,
+            left.layout.ty,
+            field_ty,
+            left.to_field(),
+            right.to_field(),
+        )?;
+        Ok(ImmTy::from_immediate(val, self.layout_of(ty)?))
+    }
+
     /// Returns the result of the specified operation, whether it overflowed, and
     /// the result type.
     pub fn overflowing_unary_op(
@@ -492,7 +680,28 @@ pub fn unary_op(
         un_op: mir::UnOp,
         val: &ImmTy<'tcx, M::Provenance>,
     ) -> InterpResult<'tcx, ImmTy<'tcx, M::Provenance>> {
+        if val.layout.ty.is_field() {
+            return self.field_unary_op(un_op, val);
+        }
         let (val, _overflow, ty) = self.overflowing_unary_op(un_op, val)?;
         Ok(ImmTy::from_scalar(val, self.layout_of(ty)?))
     }
+
+    /// Applies a unary operation to a field element. Negation never overflows.
+    pub fn field_unary_op(
+        &self,
+        un_op: mir::UnOp,
+        val: &ImmTy<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx, ImmTy<'tcx, M::Provenance>> {
+        use rustc_middle::mir::UnOp::*;
+
+        let ty::Field(field_ty) = *val.layout.ty.kind() else {
+            span_bug!(self.cur_span(), "Invalid field op on non-field type {:?}", val.layout.ty)
+        };
+        let res = match un_op {
+            Neg => val.to_field().neg_mod(field_ty),
+            _ => span_bug!(self.cur_span(), "Invalid field op {:?}", un_op),
+        };
+        Ok(ImmTy::from_immediate(res.into(), val.layout))
+    }
 }
@@ -18,7 +18,7 @@
 use super::{
     alloc_range, mir_assign_valid_types, AllocId, AllocRef, AllocRefMut, CheckInAllocMsg,
     ConstAlloc, ImmTy, Immediate, InterpCx, InterpResult, Machine, MemoryKind, OpTy, Operand,
-    Pointer, Projectable, Provenance, Readable, Scalar,
+    Pointer, Projectable, Provenance, Readable, Scalar, ScalarCurve,
 };
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
@@ -612,6 +612,33 @@ pub fn write_pointer(
         self.write_scalar(Scalar::from_maybe_pointer(ptr.into(), self), dest)
     }
 
+    /// Write a curve point to a place.
+    ///
+    /// Curve values are never kept as an `Immediate`, so this always forces `dest` into memory.
+    pub fn write_curve(
+        &mut self,
+        val: ScalarCurve,
+        dest: &impl Writeable<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx> {
+        let Abi::Curve(c) = dest.layout().abi else {
+            span_bug!(self.cur_span(), "write_curve: invalid Curve layout: {:#?}", dest.layout())
+        };
+        let mplace = dest.force_mplace(self)?;
+        let Some(mut alloc) = self.get_place_alloc_mut(&mplace)? else {
+            span_bug!(self.cur_span(), "curve types are never zero-sized")
+        };
+        let size = c.base_field().size();
+        alloc.write_field(alloc_range(Size::ZERO, size), val.x())?;
+        alloc.write_field(alloc_range(size, size), val.y())?;
+
+        if M::enforce_validity(self, dest.layout()) {
+            // Data got changed, better make sure it matches the type!
+            self.validate_operand(&dest.to_op(self)?)?;
+        }
+
+        Ok(())
+    }
+
     /// Write an immediate to a place.
     /// If you use this you are responsible for validating that things got copied at the
     /// right type.
@@ -159,11 +159,20 @@ pub fn eval_rvalue_into_place(
             }
 
             BinaryOp(bin_op, box (ref left, ref right)) => {
-                let layout = util::binop_left_homogeneous(bin_op).then_some(dest.layout);
-                let left = self.read_immediate(&self.eval_operand(left, layout)?)?;
-                let layout = util::binop_right_homogeneous(bin_op).then_some(left.layout);
-                let right = self.read_immediate(&self.eval_operand(right, layout)?)?;
-                self.binop_ignore_overflow(bin_op, &left, &right, &dest)?;
+                // Curve multiplication is not homogeneous: one of the operands is a field value.
+                let is_curve_op = dest.layout.ty.is_curve();
+                let layout = (util::binop_left_homogeneous(bin_op) && !is_curve_op)
+                    .then_some(dest.layout);
+                let left = self.eval_operand(left, layout)?;
+                if is_curve_op || left.layout.ty.is_curve() {
+                    let right = self.eval_operand(right, None)?;
+                    self.curve_binop(bin_op, &left, &right, &dest)?;
+                } else {
+                    let left = self.read_immediate(&left)?;
+                    let layout = util::binop_right_homogeneous(bin_op).then_some(left.layout);
+                    let right = self.read_immediate(&self.eval_operand(right, layout)?)?;
+                    self.binop_ignore_overflow(bin_op, &left, &right, &dest)?;
+                }
             }
 
             CheckedBinaryOp(bin_op, box (ref left, ref right)) => {
@@ -174,6 +183,11 @@ pub fn eval_rvalue_into_place(
                 self.binop_with_overflow(bin_op, &left, &right, &dest)?;
             }
 
+            UnaryOp(un_op, ref operand) if dest.layout.ty.is_curve() => {
+                let val = self.eval_operand(operand, Some(dest.layout))?;
+                self.curve_unop(un_op, &val, &dest)?;
+            }
+
             UnaryOp(un_op, ref operand) => {
                 // The operand always has the same type as the result.
                 let val = self.read_immediate(&self.eval_operand(operand, Some(dest.layout))?)?;
@@ -447,6 +447,19 @@ pub(crate) fn eval_fn_call(
         match instance.def {
             ty::InstanceDef::Intrinsic(def_id) => {
                 assert!(self.tcx.is_intrinsic(def_id));
+                if let Some(fallback) = self.tcx.assigner_intrinsic_fallback(instance) {
+                    // Run the software implementation, it takes the same arguments.
+                    let fn_abi = self.fn_abi_of_instance(fallback, ty::List::empty())?;
+                    return self.eval_fn_call(
+                        FnVal::Instance(fallback),
+                        (Abi::Rust, fn_abi),
+                        args,
+                        false,
+                        destination,
+                        target,
+                        unwind,
+                    );
+                }
                 // FIXME: Should `InPlace` arguments be reset to uninit?
                 M::call_intrinsic(
                     self,
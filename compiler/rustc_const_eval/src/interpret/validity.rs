@@ -30,7 +30,7 @@
     AllocId, CheckInAllocMsg, GlobalAlloc, ImmTy, Immediate, InterpCx, InterpResult, MPlaceTy,
     Machine, MemPlaceMeta, OpTy, Pointer, Projectable, Scalar, ValueVisitor,
 };
-use super::ScalarField;
+use super::{ScalarCurve, ScalarField};
 
 // for the validation errors
 use super::InterpError::UndefinedBehavior as Ub;
@@ -326,6 +326,24 @@ fn read_field(
         Ok(self.read_immediate(op, expected)?.to_field())
     }
 
+    fn read_curve(
+        &self,
+        op: &OpTy<'tcx, M::Provenance>,
+        expected: ExpectedKind,
+    ) -> InterpResult<'tcx, ScalarCurve> {
+        Ok(try_validation!(
+            self.ecx.read_curve(op),
+            self.path,
+            Ub(InvalidUninitBytes(None)) =>
+                Uninit { expected },
+            // The `Unsup` cases can only occur during CTFE
+            Unsup(ReadPointerAsInt(_)) =>
+                PointerAsInt { expected },
+            Unsup(ReadPartialPointer(_)) =>
+                PartialPointer,
+        ))
+    }
+
     fn check_wide_ptr_meta(
         &mut self,
         meta: MemPlaceMeta<M::Provenance>,
@@ -531,6 +549,12 @@ fn try_visit_primitive(
                 self.read_field(value, ExpectedKind::Field)?;
                 Ok(true)
             }
+            ty::Curve(_) => {
+                // Only check that the coordinates are initialized. Points which are not on
+                // the curve are reported when they are used in curve arithmetic.
+                self.read_curve(value, ExpectedKind::Curve)?;
+                Ok(true)
+            }
             ty::RawPtr(..) => {
                 let place =
                     self.ecx.ref_to_mplace(&self.read_immediate(value, ExpectedKind::RawPtr)?)?;
@@ -592,7 +616,6 @@ fn try_visit_primitive(
             // Some types only occur during typechecking, they have no layout.
             // We should not see them here and we could not check them anyway.
             ty::Error(_)
-            | ty::Curve(_)
             | ty::Infer(..)
             | ty::Placeholder(..)
             | ty::Bound(..)
@@ -895,11 +918,11 @@ fn visit_value(&mut self, op: &OpTy<'tcx, M::Provenance>) -> InterpResult<'tcx>
                     self.visit_scalar(b, b_layout)?;
                 }
             }
-            Abi::Field(f_layout) => {
+            Abi::Field(f_layout) if !f_layout.is_extension() => {
                 let field = self.read_field(op, ExpectedKind::Field)?;
                 self.visit_scalar_field(field, f_layout)?;
             }
-            Abi::Curve(_) => {
+            Abi::Field(_) | Abi::Curve(_) => {
                 // Do nothing.
             }
             Abi::Vector { .. } => {
@@ -1027,6 +1027,14 @@ macro_rules! check_kinds {
                             );
                         }
                     }
+                    CastKind::IntToField => {
+                        if !matches!(op_ty.kind(), ty::Uint(..)) || !target_type.is_field() {
+                            self.fail(
+                                location,
+                                format!("Wrong cast kind {kind:?} for the type {op_ty}"),
+                            );
+                        }
+                    }
                     CastKind::Transmute => {
                         if let MirPhase::Runtime(..) = self.mir_phase {
                             // Unlike `mem::transmute`, a MIR `Transmute` is well-formed
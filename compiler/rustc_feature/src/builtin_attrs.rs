@@ -382,6 +382,8 @@ pub struct BuiltinAttribute {
 
     // Code generation:
     ungated!(circuit, Normal, template!(Word), WarnFollowing),
+    ungated!(public_input, Normal, template!(Word), WarnFollowing),
+    ungated!(private_input, Normal, template!(Word), WarnFollowing),
     ungated!(inline, Normal, template!(Word, List: "always|never"), FutureWarnFollowing, @only_local: true),
     ungated!(cold, Normal, template!(Word), WarnFollowing, @only_local: true),
     ungated!(no_builtins, CrateLevel, template!(Word), WarnFollowing),
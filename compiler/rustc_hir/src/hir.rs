@@ -2581,7 +2581,7 @@ pub enum PrimTy {
 
 impl PrimTy {
     /// All of the primitive types
-    pub const ALL: [Self; 27] = [
+    pub const ALL: [Self; 35] = [
         // any changes here should also be reflected in `PrimTy::from_name`
         Self::Int(IntTy::I8),
         Self::Int(IntTy::I16),
@@ -2597,14 +2597,22 @@ impl PrimTy {
         Self::Uint(UintTy::Usize),
         Self::Field(FieldTy::Bls12381Base),
         Self::Field(FieldTy::Bls12381Scalar),
+        Self::Field(FieldTy::Bn254Base),
+        Self::Field(FieldTy::Bn254Scalar),
+        Self::Field(FieldTy::Secp256k1Base),
+        Self::Field(FieldTy::Secp256k1Scalar),
         Self::Field(FieldTy::Curve25519Base),
         Self::Field(FieldTy::Curve25519Scalar),
         Self::Field(FieldTy::PallasBase),
         Self::Field(FieldTy::PallasScalar),
+        Self::Field(FieldTy::Bls12381Gt),
         Self::Curve(CurveTy::Bls12381),
+        Self::Curve(CurveTy::Bn254),
+        Self::Curve(CurveTy::Secp256k1),
         Self::Curve(CurveTy::Curve25519),
         Self::Curve(CurveTy::Pallas),
         Self::Curve(CurveTy::Vesta),
+        Self::Curve(CurveTy::Bls12381G2),
         Self::Float(FloatTy::F32),
         Self::Float(FloatTy::F64),
         Self::Bool,
@@ -2647,11 +2655,19 @@ pub fn from_name(name: Symbol) -> Option<Self> {
         let ty = match name {
             // any changes here should also be reflected in `PrimTy::ALL`
             sym::__zkllvm_curve_bls12381 => Self::Curve(CurveTy::Bls12381),
+            sym::__zkllvm_curve_bn254 => Self::Curve(CurveTy::Bn254),
+            sym::__zkllvm_curve_secp256k1 => Self::Curve(CurveTy::Secp256k1),
+            sym::__zkllvm_curve_bls12381_g2 => Self::Curve(CurveTy::Bls12381G2),
             sym::__zkllvm_curve_curve25519 => Self::Curve(CurveTy::Curve25519),
             sym::__zkllvm_curve_pallas => Self::Curve(CurveTy::Pallas),
             sym::__zkllvm_curve_vesta => Self::Curve(CurveTy::Vesta),
             sym::__zkllvm_field_bls12381_base => Self::Field(FieldTy::Bls12381Base),
+            sym::__zkllvm_field_bls12381_gt => Self::Field(FieldTy::Bls12381Gt),
             sym::__zkllvm_field_bls12381_scalar => Self::Field(FieldTy::Bls12381Scalar),
+            sym::__zkllvm_field_bn254_base => Self::Field(FieldTy::Bn254Base),
+            sym::__zkllvm_field_bn254_scalar => Self::Field(FieldTy::Bn254Scalar),
+            sym::__zkllvm_field_secp256k1_base => Self::Field(FieldTy::Secp256k1Base),
+            sym::__zkllvm_field_secp256k1_scalar => Self::Field(FieldTy::Secp256k1Scalar),
             sym::__zkllvm_field_curve25519_base => Self::Field(FieldTy::Curve25519Base),
             sym::__zkllvm_field_curve25519_scalar => Self::Field(FieldTy::Curve25519Scalar),
             sym::__zkllvm_field_pallas_base => Self::Field(FieldTy::PallasBase),
@@ -327,6 +327,35 @@ pub fn extract(attrs: &[ast::Attribute]) -> Option<(Symbol, Span)> {
 
     String,                  sym::String,              string,                     Target::Struct,         GenericRequirement::None;
     CStr,                    sym::CStr,                c_str,                      Target::Struct,         GenericRequirement::None;
+
+    // Software field and curve arithmetic, used on targets without native field types.
+    SoftFieldAdd,            sym::soft_field_add,      soft_field_add_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftFieldSub,            sym::soft_field_sub,      soft_field_sub_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftFieldMul,            sym::soft_field_mul,      soft_field_mul_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftFieldDiv,            sym::soft_field_div,      soft_field_div_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftFieldNeg,            sym::soft_field_neg,      soft_field_neg_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftCurveAdd,            sym::soft_curve_add,      soft_curve_add_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftCurveSub,            sym::soft_curve_sub,      soft_curve_sub_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftCurveMul,            sym::soft_curve_mul,      soft_curve_mul_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftCurveDiv,            sym::soft_curve_div,      soft_curve_div_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    SoftCurveNeg,            sym::soft_curve_neg,      soft_curve_neg_fn,          Target::Fn,             GenericRequirement::Exact(1);
+
+    // Software implementations of the `assigner_*` intrinsics, used on targets other than the assigner.
+    ZkExitCheck,             sym::zk_exit_check,       zk_exit_check_fn,           Target::Fn,             GenericRequirement::Exact(0);
+    ZkSha2_256,              sym::zk_sha2_256,         zk_sha2_256_fn,             Target::Fn,             GenericRequirement::Exact(0);
+    ZkSha2_256Bls12381,      sym::zk_sha2_256_bls12381, zk_sha2_256_bls12381_fn,    Target::Fn,             GenericRequirement::Exact(0);
+    ZkSha2_256Compress,      sym::zk_sha2_256_compress, zk_sha2_256_compress_fn,    Target::Fn,             GenericRequirement::Exact(0);
+    ZkSha2_512,              sym::zk_sha2_512,         zk_sha2_512_fn,             Target::Fn,             GenericRequirement::Exact(0);
+    ZkBls12OptimalAtePairing, sym::zk_bls12_optimal_ate_pairing, zk_bls12_optimal_ate_pairing_fn, Target::Fn,             GenericRequirement::Exact(0);
+    ZkHashToCurve,           sym::zk_hash_to_curve,    zk_hash_to_curve_fn,        Target::Fn,             GenericRequirement::Exact(0);
+    ZkIsInG1Check,           sym::zk_is_in_g1_check,   zk_is_in_g1_check_fn,       Target::Fn,             GenericRequirement::Exact(0);
+    ZkIsInG2Check,           sym::zk_is_in_g2_check,   zk_is_in_g2_check_fn,       Target::Fn,             GenericRequirement::Exact(0);
+    ZkIsOnCurve,             sym::zk_is_on_curve,      zk_is_on_curve_fn,          Target::Fn,             GenericRequirement::Exact(1);
+    ZkGtMultiplication,      sym::zk_gt_multiplication, zk_gt_multiplication_fn,    Target::Fn,             GenericRequirement::Exact(0);
+    ZkEcdsaVerify,           sym::zk_ecdsa_verify,     zk_ecdsa_verify_fn,         Target::Fn,             GenericRequirement::Exact(0);
+    ZkCurve25519Decompress,  sym::zk_curve25519_decompress, zk_curve25519_decompress_fn, Target::Fn,             GenericRequirement::Exact(0);
+    ZkPoseidon,              sym::zk_poseidon,         zk_poseidon_fn,             Target::Fn,             GenericRequirement::Exact(1);
+    ZkMsm,                   sym::zk_msm,              zk_msm_fn,                  Target::Fn,             GenericRequirement::Exact(1);
 }
 
 pub enum GenericRequirement {
@@ -184,25 +184,45 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
         };
         (n_tps, 0, inputs, output, hir::Unsafety::Unsafe)
     } else if let Some(name) = name_str.strip_prefix("assigner_") {
+        let curve_type = |curve_name| match curve_name {
+            "bls12381" => Some(tcx.types.__zkllvm_curve_bls12381),
+            "bn254" => Some(tcx.types.__zkllvm_curve_bn254),
+            "secp256k1" => Some(tcx.types.__zkllvm_curve_secp256k1),
+            "curve25519" => Some(tcx.types.__zkllvm_curve_curve25519),
+            "pallas" => Some(tcx.types.__zkllvm_curve_pallas),
+            "vesta" => Some(tcx.types.__zkllvm_curve_vesta),
+            _ => None,
+        };
         let (n_tps, inputs, output) = match name {
             "exit_check" => (0, vec![tcx.types.bool], Ty::new_unit(tcx)),
-            _ if let Some(curve_name) = name.strip_prefix("curve_init_") => {
-                let curve_type = match curve_name {
-                    "bls12381" => tcx.types.__zkllvm_curve_bls12381,
-                    "curve25519" => tcx.types.__zkllvm_curve_curve25519,
-                    "pallas" => tcx.types.__zkllvm_curve_pallas,
-                    "vesta" => tcx.types.__zkllvm_curve_vesta,
-                    _ => {
-                        tcx.sess.emit_err(UnrecognizedIntrinsicFunction {
-                            span: it.span,
-                            name: intrinsic_name,
-                        });
-                        return;
-                    }
-                };
+            "field_to_int" => (1, vec![param(0)], tcx.types.u128),
+            _ if let Some(curve_type) = name.strip_prefix("curve_init_").and_then(curve_type) => {
                 let base_type = curve_type.curve_base_field(tcx);
                 (0, vec![base_type, base_type], curve_type)
             }
+            _ if let Some(curve_type) = name
+                .strip_prefix("curve_x_")
+                .or_else(|| name.strip_prefix("curve_y_"))
+                .and_then(curve_type) =>
+            {
+                (0, vec![curve_type], curve_type.curve_base_field(tcx))
+            }
+            _ if let Some(curve_type) = name.strip_prefix("is_on_curve_").and_then(curve_type) => {
+                let base_type = curve_type.curve_base_field(tcx);
+                (0, vec![base_type, base_type], tcx.types.bool)
+            }
+            _ if let Some(curve_type) = name.strip_prefix("msm_").and_then(curve_type) => {
+                let scalar_type = curve_type.curve_scalar_field(tcx);
+                (
+                    0,
+                    vec![
+                        Ty::new_imm_ptr(tcx, curve_type),
+                        Ty::new_imm_ptr(tcx, scalar_type),
+                        tcx.types.usize,
+                    ],
+                    curve_type,
+                )
+            }
             "sha2_256" => (
                 0,
                 vec![
@@ -213,6 +233,11 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 ],
                 Ty::new_array(tcx, tcx.types.__zkllvm_field_pallas_base, 2),
             ),
+            "sha2_256_compress" => (
+                0,
+                vec![tcx.types.__zkllvm_field_pallas_base; 6],
+                Ty::new_array(tcx, tcx.types.__zkllvm_field_pallas_base, 2),
+            ),
             "sha2_512" => (
                 0,
                 vec![
@@ -225,6 +250,16 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 ],
                 tcx.types.__zkllvm_field_curve25519_scalar,
             ),
+            "poseidon_pallas_base" => (
+                0,
+                vec![tcx.types.__zkllvm_field_pallas_base; 3],
+                Ty::new_array(tcx, tcx.types.__zkllvm_field_pallas_base, 3),
+            ),
+            "poseidon_bls12381_scalar" => (
+                0,
+                vec![tcx.types.__zkllvm_field_bls12381_scalar; 3],
+                Ty::new_array(tcx, tcx.types.__zkllvm_field_bls12381_scalar, 3),
+            ),
             "sha2_256_bls12381" => (
                 0,
                 vec![
@@ -236,12 +271,9 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 0,
                 vec![
                     tcx.types.__zkllvm_curve_bls12381,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
+                    tcx.types.__zkllvm_curve_bls12381_g2,
                 ],
-                Ty::new_array(tcx, tcx.types.__zkllvm_field_bls12381_base, 12),
+                tcx.types.__zkllvm_field_bls12381_gt,
             ),
             "hash_to_curve" => (
                 0,
@@ -260,41 +292,51 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
             "is_in_g2_check" => (
                 0,
                 vec![
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
+                    tcx.types.__zkllvm_curve_bls12381_g2,
                 ],
                 tcx.types.bool,
             ),
             "gt_multiplication" => (
                 0,
                 vec![
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
-                    tcx.types.__zkllvm_field_bls12381_base,
+                    tcx.types.__zkllvm_field_bls12381_gt,
+                    tcx.types.__zkllvm_field_bls12381_gt,
                 ],
+                tcx.types.__zkllvm_field_bls12381_gt,
+            ),
+            "curve_decompress_curve25519" => (
+                0,
+                vec![tcx.types.__zkllvm_field_curve25519_base, tcx.types.bool],
+                tcx.types.__zkllvm_field_curve25519_base,
+            ),
+            "ecdsa_verify" => (
+                0,
+                vec![
+                    tcx.types.__zkllvm_curve_secp256k1,
+                    tcx.types.__zkllvm_field_secp256k1_scalar,
+                    tcx.types.__zkllvm_field_secp256k1_scalar,
+                    tcx.types.__zkllvm_field_secp256k1_scalar,
+                ],
+                tcx.types.bool,
+            ),
+            "g2_init" => (
+                0,
+                vec![tcx.types.__zkllvm_field_bls12381_base; 4],
+                tcx.types.__zkllvm_curve_bls12381_g2,
+            ),
+            "g2_coordinates" => (
+                0,
+                vec![tcx.types.__zkllvm_curve_bls12381_g2],
+                Ty::new_array(tcx, tcx.types.__zkllvm_field_bls12381_base, 4),
+            ),
+            "gt_init" => (
+                0,
+                vec![tcx.types.__zkllvm_field_bls12381_base; 12],
+                tcx.types.__zkllvm_field_bls12381_gt,
+            ),
+            "gt_coefficients" => (
+                0,
+                vec![tcx.types.__zkllvm_field_bls12381_gt],
                 Ty::new_array(tcx, tcx.types.__zkllvm_field_bls12381_base, 12),
             ),
             _ => {
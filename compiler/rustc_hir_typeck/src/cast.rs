@@ -177,6 +177,8 @@ pub enum CastError {
     /// when we're typechecking a type parameter with a ?Sized bound.
     IntToFatCast(Option<&'static str>),
     ForeignNonExhaustiveAdt,
+    /// Cast of a field element to an integer, which would silently truncate it.
+    FieldToInt,
 }
 
 impl From<ErrorGuaranteed> for CastError {
@@ -311,6 +313,18 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                 )
                 .emit();
             }
+            CastError::FieldToInt => {
+                make_invalid_casting_error(
+                    fcx.tcx.sess,
+                    self.span,
+                    self.expr_ty,
+                    self.cast_ty,
+                    fcx,
+                )
+                .help("use `truncate_to_u128` to take the lowest 128 bits of the element")
+                .help("use `u128::try_from` to check that no bits are lost")
+                .emit();
+            }
             CastError::DifferingKinds => {
                 make_invalid_casting_error(
                     fcx.tcx.sess,
@@ -846,6 +860,12 @@ pub fn do_check(&self, fcx: &FnCtxt<'a, 'tcx>) -> Result<CastKind, CastError> {
 
             (Int(_) | Float, Int(_) | Float) => Ok(CastKind::NumericCast),
 
+            // Only unsigned integers are converted to field elements. The other way round is
+            // lossy, which the explicit `truncate_to_u128` method of field types spells out.
+            (Int(U(_)), Field) => Ok(CastKind::FieldCast),
+            (Field, Int(_)) => Err(CastError::FieldToInt),
+            (_, Field) | (Field, _) => Err(CastError::IllegalCast),
+
             (_, DynStar) => {
                 if fcx.tcx.features().dyn_star {
                     bug!("should be handled by `coerce`")
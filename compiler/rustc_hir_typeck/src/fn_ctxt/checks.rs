@@ -1316,6 +1316,10 @@ pub(in super::super) fn check_lit(
                     ty::Char => Some(tcx.types.u8),
                     ty::RawPtr(..) => Some(tcx.types.usize),
                     ty::FnDef(..) | ty::FnPtr(_) => Some(tcx.types.usize),
+                    // Only unsigned integers can be cast to field elements.
+                    ty::Field(_) if matches!(expected, ExpectCastableToType(_)) => {
+                        Some(tcx.types.u128)
+                    }
                     _ => None,
                 });
                 opt_ty.unwrap_or_else(|| self.next_int_var())
@@ -1332,7 +1336,7 @@ pub(in super::super) fn check_lit(
             }
             ast::LitKind::Field(_) => {
                 let opt_ty = expected.to_option(self).and_then(|ty| match ty.kind() {
-                    ty::Field(_) => Some(ty),
+                    ty::Field(f) if f.degree() == 1 => Some(ty),
                     _ => None,
                 });
                 opt_ty.unwrap_or_else(|| self.next_field_var())
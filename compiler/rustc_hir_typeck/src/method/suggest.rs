@@ -1682,6 +1682,10 @@ fn suggest_constraining_numerical_ty(
             || found_assoc(tcx.types.u128)
             || found_assoc(tcx.types.__zkllvm_field_bls12381_base)
             || found_assoc(tcx.types.__zkllvm_field_bls12381_scalar)
+            || found_assoc(tcx.types.__zkllvm_field_bn254_base)
+            || found_assoc(tcx.types.__zkllvm_field_bn254_scalar)
+            || found_assoc(tcx.types.__zkllvm_field_secp256k1_base)
+            || found_assoc(tcx.types.__zkllvm_field_secp256k1_scalar)
             || found_assoc(tcx.types.__zkllvm_field_curve25519_base)
             || found_assoc(tcx.types.__zkllvm_field_curve25519_scalar)
             || found_assoc(tcx.types.__zkllvm_field_pallas_base)
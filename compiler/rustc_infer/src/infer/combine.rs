@@ -110,10 +110,11 @@ pub fn super_combine_tys<R>(
                     .map_err(|e| field_unification_error(relation.a_is_expected(), e))?;
                 Ok(a)
             }
-            (&ty::Infer(ty::FieldVar(v_id)), &ty::Field(v)) => {
+            // Extension fields have no literals, so field variables never resolve to them.
+            (&ty::Infer(ty::FieldVar(v_id)), &ty::Field(v)) if v.degree() == 1 => {
                 self.unify_field_variable(a_is_expected, v_id, v)
             }
-            (&ty::Field(v), &ty::Infer(ty::FieldVar(v_id))) => {
+            (&ty::Field(v), &ty::Infer(ty::FieldVar(v_id))) if v.degree() == 1 => {
                 self.unify_field_variable(!a_is_expected, v_id, v)
             }
 
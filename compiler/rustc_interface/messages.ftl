@@ -1,3 +1,9 @@
+interface_cant_emit_circuit_abi =
+    could not emit circuit ABI: {$error}
+
+interface_cant_emit_circuit_input_template =
+    could not emit circuit input template: {$error}
+
 interface_cant_emit_mir =
     could not emit MIR: {$error}
 
@@ -59,6 +59,18 @@ pub struct GeneratedFileConflictsWithDirectory<'a> {
 #[diag(interface_out_dir_error)]
 pub struct OutDirError;
 
+#[derive(Diagnostic)]
+#[diag(interface_cant_emit_circuit_abi)]
+pub struct CantEmitCircuitAbi {
+    pub error: io::Error,
+}
+
+#[derive(Diagnostic)]
+#[diag(interface_cant_emit_circuit_input_template)]
+pub struct CantEmitCircuitInputTemplate {
+    pub error: io::Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(interface_cant_emit_mir)]
 pub struct CantEmitMIR {
@@ -984,6 +984,20 @@ pub fn start_codegen<'tcx>(
         }
     }
 
+    if tcx.sess.opts.output_types.contains_key(&OutputType::CircuitAbi) {
+        if let Err(error) = rustc_codegen_ssa::circuit_abi::emit_circuit_abi(tcx) {
+            tcx.sess.emit_err(errors::CantEmitCircuitAbi { error });
+            tcx.sess.abort_if_errors();
+        }
+    }
+
+    if tcx.sess.opts.output_types.contains_key(&OutputType::CircuitInputTemplate) {
+        if let Err(error) = rustc_codegen_ssa::circuit_abi::emit_circuit_input_template(tcx) {
+            tcx.sess.emit_err(errors::CantEmitCircuitInputTemplate { error });
+            tcx.sess.abort_if_errors();
+        }
+    }
+
     codegen
 }
 
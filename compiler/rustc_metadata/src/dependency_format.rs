@@ -96,7 +96,7 @@ fn calculate_type(tcx: TyCtxt<'_>, ty: CrateType) -> DependencyList {
             }
             let src = tcx.used_crate_source(cnum);
             if src.rmeta.is_some() {
-                // FIXME: (aleasims) here will be the check for circuit presence.
+                // The presence of a circuit in executables is checked by `entry_fn`.
                 let linkage = match ty {
                     CrateType::Executable => Linkage::Static,
                     _ => Linkage::NotLinked,
@@ -0,0 +1,61 @@
+//! Types of the parameters and the return value of `#[circuit]` functions.
+
+use crate::ty::{self, Ty, TyCtxt};
+
+impl<'tcx> TyCtxt<'tcx> {
+    /// Returns the part of `ty` that has no representation in the circuit inputs, with the
+    /// reason why. Integers, booleans, field elements, curve points and arrays, tuples and
+    /// `#[repr(C)]` or `#[repr(transparent)]` structs of them are supported, as long as they
+    /// have no padding.
+    ///
+    /// The signature check of `#[circuit]` functions, the circuit ABI manifest and the input
+    /// templates all accept exactly the types for which this returns `None`.
+    pub fn unsupported_circuit_ty(self, ty: Ty<'tcx>) -> Option<(Ty<'tcx>, &'static str)> {
+        let param_env = ty::ParamEnv::reveal_all();
+        let fields: Vec<Ty<'tcx>> = match *ty.kind() {
+            ty::Bool | ty::Int(_) | ty::Uint(_) | ty::Field(_) | ty::Curve(_) => return None,
+            // Pointees are not visited, pointers to them can make the type recursive.
+            _ if ty.builtin_deref(true).is_some_and(|pointee| pointee.ty.is_trait()) => {
+                return Some((ty, "dyn"));
+            }
+            ty::Ref(..) => return Some((ty, "reference")),
+            ty::RawPtr(_) => return Some((ty, "pointer")),
+            ty::Adt(adt_def, _) if adt_def.is_box() => return Some((ty, "pointer")),
+            ty::Dynamic(..) => return Some((ty, "dyn")),
+            ty::Float(_) => return Some((ty, "float")),
+            // Inputs are given field by field, which is only stable with a defined layout.
+            ty::Adt(adt_def, _)
+                if adt_def.is_struct() && !adt_def.repr().c() && !adt_def.repr().transparent() =>
+            {
+                return Some((ty, "repr"));
+            }
+            ty::Array(elem_ty, _) => vec![elem_ty],
+            ty::Tuple(tys) => tys.to_vec(),
+            ty::Adt(adt_def, args) if adt_def.is_struct() => adt_def
+                .non_enum_variant()
+                .fields
+                .iter()
+                .map(|field| self.normalize_erasing_regions(param_env, field.ty(self, args)))
+                .collect(),
+            _ => return Some((ty, "other")),
+        };
+        if let Some(unsupported) = fields.iter().find_map(|&ty| self.unsupported_circuit_ty(ty)) {
+            return Some(unsupported);
+        }
+        // Padding bytes have no value the assigner could read.
+        let size =
+            |ty: Ty<'tcx>| self.layout_of(param_env.and(ty)).map(|layout| layout.size.bytes());
+        let Ok(ty_size) = size(ty) else {
+            return Some((ty, "other"));
+        };
+        let fields_size: Result<u64, _> = match *ty.kind() {
+            ty::Array(..) => Ok(ty_size),
+            _ => fields.iter().map(|&ty| size(ty)).sum(),
+        };
+        match fields_size {
+            Ok(fields_size) if fields_size == ty_size => None,
+            Ok(_) => Some((ty, "padding")),
+            Err(_) => Some((ty, "other")),
+        }
+    }
+}
@@ -43,6 +43,18 @@ pub struct CodegenFnAttrs {
     /// The `#[repr(align(...))]` attribute. Indicates the value of which the function should be
     /// aligned to.
     pub alignment: Option<u32>,
+    /// The `#[public_input]` and `#[private_input]` attributes on the parameters of a
+    /// `#[circuit]` function, one entry per parameter. Empty for other functions.
+    pub circuit_inputs: Vec<Option<CircuitInput>>,
+}
+
+/// How a parameter of a `#[circuit]` function is passed to the circuit.
+#[derive(Copy, Clone, PartialEq, Eq, TyEncodable, TyDecodable, HashStable, Debug)]
+pub enum CircuitInput {
+    /// `#[public_input]`: part of the statement, known to the verifier.
+    Public,
+    /// `#[private_input]`: part of the witness, known only to the prover.
+    Private,
 }
 
 bitflags! {
@@ -125,6 +137,7 @@ pub const fn new() -> CodegenFnAttrs {
             no_sanitize: SanitizerSet::empty(),
             instruction_set: None,
             alignment: None,
+            circuit_inputs: vec![],
         }
     }
 
@@ -7,7 +7,8 @@
 //! * Traits that represent operators; e.g., `Add`, `Sub`, `Index`.
 //! * Functions called by the compiler itself.
 
-use crate::ty::{self, TyCtxt};
+use crate::mir;
+use crate::ty::{self, Ty, TyCtxt};
 
 use rustc_hir::def_id::DefId;
 use rustc_hir::LangItem;
@@ -40,6 +41,137 @@ pub fn fn_trait_kind_from_def_id(self, id: DefId) -> Option<ty::ClosureKind> {
     pub fn is_fn_trait(self, id: DefId) -> bool {
         self.fn_trait_kind_from_def_id(id).is_some()
     }
+
+    /// Returns the software runtime function implementing a field or curve binary operation,
+    /// which is used on targets without native field types. Returns `None` if no call is
+    /// needed: on the assigner target, for comparisons and for any other operand types.
+    ///
+    /// The function must be instantiated with the returned type. Curve multiplication takes
+    /// the point first, the returned flag is set if the operands have to be swapped for that.
+    pub fn soft_zk_binop(
+        self,
+        op: mir::BinOp,
+        lhs_ty: Ty<'tcx>,
+        rhs_ty: Ty<'tcx>,
+    ) -> Option<(LangItem, Ty<'tcx>, bool)> {
+        if self.sess.target.is_like_assigner {
+            return None;
+        }
+        let item = match (lhs_ty.kind(), rhs_ty.kind(), op) {
+            (ty::Field(_), ty::Field(_), mir::BinOp::Add) => LangItem::SoftFieldAdd,
+            (ty::Field(_), ty::Field(_), mir::BinOp::Sub) => LangItem::SoftFieldSub,
+            (ty::Field(_), ty::Field(_), mir::BinOp::Mul) => LangItem::SoftFieldMul,
+            (ty::Field(_), ty::Field(_), mir::BinOp::Div) => LangItem::SoftFieldDiv,
+            (ty::Curve(_), ty::Curve(_), mir::BinOp::Add) => LangItem::SoftCurveAdd,
+            (ty::Curve(_), ty::Curve(_), mir::BinOp::Sub) => LangItem::SoftCurveSub,
+            (ty::Curve(_), ty::Field(_), mir::BinOp::Mul) => LangItem::SoftCurveMul,
+            (ty::Curve(_), ty::Field(_), mir::BinOp::Div) => LangItem::SoftCurveDiv,
+            (ty::Field(_), ty::Curve(_), mir::BinOp::Mul) => {
+                return Some((LangItem::SoftCurveMul, rhs_ty, true));
+            }
+            _ => return None,
+        };
+        Some((item, lhs_ty, false))
+    }
+
+    /// Same as [`TyCtxt::soft_zk_binop`], but for unary operations.
+    /// The function must be instantiated with the operand type.
+    pub fn soft_zk_unop(self, op: mir::UnOp, ty: Ty<'tcx>) -> Option<LangItem> {
+        if self.sess.target.is_like_assigner {
+            return None;
+        }
+        match (ty.kind(), op) {
+            (ty::Field(_), mir::UnOp::Neg) => Some(LangItem::SoftFieldNeg),
+            (ty::Curve(_), mir::UnOp::Neg) => Some(LangItem::SoftCurveNeg),
+            _ => None,
+        }
+    }
+
+    /// Returns the software implementation of an `assigner_*` intrinsic, which has to be
+    /// called instead of the intrinsic on targets other than the assigner. Returns `None`
+    /// on the assigner target and for any other instance.
+    ///
+    /// The `assigner_curve_{init,x,y}_*`, `assigner_is_on_curve_*`,
+    /// `assigner_g2_{init,coordinates}` and `assigner_gt_{init,coefficients}` intrinsics
+    /// have no fallback, backends lower them directly.
+    pub fn assigner_intrinsic_fallback(
+        self,
+        instance: ty::Instance<'tcx>,
+    ) -> Option<ty::Instance<'tcx>> {
+        if self.sess.target.is_like_assigner {
+            return None;
+        }
+        let ty::InstanceDef::Intrinsic(def_id) = instance.def else {
+            return None;
+        };
+        let name = self.item_name(def_id);
+        let name = name.as_str().strip_prefix("assigner_")?;
+        if let Some(field_name) = name.strip_prefix("poseidon_") {
+            // The implementation is generic over the field.
+            let field_ty = match field_name {
+                "pallas_base" => self.types.__zkllvm_field_pallas_base,
+                "bls12381_scalar" => self.types.__zkllvm_field_bls12381_scalar,
+                _ => return None,
+            };
+            let def_id = self.require_lang_item(LangItem::ZkPoseidon, None);
+            return Some(ty::Instance::new(def_id, self.mk_args(&[field_ty.into()])));
+        }
+        if let Some(curve_name) = name.strip_prefix("msm_") {
+            // The implementation is generic over the curve.
+            return self.zk_curve_instance(LangItem::ZkMsm, curve_name);
+        }
+        let item = match name {
+            "exit_check" => LangItem::ZkExitCheck,
+            "sha2_256" => LangItem::ZkSha2_256,
+            "sha2_256_bls12381" => LangItem::ZkSha2_256Bls12381,
+            "sha2_256_compress" => LangItem::ZkSha2_256Compress,
+            "sha2_512" => LangItem::ZkSha2_512,
+            "bls12_optimal_ate_pairing" => LangItem::ZkBls12OptimalAtePairing,
+            "hash_to_curve" => LangItem::ZkHashToCurve,
+            "is_in_g1_check" => LangItem::ZkIsInG1Check,
+            "is_in_g2_check" => LangItem::ZkIsInG2Check,
+            "gt_multiplication" => LangItem::ZkGtMultiplication,
+            "curve_decompress_curve25519" => LangItem::ZkCurve25519Decompress,
+            "ecdsa_verify" => LangItem::ZkEcdsaVerify,
+            _ => return None,
+        };
+        Some(ty::Instance::mono(self, self.require_lang_item(item, None)))
+    }
+
+    /// Returns the software implementation that backends call to lower
+    /// `assigner_is_on_curve_*` outside the assigner, so that it can be collected.
+    ///
+    /// Unlike a fallback it does not replace the intrinsic, compile-time evaluation
+    /// checks the point itself.
+    pub fn assigner_is_on_curve_runtime(
+        self,
+        instance: ty::Instance<'tcx>,
+    ) -> Option<ty::Instance<'tcx>> {
+        if self.sess.target.is_like_assigner {
+            return None;
+        }
+        let ty::InstanceDef::Intrinsic(def_id) = instance.def else {
+            return None;
+        };
+        let name = self.item_name(def_id);
+        let curve_name = name.as_str().strip_prefix("assigner_is_on_curve_")?;
+        self.zk_curve_instance(LangItem::ZkIsOnCurve, curve_name)
+    }
+
+    /// Instantiates a lang item that is generic over the curve for the named curve.
+    fn zk_curve_instance(self, item: LangItem, curve_name: &str) -> Option<ty::Instance<'tcx>> {
+        let curve_ty = match curve_name {
+            "bls12381" => self.types.__zkllvm_curve_bls12381,
+            "bn254" => self.types.__zkllvm_curve_bn254,
+            "secp256k1" => self.types.__zkllvm_curve_secp256k1,
+            "curve25519" => self.types.__zkllvm_curve_curve25519,
+            "pallas" => self.types.__zkllvm_curve_pallas,
+            "vesta" => self.types.__zkllvm_curve_vesta,
+            _ => return None,
+        };
+        let def_id = self.require_lang_item(item, None);
+        Some(ty::Instance::new(def_id, self.mk_args(&[curve_ty.into()])))
+    }
 }
 
 /// Returns `true` if the specified `lang_item` must be present for this
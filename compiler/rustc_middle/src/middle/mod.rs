@@ -1,3 +1,4 @@
+pub mod circuit;
 pub mod codegen_fn_attrs;
 pub mod debugger_visualizer;
 pub mod dependency_format;
@@ -365,6 +365,7 @@ pub enum ExpectedKind {
     EnumTag,
     Str,
     Field,
+    Curve,
 }
 
 impl From<PointerKind> for ExpectedKind {
@@ -152,7 +152,7 @@ macro_rules! throw_ub_custom {
     UnsupportedOpInfo, ValidationErrorInfo, ValidationErrorKind,
 };
 
-pub use self::value::{get_slice_bytes, ConstAlloc, ConstValue, Scalar, ScalarField};
+pub use self::value::{get_slice_bytes, ConstAlloc, ConstValue, Scalar, ScalarCurve, ScalarField};
 
 pub use self::allocation::{
     alloc_range, AllocBytes, AllocError, AllocRange, AllocResult, Allocation, ConstAllocation,
@@ -16,7 +16,7 @@
     ScalarSizeMismatch,
 };
 
-pub use crate::ty::ScalarField;
+pub use crate::ty::{ScalarCurve, ScalarField};
 
 /// Represents the result of const evaluation via the `eval_to_allocation` query.
 #[derive(Copy, Clone, HashStable, TyEncodable, TyDecodable, Debug, Hash, Eq, PartialEq)]
@@ -73,6 +73,13 @@ pub fn try_to_scalar_int(&self) -> Option<ScalarInt> {
         self.try_to_scalar()?.try_to_int().ok()
     }
 
+    pub fn try_to_field(&self) -> Option<ScalarField> {
+        match *self {
+            ConstValue::Field(val) => Some(val),
+            _ => None,
+        }
+    }
+
     pub fn try_to_bits(&self, size: Size) -> Option<u128> {
         self.try_to_scalar_int()?.to_bits(size).ok()
     }
@@ -2005,6 +2005,7 @@ pub fn is_safe_to_remove(&self) -> bool {
                 | CastKind::FloatToInt
                 | CastKind::FloatToFloat
                 | CastKind::IntToFloat
+                | CastKind::IntToField
                 | CastKind::FnPtrToPtr
                 | CastKind::PtrToPtr
                 | CastKind::PointerCoercion(_)
@@ -2308,6 +2309,14 @@ pub fn try_to_scalar_int(self) -> Option<ScalarInt> {
         Some(self.try_to_scalar()?.assert_int())
     }
 
+    #[inline]
+    pub fn try_to_field(self) -> Option<ScalarField> {
+        match self {
+            ConstantKind::Val(val, _) => val.try_to_field(),
+            ConstantKind::Ty(_) | ConstantKind::Unevaluated(..) => None,
+        }
+    }
+
     #[inline]
     pub fn try_to_bits(self, size: Size) -> Option<u128> {
         self.try_to_scalar_int()?.to_bits(size).ok()
@@ -2942,6 +2951,17 @@ fn pretty_print_const_value<'tcx>(
                     return Ok(());
                 }
             }
+            // Field elements, printed as hexadecimal field literals.
+            (ConstValue::Field(field), ty::Field(_)) => {
+                let words = field.words();
+                let top = words.iter().rposition(|&word| word != 0).unwrap_or(0);
+                write!(fmt, "0x{:x}", words[top])?;
+                for word in words[..top].iter().rev() {
+                    write!(fmt, "{word:016x}")?;
+                }
+                fmt.write_str("g")?;
+                return Ok(());
+            }
             (ConstValue::Scalar(scalar), _) => {
                 let mut cx = FmtPrinter::new(tcx, Namespace::ValueNS);
                 cx.print_alloc_ids = true;
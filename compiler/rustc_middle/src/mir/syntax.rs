@@ -1247,6 +1247,9 @@ pub enum CastKind {
     IntToFloat,
     PtrToPtr,
     FnPtrToPtr,
+    /// Conversion of an unsigned integer into a field element.
+    /// Every unsigned integer is less than any supported field modulus, so this is lossless.
+    IntToField,
     /// Reinterpret the bits of the input as a different type.
     ///
     /// MIR is well-formed if the input and output types have different sizes,
@@ -36,6 +36,8 @@ pub enum CastTy<'tcx> {
     Ptr(ty::TypeAndMut<'tcx>),
     /// Casting into a `dyn*` value.
     DynStar,
+    /// Field elements.
+    Field,
 }
 
 /// Cast Kind. See [RFC 401](https://rust-lang.github.io/rfcs/0401-coercions.html)
@@ -54,6 +56,7 @@ pub enum CastKind {
     FnPtrPtrCast,
     FnPtrAddrCast,
     DynStarCast,
+    FieldCast,
 }
 
 impl<'tcx> CastTy<'tcx> {
@@ -66,8 +69,10 @@ pub fn from_ty(t: Ty<'tcx>) -> Option<CastTy<'tcx>> {
             ty::Int(_) => Some(CastTy::Int(IntTy::I)),
             ty::Infer(ty::InferTy::IntVar(_)) => Some(CastTy::Int(IntTy::I)),
             ty::Infer(ty::InferTy::FloatVar(_)) => Some(CastTy::Float),
+            ty::Infer(ty::InferTy::FieldVar(_)) => Some(CastTy::Field),
             ty::Uint(u) => Some(CastTy::Int(IntTy::U(u))),
             ty::Float(_) => Some(CastTy::Float),
+            ty::Field(f) if f.degree() == 1 => Some(CastTy::Field),
             ty::Adt(d, _) if d.is_enum() && d.is_payloadfree() => Some(CastTy::Int(IntTy::CEnum)),
             ty::RawPtr(mt) => Some(CastTy::Ptr(mt)),
             ty::FnPtr(..) => Some(CastTy::FnPtr),
@@ -95,6 +100,8 @@ pub fn mir_cast_kind<'tcx>(from_ty: Ty<'tcx>, cast_ty: Ty<'tcx>) -> mir::CastKin
         (Some(CastTy::Float), Some(CastTy::Float)) => mir::CastKind::FloatToFloat,
         (Some(CastTy::Ptr(_)), Some(CastTy::Ptr(_))) => mir::CastKind::PtrToPtr,
 
+        (Some(CastTy::Int(_)), Some(CastTy::Field)) => mir::CastKind::IntToField,
+
         (_, _) => {
             bug!("Attempting to cast non-castable types {:?} and {:?}", from_ty, cast_ty)
         }
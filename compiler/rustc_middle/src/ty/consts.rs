@@ -8,11 +8,13 @@
 use rustc_hir::def_id::LocalDefId;
 use rustc_macros::HashStable;
 
+mod curve;
 mod field;
 mod int;
 mod kind;
 mod valtree;
 
+pub use curve::*;
 pub use field::*;
 pub use int::*;
 pub use kind::*;
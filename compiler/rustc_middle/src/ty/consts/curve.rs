@@ -0,0 +1,203 @@
+use std::fmt;
+
+use rustc_type_ir::CurveTy;
+
+use crypto_bigint::U384;
+
+use super::ScalarField;
+
+/// A `ScalarCurve` represents an elliptic curve point in affine coordinates.
+///
+/// Curve values are two base field values and do not fit into an `Immediate`, so the
+/// interpreter keeps them in memory and uses this type only to do the arithmetic.
+///
+/// This is a reference implementation, which favors simplicity over speed.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScalarCurve {
+    x: ScalarField,
+    y: ScalarField,
+}
+
+impl fmt::Debug for ScalarCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:x}, {:x})", self.x, self.y)
+    }
+}
+
+/// Shape of the curve equation.
+enum CurveEquation {
+    /// Short Weierstrass curve `y^2 = x^3 + b`.
+    ///
+    /// There is no affine point for the neutral element, so it is represented
+    /// by `(0, 1)`, which does not belong to any of these curves.
+    Weierstrass { b: ScalarField },
+    /// Twisted Edwards curve `-x^2 + y^2 = 1 + d * x^2 * y^2`.
+    ///
+    /// Neutral element is `(0, 1)`.
+    TwistedEdwards { d: ScalarField },
+}
+
+fn equation(curve_ty: CurveTy) -> CurveEquation {
+    let size = ScalarField::modulus(curve_ty.base_field()).size();
+    match curve_ty {
+        CurveTy::Bls12381 => CurveEquation::Weierstrass { b: ScalarField::from_uint(4u8, size) },
+        CurveTy::Bn254 => CurveEquation::Weierstrass { b: ScalarField::from_uint(3u8, size) },
+        CurveTy::Secp256k1 => CurveEquation::Weierstrass { b: ScalarField::from_uint(7u8, size) },
+        CurveTy::Pallas | CurveTy::Vesta => {
+            CurveEquation::Weierstrass { b: ScalarField::from_uint(5u8, size) }
+        }
+        CurveTy::Bls12381G2 => bug!("curve {} has no scalar values", curve_ty.name_str()),
+        CurveTy::Curve25519 => CurveEquation::TwistedEdwards {
+            d: ScalarField::from_u384(
+                U384::from_be_hex("0000000000000000000000000000000052036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3"),
+                size,
+            ),
+        },
+    }
+}
+
+impl ScalarCurve {
+    pub fn new(x: ScalarField, y: ScalarField) -> Self {
+        debug_assert_eq!(x.size(), y.size());
+        Self { x, y }
+    }
+
+    pub fn x(&self) -> ScalarField {
+        self.x
+    }
+
+    pub fn y(&self) -> ScalarField {
+        self.y
+    }
+
+    /// Returns the neutral element of the curve group.
+    pub fn zero(curve_ty: CurveTy) -> Self {
+        let size = ScalarField::modulus(curve_ty.base_field()).size();
+        Self::new(ScalarField::zero(size), ScalarField::one(size))
+    }
+
+    pub fn is_zero(&self, curve_ty: CurveTy) -> bool {
+        *self == Self::zero(curve_ty)
+    }
+
+    /// Checks that the point satisfies the curve equation.
+    ///
+    /// Coordinates must be reduced modulo the base field modulus.
+    pub fn is_on_curve(&self, curve_ty: CurveTy) -> bool {
+        if self.is_zero(curve_ty) {
+            return true;
+        }
+        let f = curve_ty.base_field();
+        let (x, y) = (self.x, self.y);
+        let xx = x.mul_mod(x, f);
+        let yy = y.mul_mod(y, f);
+        match equation(curve_ty) {
+            CurveEquation::Weierstrass { b } => yy == xx.mul_mod(x, f).add_mod(b, f),
+            CurveEquation::TwistedEdwards { d } => {
+                let one = ScalarField::one(x.size());
+                yy.sub_mod(xx, f) == one.add_mod(d.mul_mod(xx, f).mul_mod(yy, f), f)
+            }
+        }
+    }
+
+    pub fn neg(self, curve_ty: CurveTy) -> Self {
+        let f = curve_ty.base_field();
+        match equation(curve_ty) {
+            CurveEquation::Weierstrass { .. } if self.is_zero(curve_ty) => self,
+            CurveEquation::Weierstrass { .. } => Self::new(self.x, self.y.neg_mod(f)),
+            CurveEquation::TwistedEdwards { .. } => Self::new(self.x.neg_mod(f), self.y),
+        }
+    }
+
+    /// Adds two points. Both points must be on the curve.
+    pub fn add(self, rhs: Self, curve_ty: CurveTy) -> Self {
+        let f = curve_ty.base_field();
+        match equation(curve_ty) {
+            CurveEquation::Weierstrass { .. } => {
+                if self.is_zero(curve_ty) {
+                    return rhs;
+                }
+                if rhs.is_zero(curve_ty) {
+                    return self;
+                }
+                if self.x == rhs.x {
+                    // Either the same point or the opposite one.
+                    return if self.y == rhs.y {
+                        self.double(curve_ty)
+                    } else {
+                        Self::zero(curve_ty)
+                    };
+                }
+                // `x1 != x2`, so the division never fails.
+                let lambda =
+                    rhs.y.sub_mod(self.y, f).div_mod(rhs.x.sub_mod(self.x, f), f).unwrap();
+                self.weierstrass_chord(lambda, rhs.x, curve_ty)
+            }
+            CurveEquation::TwistedEdwards { d } => {
+                // Edwards addition law is complete, so denominators are never zero
+                // for points on the curve.
+                let one = ScalarField::one(self.x.size());
+                let x1y2 = self.x.mul_mod(rhs.y, f);
+                let y1x2 = self.y.mul_mod(rhs.x, f);
+                let x1x2 = self.x.mul_mod(rhs.x, f);
+                let y1y2 = self.y.mul_mod(rhs.y, f);
+                let dxxyy = d.mul_mod(x1x2, f).mul_mod(y1y2, f);
+                let x = x1y2.add_mod(y1x2, f).div_mod(one.add_mod(dxxyy, f), f);
+                let y = y1y2.add_mod(x1x2, f).div_mod(one.sub_mod(dxxyy, f), f);
+                match (x, y) {
+                    (Some(x), Some(y)) => Self::new(x, y),
+                    _ => bug!("adding points which are not on the curve {curve_ty:?}"),
+                }
+            }
+        }
+    }
+
+    pub fn sub(self, rhs: Self, curve_ty: CurveTy) -> Self {
+        self.add(rhs.neg(curve_ty), curve_ty)
+    }
+
+    /// Doubles the point. The point must be on the curve.
+    pub fn double(self, curve_ty: CurveTy) -> Self {
+        let f = curve_ty.base_field();
+        match equation(curve_ty) {
+            CurveEquation::Weierstrass { .. } => {
+                if self.is_zero(curve_ty) || self.y.is_zero() {
+                    // Points of order two are doubled to zero.
+                    return Self::zero(curve_ty);
+                }
+                let xx = self.x.mul_mod(self.x, f);
+                let three_xx = xx.add_mod(xx, f).add_mod(xx, f);
+                let lambda = three_xx.div_mod(self.y.add_mod(self.y, f), f).unwrap();
+                self.weierstrass_chord(lambda, self.x, curve_ty)
+            }
+            CurveEquation::TwistedEdwards { .. } => self.add(self, curve_ty),
+        }
+    }
+
+    /// Returns the third intersection of the line with slope `lambda` through `self`
+    /// and the point with abscissa `other_x`, reflected over the `x` axis.
+    fn weierstrass_chord(
+        self,
+        lambda: ScalarField,
+        other_x: ScalarField,
+        curve_ty: CurveTy,
+    ) -> Self {
+        let f = curve_ty.base_field();
+        let x = lambda.mul_mod(lambda, f).sub_mod(self.x, f).sub_mod(other_x, f);
+        let y = lambda.mul_mod(self.x.sub_mod(x, f), f).sub_mod(self.y, f);
+        Self::new(x, y)
+    }
+
+    /// Multiplies the point by a value of the curve scalar field.
+    pub fn mul_scalar(self, scalar: ScalarField, curve_ty: CurveTy) -> Self {
+        let k = scalar.data();
+        let mut acc = Self::zero(curve_ty);
+        for i in (0..k.bits_vartime()).rev() {
+            acc = acc.double(curve_ty);
+            if k.bit_vartime(i) == 1 {
+                acc = acc.add(self, curve_ty);
+            }
+        }
+        acc
+    }
+}
@@ -3,6 +3,7 @@
 
 use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
 use rustc_target::abi::Size;
+use rustc_type_ir::FieldTy;
 
 use crypto_bigint::{U384, Encoding};
 
@@ -86,6 +87,26 @@ impl ScalarField {
         size: unsafe { NonZeroU16::new_unchecked(32) },
     };
 
+    pub const BN254_BASE_MODULUS: Self = Self {
+        data: U384::from_be_hex("0000000000000000000000000000000030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"),
+        size: unsafe { NonZeroU16::new_unchecked(32) },
+    };
+
+    pub const BN254_SCALAR_MODULUS: Self = Self {
+        data: U384::from_be_hex("0000000000000000000000000000000030644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+        size: unsafe { NonZeroU16::new_unchecked(32) },
+    };
+
+    pub const SECP256K1_BASE_MODULUS: Self = Self {
+        data: U384::from_be_hex("00000000000000000000000000000000fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+        size: unsafe { NonZeroU16::new_unchecked(32) },
+    };
+
+    pub const SECP256K1_SCALAR_MODULUS: Self = Self {
+        data: U384::from_be_hex("00000000000000000000000000000000fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+        size: unsafe { NonZeroU16::new_unchecked(32) },
+    };
+
     pub const CURVE25519_BASE_MODULUS: Self = Self {
         data: U384::from_be_hex("000000000000000000000000000000007fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"),
         size: unsafe { NonZeroU16::new_unchecked(32) },
@@ -106,6 +127,23 @@ impl ScalarField {
         size: unsafe { NonZeroU16::new_unchecked(32) },
     };
 
+    /// Returns the modulus of the given field type.
+    pub fn modulus(field_ty: FieldTy) -> Self {
+        match field_ty {
+            FieldTy::Bls12381Base => Self::BLS12381_BASE_MODULUS,
+            FieldTy::Bls12381Scalar => Self::BLS12381_SCALAR_MODULUS,
+            FieldTy::Bn254Base => Self::BN254_BASE_MODULUS,
+            FieldTy::Bn254Scalar => Self::BN254_SCALAR_MODULUS,
+            FieldTy::Secp256k1Base => Self::SECP256K1_BASE_MODULUS,
+            FieldTy::Secp256k1Scalar => Self::SECP256K1_SCALAR_MODULUS,
+            FieldTy::Curve25519Base => Self::CURVE25519_BASE_MODULUS,
+            FieldTy::Curve25519Scalar => Self::CURVE25519_SCALAR_MODULUS,
+            FieldTy::PallasBase => Self::PALLAS_BASE_MODULUS,
+            FieldTy::PallasScalar => Self::PALLAS_SCALAR_MODULUS,
+            FieldTy::Bls12381Gt => bug!("extension field {} has no scalar values", field_ty.name_str()),
+        }
+    }
+
     pub fn from_be_bytes(bytes_be: &[u8; 48], size: Size) -> Self {
         let data = U384::from_be_slice(bytes_be);
         let Ok(size) = NonZeroU16::try_from(size.bytes() as u16) else {
@@ -141,4 +179,100 @@ pub fn size(&self) -> Size {
     pub fn words(&self) -> &[u64; 6] {
         self.data.as_words()
     }
+
+    /// Returns the value truncated to an unsigned integer of the given size.
+    pub fn truncate_to_uint(&self, size: Size) -> u128 {
+        let words = self.words();
+        size.truncate(u128::from(words[0]) | u128::from(words[1]) << 64)
+    }
+}
+
+/// Modular arithmetic.
+///
+/// All operands are expected to be already reduced modulo the field modulus,
+/// which is the case for any value produced by literal lowering or by these methods.
+impl ScalarField {
+    #[inline]
+    pub fn zero(size: Size) -> Self {
+        Self::from_u384(U384::ZERO, size)
+    }
+
+    #[inline]
+    pub fn one(size: Size) -> Self {
+        Self::from_u384(U384::ONE, size)
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.data == U384::ZERO
+    }
+
+    /// Reduces an arbitrary value modulo the field modulus.
+    pub fn reduce(self, field_ty: FieldTy) -> Self {
+        let modulus = Self::modulus(field_ty).data;
+        if self.data >= modulus {
+            Self { data: self.data.wrapping_rem(&modulus), size: self.size }
+        } else {
+            self
+        }
+    }
+
+    pub fn add_mod(self, rhs: Self, field_ty: FieldTy) -> Self {
+        debug_assert_eq!(self.size, rhs.size);
+        let modulus = Self::modulus(field_ty).data;
+        Self { data: self.data.add_mod(&rhs.data, &modulus), size: self.size }
+    }
+
+    pub fn sub_mod(self, rhs: Self, field_ty: FieldTy) -> Self {
+        debug_assert_eq!(self.size, rhs.size);
+        let modulus = Self::modulus(field_ty).data;
+        Self { data: self.data.sub_mod(&rhs.data, &modulus), size: self.size }
+    }
+
+    pub fn neg_mod(self, field_ty: FieldTy) -> Self {
+        let modulus = Self::modulus(field_ty).data;
+        Self { data: self.data.neg_mod(&modulus), size: self.size }
+    }
+
+    pub fn mul_mod(self, rhs: Self, field_ty: FieldTy) -> Self {
+        debug_assert_eq!(self.size, rhs.size);
+        let modulus = Self::modulus(field_ty).data;
+        // Double-and-add over the bits of `rhs`, so every intermediate value stays reduced.
+        let mut acc = U384::ZERO;
+        for i in (0..rhs.data.bits_vartime()).rev() {
+            acc = acc.add_mod(&acc, &modulus);
+            if rhs.data.bit_vartime(i) == 1 {
+                acc = acc.add_mod(&self.data, &modulus);
+            }
+        }
+        Self { data: acc, size: self.size }
+    }
+
+    /// Raises `self` to the power of `exp`, where `exp` is an arbitrary
+    /// (not necessarily reduced) integer.
+    pub fn pow_mod(self, exp: U384, field_ty: FieldTy) -> Self {
+        let mut acc = Self::one(self.size());
+        for i in (0..exp.bits_vartime()).rev() {
+            acc = acc.mul_mod(acc, field_ty);
+            if exp.bit_vartime(i) == 1 {
+                acc = acc.mul_mod(self, field_ty);
+            }
+        }
+        acc
+    }
+
+    /// Returns the multiplicative inverse, or `None` if `self` is zero.
+    pub fn inv_mod(self, field_ty: FieldTy) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        // All field moduli are prime, so by Fermat's little theorem `a^(p - 2) == a^(-1)`.
+        let exp = Self::modulus(field_ty).data.wrapping_sub(&U384::from(2u8));
+        Some(self.pow_mod(exp, field_ty))
+    }
+
+    /// Returns `self / rhs`, or `None` if `rhs` is zero.
+    pub fn div_mod(self, rhs: Self, field_ty: FieldTy) -> Option<Self> {
+        rhs.inv_mod(field_ty).map(|inv| self.mul_mod(inv, field_ty))
+    }
 }
@@ -268,11 +268,19 @@ fn intern_predicate(
 
 pub struct CommonTypes<'tcx> {
     pub __zkllvm_curve_bls12381: Ty<'tcx>,
+    pub __zkllvm_curve_bn254: Ty<'tcx>,
+    pub __zkllvm_curve_secp256k1: Ty<'tcx>,
+    pub __zkllvm_curve_bls12381_g2: Ty<'tcx>,
     pub __zkllvm_curve_curve25519: Ty<'tcx>,
     pub __zkllvm_curve_pallas: Ty<'tcx>,
     pub __zkllvm_curve_vesta: Ty<'tcx>,
     pub __zkllvm_field_bls12381_base: Ty<'tcx>,
+    pub __zkllvm_field_bls12381_gt: Ty<'tcx>,
     pub __zkllvm_field_bls12381_scalar: Ty<'tcx>,
+    pub __zkllvm_field_bn254_base: Ty<'tcx>,
+    pub __zkllvm_field_bn254_scalar: Ty<'tcx>,
+    pub __zkllvm_field_secp256k1_base: Ty<'tcx>,
+    pub __zkllvm_field_secp256k1_scalar: Ty<'tcx>,
     pub __zkllvm_field_curve25519_base: Ty<'tcx>,
     pub __zkllvm_field_curve25519_scalar: Ty<'tcx>,
     pub __zkllvm_field_pallas_base: Ty<'tcx>,
@@ -363,11 +371,19 @@ fn new(
 
         CommonTypes {
             __zkllvm_curve_bls12381: mk(Curve(ty::CurveTy::Bls12381)),
+            __zkllvm_curve_bn254: mk(Curve(ty::CurveTy::Bn254)),
+            __zkllvm_curve_secp256k1: mk(Curve(ty::CurveTy::Secp256k1)),
+            __zkllvm_curve_bls12381_g2: mk(Curve(ty::CurveTy::Bls12381G2)),
             __zkllvm_curve_curve25519: mk(Curve(ty::CurveTy::Curve25519)),
             __zkllvm_curve_pallas: mk(Curve(ty::CurveTy::Pallas)),
             __zkllvm_curve_vesta: mk(Curve(ty::CurveTy::Vesta)),
             __zkllvm_field_bls12381_base: mk(Field(ty::FieldTy::Bls12381Base)),
+            __zkllvm_field_bls12381_gt: mk(Field(ty::FieldTy::Bls12381Gt)),
             __zkllvm_field_bls12381_scalar: mk(Field(ty::FieldTy::Bls12381Scalar)),
+            __zkllvm_field_bn254_base: mk(Field(ty::FieldTy::Bn254Base)),
+            __zkllvm_field_bn254_scalar: mk(Field(ty::FieldTy::Bn254Scalar)),
+            __zkllvm_field_secp256k1_base: mk(Field(ty::FieldTy::Secp256k1Base)),
+            __zkllvm_field_secp256k1_scalar: mk(Field(ty::FieldTy::Secp256k1Scalar)),
             __zkllvm_field_curve25519_base: mk(Field(ty::FieldTy::Curve25519Base)),
             __zkllvm_field_curve25519_scalar: mk(Field(ty::FieldTy::Curve25519Scalar)),
             __zkllvm_field_pallas_base: mk(Field(ty::FieldTy::PallasBase)),
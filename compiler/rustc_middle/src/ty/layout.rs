@@ -163,10 +163,15 @@ fn to_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
         match self {
             Field::Bls12381Base => tcx.types.__zkllvm_field_bls12381_base,
             Field::Bls12381Scalar => tcx.types.__zkllvm_field_bls12381_scalar,
+            Field::Bn254Base => tcx.types.__zkllvm_field_bn254_base,
+            Field::Bn254Scalar => tcx.types.__zkllvm_field_bn254_scalar,
+            Field::Secp256k1Base => tcx.types.__zkllvm_field_secp256k1_base,
+            Field::Secp256k1Scalar => tcx.types.__zkllvm_field_secp256k1_scalar,
             Field::Curve25519Base => tcx.types.__zkllvm_field_curve25519_base,
             Field::Curve25519Scalar => tcx.types.__zkllvm_field_curve25519_scalar,
             Field::PallasBase => tcx.types.__zkllvm_field_pallas_base,
             Field::PallasScalar => tcx.types.__zkllvm_field_pallas_scalar,
+            Field::Bls12381Gt => tcx.types.__zkllvm_field_bls12381_gt,
         }
     }
 
@@ -174,10 +179,15 @@ fn from_field_ty(fty: ty::FieldTy) -> Field {
         match fty {
             ty::FieldTy::Bls12381Base => Field::Bls12381Base,
             ty::FieldTy::Bls12381Scalar => Field::Bls12381Scalar,
+            ty::FieldTy::Bn254Base => Field::Bn254Base,
+            ty::FieldTy::Bn254Scalar => Field::Bn254Scalar,
+            ty::FieldTy::Secp256k1Base => Field::Secp256k1Base,
+            ty::FieldTy::Secp256k1Scalar => Field::Secp256k1Scalar,
             ty::FieldTy::Curve25519Base => Field::Curve25519Base,
             ty::FieldTy::Curve25519Scalar => Field::Curve25519Scalar,
             ty::FieldTy::PallasBase => Field::PallasBase,
             ty::FieldTy::PallasScalar => Field::PallasScalar,
+            ty::FieldTy::Bls12381Gt => Field::Bls12381Gt,
         }
     }
 }
@@ -191,18 +201,24 @@ impl CurveExt for Curve {
     fn to_ty<'tcx>(&self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
         match self {
             Curve::Bls12381 => tcx.types.__zkllvm_curve_bls12381,
+            Curve::Bn254 => tcx.types.__zkllvm_curve_bn254,
+            Curve::Secp256k1 => tcx.types.__zkllvm_curve_secp256k1,
             Curve::Curve25519 => tcx.types.__zkllvm_curve_curve25519,
             Curve::Pallas => tcx.types.__zkllvm_curve_pallas,
             Curve::Vesta => tcx.types.__zkllvm_curve_vesta,
+            Curve::Bls12381G2 => tcx.types.__zkllvm_curve_bls12381_g2,
         }
     }
 
     fn from_curve_ty(cty: ty::CurveTy) -> Curve {
         match cty {
             ty::CurveTy::Bls12381 => Curve::Bls12381,
+            ty::CurveTy::Bn254 => Curve::Bn254,
+            ty::CurveTy::Secp256k1 => Curve::Secp256k1,
             ty::CurveTy::Curve25519 => Curve::Curve25519,
             ty::CurveTy::Pallas => Curve::Pallas,
             ty::CurveTy::Vesta => Curve::Vesta,
+            ty::CurveTy::Bls12381G2 => Curve::Bls12381G2,
         }
     }
 }
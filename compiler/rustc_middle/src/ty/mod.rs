@@ -87,7 +87,7 @@
 pub use self::consts::{
     Const, ConstData, ConstInt, Expr, InferConst, ScalarInt, UnevaluatedConst, ValTree,
 };
-pub use self::consts::ScalarField;
+pub use self::consts::{ScalarCurve, ScalarField};
 pub use self::context::{
     tls, CtxtInterners, DeducedParamAttrs, FreeRegionInfo, GlobalCtxt, Lift, TyCtxt, TyCtxtFeed,
 };
@@ -2652,19 +2652,27 @@ pub fn field_ty(fty: ast::FieldTy) -> FieldTy {
     match fty {
         ast::FieldTy::Bls12381Base => FieldTy::Bls12381Base,
         ast::FieldTy::Bls12381Scalar => FieldTy::Bls12381Scalar,
+        ast::FieldTy::Bn254Base => FieldTy::Bn254Base,
+        ast::FieldTy::Bn254Scalar => FieldTy::Bn254Scalar,
+        ast::FieldTy::Secp256k1Base => FieldTy::Secp256k1Base,
+        ast::FieldTy::Secp256k1Scalar => FieldTy::Secp256k1Scalar,
         ast::FieldTy::Curve25519Base => FieldTy::Curve25519Base,
         ast::FieldTy::Curve25519Scalar => FieldTy::Curve25519Scalar,
         ast::FieldTy::PallasBase => FieldTy::PallasBase,
         ast::FieldTy::PallasScalar => FieldTy::PallasScalar,
+        ast::FieldTy::Bls12381Gt => FieldTy::Bls12381Gt,
     }
 }
 
 pub fn curve_ty(fty: ast::CurveTy) -> CurveTy {
     match fty {
         ast::CurveTy::Bls12381 => CurveTy::Bls12381,
+        ast::CurveTy::Bn254 => CurveTy::Bn254,
+        ast::CurveTy::Secp256k1 => CurveTy::Secp256k1,
         ast::CurveTy::Curve25519 => CurveTy::Curve25519,
         ast::CurveTy::Pallas => CurveTy::Pallas,
         ast::CurveTy::Vesta => CurveTy::Vesta,
+        ast::CurveTy::Bls12381G2 => CurveTy::Bls12381G2,
     }
 }
 
@@ -2025,10 +2025,15 @@ pub fn new_field(tcx: TyCtxt<'tcx>, f: ty::FieldTy) -> Ty<'tcx> {
         match f {
             Bls12381Base => tcx.types.__zkllvm_field_bls12381_base,
             Bls12381Scalar => tcx.types.__zkllvm_field_bls12381_scalar,
+            Bn254Base => tcx.types.__zkllvm_field_bn254_base,
+            Bn254Scalar => tcx.types.__zkllvm_field_bn254_scalar,
+            Secp256k1Base => tcx.types.__zkllvm_field_secp256k1_base,
+            Secp256k1Scalar => tcx.types.__zkllvm_field_secp256k1_scalar,
             Curve25519Base => tcx.types.__zkllvm_field_curve25519_base,
             Curve25519Scalar => tcx.types.__zkllvm_field_curve25519_scalar,
             PallasBase => tcx.types.__zkllvm_field_pallas_base,
             PallasScalar => tcx.types.__zkllvm_field_pallas_scalar,
+            Bls12381Gt => tcx.types.__zkllvm_field_bls12381_gt,
         }
     }
 
@@ -2037,9 +2042,12 @@ pub fn new_curve(tcx: TyCtxt<'tcx>, c: ty::CurveTy) -> Ty<'tcx> {
         use ty::CurveTy::*;
         match c {
             Bls12381 => tcx.types.__zkllvm_curve_bls12381,
+            Bn254 => tcx.types.__zkllvm_curve_bn254,
+            Secp256k1 => tcx.types.__zkllvm_curve_secp256k1,
             Curve25519 => tcx.types.__zkllvm_curve_curve25519,
             Pallas => tcx.types.__zkllvm_curve_pallas,
             Vesta => tcx.types.__zkllvm_curve_vesta,
+            Bls12381G2 => tcx.types.__zkllvm_curve_bls12381_g2,
         }
     }
 
@@ -2464,21 +2472,22 @@ pub fn boxed_ty(self) -> Ty<'tcx> {
     /// A scalar type is one that denotes an atomic datum, with no sub-components.
     /// (A RawPtr is scalar because it represents a non-managed pointer, so its
     /// contents are abstract to rustc.)
+    /// Extension fields and curves over them are not scalar, they are only operated on
+    /// through intrinsics.
     #[inline]
     pub fn is_scalar(self) -> bool {
         matches!(
             self.kind(),
             Bool | Char
                 | Int(_)
-                | Field(_)
-                | Curve(_)
                 | Float(_)
                 | Uint(_)
                 | FnDef(..)
                 | FnPtr(_)
                 | RawPtr(_)
-                | Infer(IntVar(_) | FloatVar(_) | FieldVar(_))
-        )
+                | Infer(IntVar(_) | FloatVar(_))
+        ) || self.is_field()
+            || self.is_curve()
     }
 
     /// Returns `true` if this type is a floating point type.
@@ -2522,14 +2531,20 @@ pub fn is_integral(self) -> bool {
         matches!(self.kind(), Infer(IntVar(_)) | Int(_) | Uint(_))
     }
 
+    /// Returns `true` for prime field types, which have built-in arithmetic.
     #[inline]
     pub fn is_field(self) -> bool {
-        matches!(self.kind(), Infer(FieldVar(_)) | Field(_))
+        match self.kind() {
+            Infer(FieldVar(_)) => true,
+            Field(f) => f.degree() == 1,
+            _ => false,
+        }
     }
 
+    /// Returns `true` for curves over prime fields, which have built-in arithmetic.
     #[inline]
     pub fn is_curve(self) -> bool {
-        matches!(self.kind(), Curve(_))
+        matches!(self.kind(), Curve(c) if c.degree() == 1)
     }
 
     #[inline]
@@ -2630,9 +2645,12 @@ pub fn curve_base_field(self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
         if let TyKind::Curve(curve_ty) = self.kind() {
             match curve_ty {
                 ty::CurveTy::Bls12381 => tcx.types.__zkllvm_field_bls12381_base,
+                ty::CurveTy::Bn254 => tcx.types.__zkllvm_field_bn254_base,
+                ty::CurveTy::Secp256k1 => tcx.types.__zkllvm_field_secp256k1_base,
                 ty::CurveTy::Curve25519 => tcx.types.__zkllvm_field_curve25519_base,
                 ty::CurveTy::Pallas => tcx.types.__zkllvm_field_pallas_base,
                 ty::CurveTy::Vesta => tcx.types.__zkllvm_field_pallas_scalar,
+                ty::CurveTy::Bls12381G2 => tcx.types.__zkllvm_field_bls12381_base,
             }
         } else {
             bug!("not a curve type given to Ty::curve_scalar_field")
@@ -2646,9 +2664,12 @@ pub fn curve_scalar_field(self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
         if let TyKind::Curve(curve_ty) = self.kind() {
             match curve_ty {
                 ty::CurveTy::Bls12381 => tcx.types.__zkllvm_field_bls12381_scalar,
+                ty::CurveTy::Bn254 => tcx.types.__zkllvm_field_bn254_scalar,
+                ty::CurveTy::Secp256k1 => tcx.types.__zkllvm_field_secp256k1_scalar,
                 ty::CurveTy::Curve25519 => tcx.types.__zkllvm_field_curve25519_scalar,
                 ty::CurveTy::Pallas => tcx.types.__zkllvm_field_pallas_scalar,
                 ty::CurveTy::Vesta => tcx.types.__zkllvm_field_pallas_base,
+                ty::CurveTy::Bls12381G2 => tcx.types.__zkllvm_field_bls12381_scalar,
             }
         } else {
             bug!("not a curve type given to Ty::curve_scalar_field")
@@ -2998,16 +3019,24 @@ pub fn primitive_symbol(self) -> Option<Symbol> {
             ty::Field(f) => match f {
                 ty::FieldTy::Bls12381Base => Some(sym::__zkllvm_field_bls12381_base),
                 ty::FieldTy::Bls12381Scalar => Some(sym::__zkllvm_field_bls12381_scalar),
+                ty::FieldTy::Bn254Base => Some(sym::__zkllvm_field_bn254_base),
+                ty::FieldTy::Bn254Scalar => Some(sym::__zkllvm_field_bn254_scalar),
+                ty::FieldTy::Secp256k1Base => Some(sym::__zkllvm_field_secp256k1_base),
+                ty::FieldTy::Secp256k1Scalar => Some(sym::__zkllvm_field_secp256k1_scalar),
                 ty::FieldTy::Curve25519Base => Some(sym::__zkllvm_field_curve25519_base),
                 ty::FieldTy::Curve25519Scalar => Some(sym::__zkllvm_field_curve25519_scalar),
                 ty::FieldTy::PallasBase => Some(sym::__zkllvm_field_pallas_base),
                 ty::FieldTy::PallasScalar => Some(sym::__zkllvm_field_pallas_scalar),
+                ty::FieldTy::Bls12381Gt => Some(sym::__zkllvm_field_bls12381_gt),
             },
             ty::Curve(c) => match c {
                 ty::CurveTy::Bls12381 => Some(sym::__zkllvm_curve_bls12381),
+                ty::CurveTy::Bn254 => Some(sym::__zkllvm_curve_bn254),
+                ty::CurveTy::Secp256k1 => Some(sym::__zkllvm_curve_secp256k1),
                 ty::CurveTy::Curve25519 => Some(sym::__zkllvm_curve_curve25519),
                 ty::CurveTy::Pallas => Some(sym::__zkllvm_curve_pallas),
                 ty::CurveTy::Vesta => Some(sym::__zkllvm_curve_vesta),
+                ty::CurveTy::Bls12381G2 => Some(sym::__zkllvm_curve_bls12381_g2),
             }
             ty::Float(f) => match f {
                 ty::FloatTy::F32 => Some(sym::f32),
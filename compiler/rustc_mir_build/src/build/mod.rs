@@ -1057,27 +1057,12 @@ fn parse_field_into_scalar_field(
     rest.append(&mut bytes_be);
 
     let size = Size::from_bits(field_ty.bit_width());
-    let mut scalar = ScalarField::from_be_bytes(rest.as_slice().try_into().unwrap(), size);
-
-    let modulus = match field_ty {
-        ty::FieldTy::Bls12381Base => ScalarField::BLS12381_BASE_MODULUS,
-        ty::FieldTy::Bls12381Scalar => ScalarField::BLS12381_SCALAR_MODULUS,
-        ty::FieldTy::Curve25519Base => ScalarField::CURVE25519_BASE_MODULUS,
-        ty::FieldTy::Curve25519Scalar => ScalarField::CURVE25519_SCALAR_MODULUS,
-        ty::FieldTy::PallasBase => ScalarField::PALLAS_BASE_MODULUS,
-        ty::FieldTy::PallasScalar => ScalarField::PALLAS_SCALAR_MODULUS,
-    };
+    let scalar = ScalarField::from_be_bytes(rest.as_slice().try_into().unwrap(), size);
 
     // Deal with overflow
-    if scalar.data() >= modulus.data() {
-        scalar = ScalarField::from_u384(scalar.data().wrapping_rem(&modulus.data()), size);
-    }
+    let scalar = scalar.reduce(field_ty);
 
-    Some(if neg && scalar.data() > 0u32.into() {
-        ScalarField::from_u384(modulus.data().saturating_sub(&scalar.data()), size)
-    } else {
-        scalar
-    })
+    Some(if neg { scalar.neg_mod(field_ty) } else { scalar })
 }
 
 ///////////////////////////////////////////////////////////////////////////
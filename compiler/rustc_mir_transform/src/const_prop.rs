@@ -23,7 +23,7 @@
 use rustc_const_eval::interpret::{
     self, compile_time_machine, AllocId, ConstAllocation, ConstValue, FnArg, Frame, ImmTy,
     Immediate, InterpCx, InterpResult, LocalValue, MemoryKind, OpTy, PlaceTy, Pointer, Scalar,
-    StackPopCleanup,
+    ScalarField, StackPopCleanup,
 };
 
 /// The maximum number of bytes that we'll allocate space for a local or the return value.
@@ -500,6 +500,15 @@ fn eval_rvalue_with_identities(
                     (Ok(_), Ok(_)) => return self.ecx.eval_rvalue_into_place(rvalue, place).ok(), // both sides are known
                 };
 
+                if let Immediate::Field(field) = *const_arg {
+                    // `x * 0 == 0` for any field element `x`, known or not.
+                    if *op == BinOp::Mul && field.is_zero() {
+                        let dest = self.ecx.eval_place(place).ok()?;
+                        return self.ecx.write_immediate(*const_arg, &dest).ok();
+                    }
+                    return None;
+                }
+
                 if !matches!(const_arg.layout.abi, abi::Abi::Scalar(..)) {
                     // We cannot handle Scalar Pair stuff.
                     // No point in calling `eval_rvalue_into_place`, since only one side is known
@@ -546,6 +555,15 @@ fn operand_from_scalar(&self, scalar: Scalar, ty: Ty<'tcx>) -> Operand<'tcx> {
         }))
     }
 
+    /// Creates a new `Operand::Constant` from a `ScalarField` value
+    fn operand_from_field(&self, field: ScalarField, ty: Ty<'tcx>) -> Operand<'tcx> {
+        Operand::Constant(Box::new(Constant {
+            span: DUMMY_SP,
+            user_ty: None,
+            literal: ConstantKind::from_value(ConstValue::Field(field), ty),
+        }))
+    }
+
     fn replace_with_const(&mut self, place: Place<'tcx>) -> Option<Operand<'tcx>> {
         // This will return None if the above `const_prop` invocation only "wrote" a
         // type whose creation requires no write. E.g. a generator whose initial state
@@ -564,6 +582,7 @@ fn replace_with_const(&mut self, place: Place<'tcx>) -> Option<Operand<'tcx>> {
             Immediate::Scalar(scalar) if scalar.try_to_int().is_ok() => {
                 Some(self.operand_from_scalar(scalar, value.layout.ty))
             }
+            Immediate::Field(field) => Some(self.operand_from_field(field, value.layout.ty)),
             Immediate::ScalarPair(l, r) if l.try_to_int().is_ok() && r.try_to_int().is_ok() => {
                 let alloc = self
                     .ecx
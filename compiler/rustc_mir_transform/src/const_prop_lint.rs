@@ -320,6 +320,10 @@ fn report_assert_as_lint(&self, source_info: &SourceInfo, lint: AssertLint<impl
     }
 
     fn check_unary_op(&mut self, op: UnOp, arg: &Operand<'tcx>, location: Location) -> Option<()> {
+        // Field negation never overflows.
+        if arg.ty(self.local_decls(), self.tcx).is_field() {
+            return Some(());
+        }
         if let (val, true) = self.use_ecx(location, |this| {
             let val = this.ecx.read_immediate(&this.ecx.eval_operand(arg, None)?)?;
             let (_res, overflow, _ty) = this.ecx.overflowing_unary_op(op, &val)?;
@@ -387,6 +391,11 @@ fn check_binary_op(
             }
         }
 
+        // Field arithmetic is modular and never overflows.
+        if left.ty(self.local_decls(), self.tcx).is_field() {
+            return Some(());
+        }
+
         if let (Some(l), Some(r)) = (l, r) {
             // The remaining operators are handled through `overflowing_binary_op`.
             if self.use_ecx(location, |this| {
@@ -1,9 +1,9 @@
 //! A constant propagation optimization pass based on dataflow analysis.
 //!
-//! Currently, this pass only propagates scalar values.
+//! Currently, this pass only propagates scalar values and field elements.
 
 use rustc_const_eval::const_eval::CheckAlignment;
-use rustc_const_eval::interpret::{ConstValue, ImmTy, Immediate, InterpCx, Scalar};
+use rustc_const_eval::interpret::{ConstValue, ImmTy, Immediate, InterpCx, Scalar, ScalarField};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def::DefKind;
 use rustc_middle::mir::visit::{MutVisitor, Visitor};
@@ -239,11 +239,13 @@ fn handle_constant(
         constant: &Constant<'tcx>,
         _state: &mut State<Self::Value>,
     ) -> Self::Value {
-        constant
-            .literal
-            .eval(self.tcx, self.param_env)
+        let literal = constant.literal.eval(self.tcx, self.param_env);
+        if let Some(field) = literal.try_to_field() {
+            return FlatSet::Elem(ScalarTy::Field(field, constant.ty()));
+        }
+        literal
             .try_to_scalar()
-            .map(|value| FlatSet::Elem(ScalarTy(value, constant.ty())))
+            .map(|value| FlatSet::Elem(ScalarTy::Scalar(value, constant.ty())))
             .unwrap_or(FlatSet::Top)
     }
 
@@ -261,23 +263,39 @@ fn handle_switch_int<'mir>(
             // We are branching on uninitialized data, this is UB, treat it as unreachable.
             // This allows the set of visited edges to grow monotonically with the lattice.
             FlatSet::Bottom => TerminatorEdges::None,
-            FlatSet::Elem(ScalarTy(scalar, _)) => {
+            FlatSet::Elem(ScalarTy::Scalar(scalar, _)) => {
                 let int = scalar.assert_int();
                 let choice = int.assert_bits(int.size());
                 TerminatorEdges::Single(targets.target_for_value(choice))
             }
-            FlatSet::Top => TerminatorEdges::SwitchInt { discr, targets },
+            // Field elements are never switched on.
+            FlatSet::Elem(ScalarTy::Field(..)) | FlatSet::Top => {
+                TerminatorEdges::SwitchInt { discr, targets }
+            }
         }
     }
 }
 
 #[derive(Clone, PartialEq, Eq)]
-struct ScalarTy<'tcx>(Scalar, Ty<'tcx>);
+enum ScalarTy<'tcx> {
+    Scalar(Scalar, Ty<'tcx>),
+    /// Field elements do not fit into a `Scalar`, so they are tracked separately.
+    Field(ScalarField, Ty<'tcx>),
+}
+
+impl<'tcx> ScalarTy<'tcx> {
+    fn literal(&self) -> ConstantKind<'tcx> {
+        match *self {
+            ScalarTy::Scalar(scalar, ty) => ConstantKind::Val(ConstValue::Scalar(scalar), ty),
+            ScalarTy::Field(field, ty) => ConstantKind::Val(ConstValue::Field(field), ty),
+        }
+    }
+}
 
 impl<'tcx> std::fmt::Debug for ScalarTy<'tcx> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // This is used for dataflow visualization, so we return something more concise.
-        std::fmt::Display::fmt(&ConstantKind::Val(ConstValue::Scalar(self.0), self.1), f)
+        std::fmt::Display::fmt(&self.literal(), f)
     }
 }
 
@@ -303,6 +321,13 @@ fn binary_op(
         let left = self.eval_operand(left, state);
         let right = self.eval_operand(right, state);
         match (left, right) {
+            (FlatSet::Elem(left), FlatSet::Elem(right)) if left.layout.ty.is_field() => {
+                // Field arithmetic never overflows, but its result is not a `Scalar`.
+                match self.ecx.binary_op(op, &left, &right) {
+                    Ok(val) => (self.wrap_immty(val), FlatSet::Elem(false)),
+                    _ => (FlatSet::Top, FlatSet::Top),
+                }
+            }
             (FlatSet::Elem(left), FlatSet::Elem(right)) => {
                 match self.ecx.overflowing_binary_op(op, &left, &right) {
                     Ok((val, overflow, ty)) => (self.wrap_scalar(val, ty), FlatSet::Elem(overflow)),
@@ -328,11 +353,16 @@ fn eval_operand(
         };
         match value {
             FlatSet::Top => FlatSet::Top,
-            FlatSet::Elem(ScalarTy(scalar, ty)) => self
+            FlatSet::Elem(ScalarTy::Scalar(scalar, ty)) => self
                 .tcx
                 .layout_of(self.param_env.and(ty))
                 .map(|layout| FlatSet::Elem(ImmTy::from_scalar(scalar, layout)))
                 .unwrap_or(FlatSet::Top),
+            FlatSet::Elem(ScalarTy::Field(field, ty)) => self
+                .tcx
+                .layout_of(self.param_env.and(ty))
+                .map(|layout| FlatSet::Elem(ImmTy::from_immediate(field.into(), layout)))
+                .unwrap_or(FlatSet::Top),
             FlatSet::Bottom => FlatSet::Bottom,
         }
     }
@@ -348,16 +378,17 @@ fn eval_discriminant(
         let discr = enum_ty.discriminant_for_variant(self.tcx, variant_index)?;
         let discr_layout = self.tcx.layout_of(self.param_env.and(discr.ty)).ok()?;
         let discr_value = Scalar::try_from_uint(discr.val, discr_layout.size)?;
-        Some(ScalarTy(discr_value, discr.ty))
+        Some(ScalarTy::Scalar(discr_value, discr.ty))
     }
 
     fn wrap_scalar(&self, scalar: Scalar, ty: Ty<'tcx>) -> FlatSet<ScalarTy<'tcx>> {
-        FlatSet::Elem(ScalarTy(scalar, ty))
+        FlatSet::Elem(ScalarTy::Scalar(scalar, ty))
     }
 
     fn wrap_immediate(&self, imm: Immediate, ty: Ty<'tcx>) -> FlatSet<ScalarTy<'tcx>> {
         match imm {
             Immediate::Scalar(scalar) => self.wrap_scalar(scalar, ty),
+            Immediate::Field(field) => FlatSet::Elem(ScalarTy::Field(field, ty)),
             _ => FlatSet::Top,
         }
     }
@@ -388,7 +419,7 @@ fn make_operand(&self, scalar: ScalarTy<'tcx>) -> Operand<'tcx> {
         Operand::Constant(Box::new(Constant {
             span: DUMMY_SP,
             user_ty: None,
-            literal: ConstantKind::Val(ConstValue::Scalar(scalar.0), scalar.1),
+            literal: scalar.literal(),
         }))
     }
 }
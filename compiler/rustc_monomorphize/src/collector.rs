@@ -604,6 +604,14 @@ pub fn monomorphize<T>(&self, value: T) -> T
             ty::EarlyBinder::bind(value),
         )
     }
+
+    fn push_soft_zk_instance(&mut self, lang_item: LangItem, ty: Ty<'tcx>, span: Span) {
+        let def_id = self.tcx.require_lang_item(lang_item, Some(span));
+        let instance = Instance::new(def_id, self.tcx.mk_args(&[ty.into()]));
+        if should_codegen_locally(self.tcx, &instance) {
+            self.output.push(create_fn_mono_item(self.tcx, instance, span));
+        }
+    }
 }
 
 impl<'a, 'tcx> MirVisitor<'tcx> for MirUsedCollector<'a, 'tcx> {
@@ -682,6 +690,22 @@ fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
                     self.output.push(respan(span, MonoItem::Static(def_id)));
                 }
             }
+            // On targets without native field types field and curve operations are
+            // calls into the software runtime, see `TyCtxt::soft_zk_binop`.
+            mir::Rvalue::BinaryOp(op, ref operands) => {
+                let (lhs, rhs) = &**operands;
+                let lhs_ty = self.monomorphize(lhs.ty(self.body, self.tcx));
+                let rhs_ty = self.monomorphize(rhs.ty(self.body, self.tcx));
+                if let Some((lang_item, ty, _)) = self.tcx.soft_zk_binop(op, lhs_ty, rhs_ty) {
+                    self.push_soft_zk_instance(lang_item, ty, span);
+                }
+            }
+            mir::Rvalue::UnaryOp(op, ref operand) => {
+                let ty = self.monomorphize(operand.ty(self.body, self.tcx));
+                if let Some(lang_item) = self.tcx.soft_zk_unop(op, ty) {
+                    self.push_soft_zk_instance(lang_item, ty, span);
+                }
+            }
             _ => { /* not interesting */ }
         }
 
@@ -896,6 +920,14 @@ fn visit_instance_use<'tcx>(
     output: &mut MonoItems<'tcx>,
 ) {
     debug!("visit_item_use({:?}, is_direct_call={:?})", instance, is_direct_call);
+    // Outside the assigner its intrinsics are calls to their software implementations.
+    let instance = tcx.assigner_intrinsic_fallback(instance).unwrap_or(instance);
+    // The backend lowers the curve equation check to a call of the software runtime.
+    if let Some(runtime) = tcx.assigner_is_on_curve_runtime(instance) {
+        if should_codegen_locally(tcx, &runtime) {
+            output.push(create_fn_mono_item(tcx, runtime, source));
+        }
+    }
     if !should_codegen_locally(tcx, &instance) {
         return;
     }
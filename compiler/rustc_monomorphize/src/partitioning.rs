@@ -114,7 +114,6 @@
 use rustc_middle::ty::print::{characteristic_def_id_of_type, with_no_trimmed_paths};
 use rustc_middle::ty::{self, visit::TypeVisitableExt, InstanceDef, TyCtxt};
 use rustc_session::config::{DumpMonoStatsFormat, SwitchWithOptPath};
-use rustc_session::config::EntryFnType;
 use rustc_session::CodegenUnits;
 use rustc_span::symbol::Symbol;
 
@@ -803,14 +802,12 @@ fn mono_item_visibility<'tcx>(
     }
 
     // We explicitly set circuit function visibility to default, so it won't be eliminated.
-    let entry = tcx.entry_fn(());
-    if tcx.sess.target.is_like_assigner {
-        if let Some((entry_id, entry_type)) = entry {
-            if entry_id == def_id && entry_type == EntryFnType::Circuit {
-                *can_be_internalized = false;
-                return Visibility::Default;
-            }
-        }
+    // This covers the circuits of libraries as well, an executable may pick one of them.
+    if tcx.sess.target.is_like_assigner
+        && tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::CIRCUIT)
+    {
+        *can_be_internalized = false;
+        return Visibility::Default;
     }
 
     let is_generic = instance.args.non_erasable_generics().next().is_some();
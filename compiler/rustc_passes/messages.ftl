@@ -55,6 +55,9 @@ passes_attr_only_on_root_main =
 passes_both_ffi_const_and_pure =
     `#[ffi_const]` function cannot be `#[ffi_pure]`
 
+passes_both_public_and_private_input =
+    a circuit parameter cannot be both `#[public_input]` and `#[private_input]`
+
 passes_break_inside_async_block =
     `{$name}` inside of an `async` block
     .label = cannot `{$name}` inside of an `async` block
@@ -89,6 +92,36 @@ passes_change_fields_to_be_of_unit_type =
      *[other] fields
     }
 
+passes_circuit_candidates =
+    select one of the circuits in the crate graph with `-Z circuit-entry=PATH`: {$candidates}
+
+passes_circuit_entry_not_executable =
+    `-Z circuit-entry` has no effect on crates that are not executables
+    .note = libraries have no circuit of their own, their `#[circuit]` functions are exported for executables to pick from
+
+passes_circuit_entry_not_found =
+    `-Z circuit-entry={$path}` does not name a public `#[circuit]` function
+
+passes_circuit_input_invalid_target =
+    `#[{$attr}]` may only be used on parameters of a `#[circuit]` function
+
+passes_circuit_unsupported_type =
+    the {$position ->
+        [return] return type
+        *[param] parameter
+    } of a `#[circuit]` function cannot be of type `{$ty}`
+    .label = contains `{$unsupported}`
+    .note = {$reason ->
+        [reference] the assigner reads circuit inputs by value and cannot follow references
+        [pointer] the assigner cannot follow pointers
+        [dyn] trait objects have no layout the assigner could read
+        [float] circuits have no floating-point arithmetic, use integers or field elements instead
+        [padding] the padding bytes of `{$unsupported}` have no value in the circuit
+        [repr] the fields of `{$unsupported}` may be reordered, add `#[repr(C)]` to give it a defined layout
+        *[other] only integers, booleans, field elements, curve points and arrays, tuples and `#[repr(C)]` structs of them are supported
+    }
+    .suggestion = pass the value itself
+
 passes_cold =
     {passes_should_be_applied_to_fn}
     .warn = {-passes_previously_accepted}
@@ -499,6 +532,12 @@ passes_naked_functions_operands =
 passes_naked_tracked_caller =
     cannot use `#[track_caller]` with `#[naked]`
 
+passes_no_circuit_candidates =
+    consider adding `#[circuit]` to the function describing the circuit
+
+passes_no_circuit_function =
+    no `#[circuit]` function found in crate `{$crate_name}`
+
 passes_no_coverage_fn_defn =
     `#[no_coverage]` may only be applied to function definitions
 
@@ -176,6 +176,9 @@ fn check_attributes(
                 sym::ffi_pure => self.check_ffi_pure(attr.span, attrs, target),
                 sym::ffi_const => self.check_ffi_const(attr.span, target),
                 sym::ffi_returns_twice => self.check_ffi_returns_twice(attr.span, target),
+                sym::public_input | sym::private_input => {
+                    self.check_circuit_input(hir_id, attr, attrs, target)
+                }
                 sym::rustc_const_unstable
                 | sym::rustc_const_stable
                 | sym::unstable
@@ -1238,6 +1241,33 @@ fn check_ffi_pure(&self, attr_span: Span, attrs: &[Attribute], target: Target) -
         }
     }
 
+    /// Checks that `#[public_input]` or `#[private_input]` is applied to a parameter of a
+    /// `#[circuit]` function, and that the parameter does not have both of them.
+    fn check_circuit_input(
+        &self,
+        hir_id: HirId,
+        attr: &Attribute,
+        attrs: &[Attribute],
+        target: Target,
+    ) -> bool {
+        let name = attr.name_or_empty();
+        if target != Target::Param
+            || !self.tcx.has_attr(self.tcx.hir().enclosing_body_owner(hir_id), sym::circuit)
+        {
+            self.tcx
+                .sess
+                .emit_err(errors::CircuitInputInvalidTarget { attr_span: attr.span, attr: name });
+            return false;
+        }
+        // The conflict is reported once, on the `#[public_input]` attribute.
+        if name == sym::public_input && attrs.iter().any(|a| a.has_name(sym::private_input)) {
+            self.tcx.sess.emit_err(errors::BothPublicAndPrivateInput { attr_span: attr.span });
+            false
+        } else {
+            true
+        }
+    }
+
     fn check_ffi_const(&self, attr_span: Span, target: Target) -> bool {
         if target == Target::ForeignFn {
             true
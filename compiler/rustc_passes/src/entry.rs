@@ -1,11 +1,14 @@
 use rustc_ast::attr;
 use rustc_ast::entry::EntryPointType;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::error_code;
-use rustc_hir::def::DefKind;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::{DefId, LocalDefId, CRATE_DEF_ID, LOCAL_CRATE};
-use rustc_hir::{ItemId, Node, CRATE_HIR_ID};
+use rustc_hir::{self as hir, ItemId, Node, CRATE_HIR_ID};
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::query::Providers;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{self, TyCtxt};
 use rustc_session::config::{sigpipe, CrateType, EntryFnType};
 use rustc_session::parse::feature_err;
 use rustc_span::symbol::sym;
@@ -15,7 +18,10 @@
     AttrOnlyInFunctions, AttrOnlyOnMain, AttrOnlyOnRootMain, ExternMain, MultipleRustcMain,
     MultipleStartFunctions, NoMainErr, UnixSigpipeValues,
 };
-use crate::errors::MultipleCircuitFunctions;
+use crate::errors::{
+    CircuitCandidates, CircuitEntryNotExecutable, CircuitEntryNotFound, CircuitUnsupportedType,
+    MultipleCircuitFunctions, NoCircuitFunction,
+};
 
 struct EntryContext<'tcx> {
     tcx: TyCtxt<'tcx>,
@@ -26,8 +32,8 @@ struct EntryContext<'tcx> {
     /// The function that has the attribute 'start' on it.
     start_fn: Option<(LocalDefId, Span)>,
 
-    /// The function that has the attribute 'circuit' on it.
-    circuit_fn: Option<(LocalDefId, Span)>,
+    /// The functions that have the attribute 'circuit' on them.
+    circuit_fns: Vec<(LocalDefId, Span)>,
 
     /// The functions that one might think are `main` but aren't, e.g.
     /// main functions not defined at the top level. For diagnostics.
@@ -51,7 +57,7 @@ fn entry_fn(tcx: TyCtxt<'_>, (): ()) -> Option<(DefId, EntryFnType)> {
         tcx,
         attr_main_fn: None,
         start_fn: None,
-        circuit_fn: None,
+        circuit_fns: Vec::new(),
         non_main_fns: Vec::new(),
     };
 
@@ -60,7 +66,7 @@ fn entry_fn(tcx: TyCtxt<'_>, (): ()) -> Option<(DefId, EntryFnType)> {
     }
 
     if assigner_target {
-        return ctxt.circuit_fn.map(|(local_def_id, _)| (local_def_id.to_def_id(), EntryFnType::Circuit));
+        return configure_circuit(tcx, &ctxt);
     }
 
     configure_main(tcx, &ctxt)
@@ -147,17 +153,173 @@ fn find_item(id: ItemId, ctxt: &mut EntryContext<'_>) {
             }
         }
         EntryPointType::Circuit => {
-            if ctxt.circuit_fn.is_none() {
-                ctxt.circuit_fn = Some((id.owner_id.def_id, ctxt.tcx.def_span(id.owner_id)))
-            } else {
-                ctxt.tcx.sess.emit_err(MultipleCircuitFunctions {
-                    span: ctxt.tcx.def_span(id.owner_id),
-                    labeled: ctxt.tcx.def_span(id.owner_id.to_def_id()),
-                    previous: ctxt.circuit_fn.unwrap().1,
-                });
+            ctxt.circuit_fns.push((id.owner_id.def_id, ctxt.tcx.def_span(id.owner_id)));
+        }
+    }
+}
+
+/// Picks the circuit of an assigner executable: the function named by `-Z circuit-entry`,
+/// or else the `#[circuit]` function of the crate.
+///
+/// Libraries have no entry point, the circuits they define are exported for executables
+/// to pick from.
+fn configure_circuit(tcx: TyCtxt<'_>, ctxt: &EntryContext<'_>) -> Option<(DefId, EntryFnType)> {
+    for &(def_id, _) in &ctxt.circuit_fns {
+        check_circuit_signature(tcx, def_id);
+    }
+    if !tcx.crate_types().contains(&CrateType::Executable) {
+        if tcx.sess.opts.unstable_opts.circuit_entry.is_some() {
+            tcx.sess.emit_warning(CircuitEntryNotExecutable);
+        }
+        return None;
+    }
+
+    if let Some(path) = &tcx.sess.opts.unstable_opts.circuit_entry {
+        let Some(def_id) = resolve_circuit_entry(tcx, ctxt, path) else {
+            let candidates = circuit_candidates(tcx, ctxt);
+            tcx.sess.emit_err(CircuitEntryNotFound { path: path.clone(), candidates });
+            return None;
+        };
+        return Some((def_id, EntryFnType::Circuit));
+    }
+
+    let Some(&(def_id, first)) = ctxt.circuit_fns.first() else {
+        tcx.sess.emit_err(NoCircuitFunction {
+            span: tcx.def_span(CRATE_DEF_ID).shrink_to_hi(),
+            crate_name: tcx.crate_name(LOCAL_CRATE),
+            candidates: circuit_candidates(tcx, ctxt),
+        });
+        return None;
+    };
+    for &(_, span) in &ctxt.circuit_fns[1..] {
+        tcx.sess.emit_err(MultipleCircuitFunctions {
+            span,
+            labeled: span,
+            previous: first,
+            candidates: circuit_candidates(tcx, ctxt),
+        });
+    }
+    Some((def_id.to_def_id(), EntryFnType::Circuit))
+}
+
+fn is_circuit(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
+    tcx.def_kind(def_id) == DefKind::Fn
+        && tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::CIRCUIT)
+}
+
+/// Returns the path of a circuit as `-Z circuit-entry` takes it, starting with the crate name.
+fn circuit_path(tcx: TyCtxt<'_>, def_id: DefId) -> String {
+    let path = with_no_trimmed_paths!(tcx.def_path_str(def_id));
+    if def_id.is_local() { format!("{}::{path}", tcx.crate_name(LOCAL_CRATE)) } else { path }
+}
+
+/// Resolves the `-Z circuit-entry` path to a `#[circuit]` function, either of the local crate
+/// or a public one of a dependency.
+fn resolve_circuit_entry(tcx: TyCtxt<'_>, ctxt: &EntryContext<'_>, path: &str) -> Option<DefId> {
+    let (krate, rest) = path.split_once("::")?;
+    if krate == "crate" || krate == tcx.crate_name(LOCAL_CRATE).as_str() {
+        return ctxt
+            .circuit_fns
+            .iter()
+            .map(|&(def_id, _)| def_id.to_def_id())
+            .find(|&def_id| with_no_trimmed_paths!(tcx.def_path_str(def_id)) == rest);
+    }
+    let cnum = tcx.crates(()).iter().find(|&&cnum| tcx.crate_name(cnum).as_str() == krate)?;
+    let mut def_id = cnum.as_def_id();
+    for segment in rest.split("::") {
+        if tcx.def_kind(def_id) != DefKind::Mod {
+            return None;
+        }
+        let child = tcx
+            .module_children(def_id)
+            .iter()
+            .find(|child| child.vis.is_public() && child.ident.as_str() == segment)?;
+        def_id = child.res.opt_def_id()?;
+    }
+    is_circuit(tcx, def_id).then_some(def_id)
+}
+
+/// Lists the circuits an executable can pick with `-Z circuit-entry`.
+fn circuit_candidates(tcx: TyCtxt<'_>, ctxt: &EntryContext<'_>) -> CircuitCandidates {
+    fn exported_circuits(
+        tcx: TyCtxt<'_>,
+        module: DefId,
+        visited: &mut FxHashSet<DefId>,
+        circuits: &mut Vec<DefId>,
+    ) {
+        for child in tcx.module_children(module) {
+            let Res::Def(kind, def_id) = child.res else {
+                continue;
+            };
+            if !child.vis.is_public() || !visited.insert(def_id) {
+                continue;
+            }
+            match kind {
+                DefKind::Mod => exported_circuits(tcx, def_id, visited, circuits),
+                DefKind::Fn if is_circuit(tcx, def_id) => circuits.push(def_id),
+                _ => {}
             }
         }
     }
+
+    let mut circuits: Vec<_> =
+        ctxt.circuit_fns.iter().map(|&(def_id, _)| def_id.to_def_id()).collect();
+    let mut visited = FxHashSet::default();
+    for &cnum in tcx.crates(()) {
+        exported_circuits(tcx, cnum.as_def_id(), &mut visited, &mut circuits);
+    }
+    if circuits.is_empty() {
+        return CircuitCandidates::NotFound;
+    }
+    let candidates = circuits
+        .into_iter()
+        .map(|def_id| format!("`{}`", circuit_path(tcx, def_id)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    CircuitCandidates::Found { candidates }
+}
+
+/// Reports the parameter and return types of the `#[circuit]` function that the assigner
+/// cannot read as circuit inputs or write as outputs.
+fn check_circuit_signature(tcx: TyCtxt<'_>, def_id: LocalDefId) {
+    let sig = tcx.normalize_erasing_late_bound_regions(
+        ty::ParamEnv::reveal_all(),
+        tcx.fn_sig(def_id).instantiate_identity(),
+    );
+    let Some(decl) = tcx.hir().get_by_def_id(def_id).fn_decl() else {
+        return;
+    };
+    let hir_tys = decl.inputs.iter().map(|hir_ty| (hir_ty.span, Some(hir_ty)));
+    let ret = match decl.output {
+        hir::FnRetTy::DefaultReturn(span) => (span, None),
+        hir::FnRetTy::Return(hir_ty) => (hir_ty.span, Some(hir_ty)),
+    };
+    let positions = std::iter::repeat("param").take(sig.inputs().len()).chain(["return"]);
+    for ((&ty, (span, hir_ty)), position) in
+        sig.inputs_and_output.iter().zip(hir_tys.chain([ret])).zip(positions)
+    {
+        let Some((unsupported, reason)) = tcx.unsupported_circuit_ty(ty) else {
+            continue;
+        };
+        // A reference to a supported type can be replaced with the value itself.
+        let remove_ref = match hir_ty.map(|hir_ty| &hir_ty.kind) {
+            Some(hir::TyKind::Ref(_, pointee))
+                if reason == "reference"
+                    && tcx.unsupported_circuit_ty(ty.peel_refs()).is_none() =>
+            {
+                Some(span.until(pointee.ty.span))
+            }
+            _ => None,
+        };
+        tcx.sess.emit_err(CircuitUnsupportedType {
+            span,
+            ty,
+            unsupported,
+            position,
+            reason,
+            remove_ref,
+        });
+    }
 }
 
 fn configure_main(tcx: TyCtxt<'_>, visitor: &EntryContext<'_>) -> Option<(DefId, EntryFnType)> {
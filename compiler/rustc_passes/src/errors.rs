@@ -369,6 +369,21 @@ pub struct HasIncoherentInherentImpl {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_both_public_and_private_input)]
+pub struct BothPublicAndPrivateInput {
+    #[primary_span]
+    pub attr_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_circuit_input_invalid_target)]
+pub struct CircuitInputInvalidTarget {
+    #[primary_span]
+    pub attr_span: Span,
+    pub attr: Symbol,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_both_ffi_const_and_pure, code = "E0757")]
 pub struct BothFfiConstAndPure {
@@ -1200,6 +1215,54 @@ pub struct MultipleCircuitFunctions {
     pub labeled: Span,
     #[label(passes_previous)]
     pub previous: Span,
+    #[subdiagnostic]
+    pub candidates: CircuitCandidates,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_no_circuit_function)]
+pub struct NoCircuitFunction {
+    #[primary_span]
+    pub span: Span,
+    pub crate_name: Symbol,
+    #[subdiagnostic]
+    pub candidates: CircuitCandidates,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_circuit_entry_not_found)]
+pub struct CircuitEntryNotFound {
+    pub path: String,
+    #[subdiagnostic]
+    pub candidates: CircuitCandidates,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_circuit_entry_not_executable)]
+#[note]
+pub struct CircuitEntryNotExecutable;
+
+#[derive(Subdiagnostic)]
+pub enum CircuitCandidates {
+    #[help(passes_circuit_candidates)]
+    Found { candidates: String },
+    #[help(passes_no_circuit_candidates)]
+    NotFound,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_circuit_unsupported_type)]
+#[note]
+pub struct CircuitUnsupportedType<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub unsupported: Ty<'tcx>,
+    pub position: &'static str,
+    pub reason: &'static str,
+    #[suggestion(code = "", applicability = "maybe-incorrect", style = "verbose")]
+    pub remove_ref: Option<Span>,
 }
 
 #[derive(Diagnostic)]
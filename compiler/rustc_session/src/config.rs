@@ -423,6 +423,8 @@ pub enum OutputType {
     Object,
     Exe,
     DepInfo,
+    CircuitAbi,
+    CircuitInputTemplate,
 }
 
 // Safety: Trivial C-Style enums have a stable sort order across compilation sessions.
@@ -441,7 +443,11 @@ fn to_stable_hash_key(&self, _: &HCX) -> Self::KeyType {
 impl OutputType {
     fn is_compatible_with_codegen_units_and_single_output_file(&self) -> bool {
         match *self {
-            OutputType::Exe | OutputType::DepInfo | OutputType::Metadata => true,
+            OutputType::Exe
+            | OutputType::DepInfo
+            | OutputType::Metadata
+            | OutputType::CircuitAbi
+            | OutputType::CircuitInputTemplate => true,
             OutputType::Bitcode
             | OutputType::Assembly
             | OutputType::LlvmAssembly
@@ -460,6 +466,8 @@ pub fn shorthand(&self) -> &'static str {
             OutputType::Metadata => "metadata",
             OutputType::Exe => "link",
             OutputType::DepInfo => "dep-info",
+            OutputType::CircuitAbi => "circuit-abi",
+            OutputType::CircuitInputTemplate => "circuit-input-template",
         }
     }
 
@@ -473,13 +481,15 @@ fn from_shorthand(shorthand: &str) -> Option<Self> {
             "metadata" => OutputType::Metadata,
             "link" => OutputType::Exe,
             "dep-info" => OutputType::DepInfo,
+            "circuit-abi" => OutputType::CircuitAbi,
+            "circuit-input-template" => OutputType::CircuitInputTemplate,
             _ => return None,
         })
     }
 
     fn shorthands_display() -> String {
         format!(
-            "`{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`",
+            "`{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`",
             OutputType::Bitcode.shorthand(),
             OutputType::Assembly.shorthand(),
             OutputType::LlvmAssembly.shorthand(),
@@ -488,6 +498,8 @@ fn shorthands_display() -> String {
             OutputType::Metadata.shorthand(),
             OutputType::Exe.shorthand(),
             OutputType::DepInfo.shorthand(),
+            OutputType::CircuitAbi.shorthand(),
+            OutputType::CircuitInputTemplate.shorthand(),
         )
     }
 
@@ -500,6 +512,8 @@ pub fn extension(&self) -> &'static str {
             OutputType::Object => "o",
             OutputType::Metadata => "rmeta",
             OutputType::DepInfo => "d",
+            OutputType::CircuitAbi => "abi.json",
+            OutputType::CircuitInputTemplate => "input.json",
             OutputType::Exe => "",
         }
     }
@@ -509,7 +523,9 @@ pub fn is_text_output(&self) -> bool {
             OutputType::Assembly
             | OutputType::LlvmAssembly
             | OutputType::Mir
-            | OutputType::DepInfo => true,
+            | OutputType::DepInfo
+            | OutputType::CircuitAbi
+            | OutputType::CircuitInputTemplate => true,
             OutputType::Bitcode | OutputType::Object | OutputType::Metadata | OutputType::Exe => {
                 false
             }
@@ -522,7 +538,9 @@ pub fn is_compatible_with_assigner_target(&self) -> bool {
             | OutputType::LlvmAssembly
             | OutputType::DepInfo
             | OutputType::Mir
-            | OutputType::Metadata => true,
+            | OutputType::Metadata
+            | OutputType::CircuitAbi
+            | OutputType::CircuitInputTemplate => true,
             OutputType::Assembly
             | OutputType::Exe
             | OutputType::Object => false,
@@ -619,7 +637,9 @@ pub fn should_codegen(&self) -> bool {
             | OutputType::LlvmAssembly
             | OutputType::Mir
             | OutputType::Object
-            | OutputType::Exe => true,
+            | OutputType::Exe
+            | OutputType::CircuitAbi
+            | OutputType::CircuitInputTemplate => true,
             OutputType::Metadata | OutputType::DepInfo => false,
         })
     }
@@ -633,7 +653,9 @@ pub fn should_link(&self) -> bool {
             | OutputType::Mir
             | OutputType::Metadata
             | OutputType::Object
-            | OutputType::DepInfo => false,
+            | OutputType::DepInfo
+            | OutputType::CircuitAbi
+            | OutputType::CircuitInputTemplate => false,
             OutputType::Exe => true,
         })
     }
@@ -1714,7 +1736,8 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
             "emit",
             "Comma separated list of types of output for \
              the compiler to emit",
-            "[asm|llvm-bc|llvm-ir|obj|metadata|link|dep-info|mir]",
+            "[asm|llvm-bc|llvm-ir|obj|metadata|link|dep-info|mir|\
+             circuit-abi|circuit-input-template]",
         ),
         opt::multi_s(
             "",
@@ -1414,6 +1414,9 @@ pub(crate) fn parse_dump_solver_proof_tree(
         "set options for branch target identification and pointer authentication on AArch64"),
     cf_protection: CFProtection = (CFProtection::None, parse_cfprotection, [TRACKED],
         "instrument control-flow architecture protection"),
+    circuit_entry: Option<String> = (None, parse_opt_string, [TRACKED],
+        "the path of the `#[circuit]` function used as the circuit of an assigner executable, \
+        e.g. `my_lib::my_circuit` for a circuit defined in a dependency"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
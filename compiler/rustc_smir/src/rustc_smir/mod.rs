@@ -303,6 +303,7 @@ fn stable(&self, tables: &mut Tables<'tcx>) -> Self::T {
             IntToFloat => stable_mir::mir::CastKind::IntToFloat,
             PtrToPtr => stable_mir::mir::CastKind::PtrToPtr,
             FnPtrToPtr => stable_mir::mir::CastKind::FnPtrToPtr,
+            IntToField => stable_mir::mir::CastKind::IntToField,
             Transmute => stable_mir::mir::CastKind::Transmute,
         }
     }
@@ -1025,10 +1026,15 @@ fn stable(&self, _: &mut Tables<'tcx>) -> Self::T {
         match self {
             ty::FieldTy::Bls12381Base => FieldTy::Bls12381Base,
             ty::FieldTy::Bls12381Scalar => FieldTy::Bls12381Scalar,
+            ty::FieldTy::Bn254Base => FieldTy::Bn254Base,
+            ty::FieldTy::Bn254Scalar => FieldTy::Bn254Scalar,
+            ty::FieldTy::Secp256k1Base => FieldTy::Secp256k1Base,
+            ty::FieldTy::Secp256k1Scalar => FieldTy::Secp256k1Scalar,
             ty::FieldTy::Curve25519Base => FieldTy::Curve25519Base,
             ty::FieldTy::Curve25519Scalar => FieldTy::Curve25519Scalar,
             ty::FieldTy::PallasBase => FieldTy::PallasBase,
             ty::FieldTy::PallasScalar => FieldTy::PallasScalar,
+            ty::FieldTy::Bls12381Gt => FieldTy::Bls12381Gt,
         }
     }
 }
@@ -1039,9 +1045,12 @@ impl<'tcx> Stable<'tcx> for ty::CurveTy {
     fn stable(&self, _: &mut Tables<'tcx>) -> Self::T {
         match self {
             ty::CurveTy::Bls12381 => CurveTy::Bls12381,
+            ty::CurveTy::Bn254 => CurveTy::Bn254,
+            ty::CurveTy::Secp256k1 => CurveTy::Secp256k1,
             ty::CurveTy::Curve25519 => CurveTy::Curve25519,
             ty::CurveTy::Pallas => CurveTy::Pallas,
             ty::CurveTy::Vesta => CurveTy::Vesta,
+            ty::CurveTy::Bls12381G2 => CurveTy::Bls12381G2,
         }
     }
 }
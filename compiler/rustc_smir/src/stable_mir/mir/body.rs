@@ -464,6 +464,7 @@ pub enum CastKind {
     IntToFloat,
     PtrToPtr,
     FnPtrToPtr,
+    IntToField,
     Transmute,
 }
 
@@ -87,18 +87,26 @@ pub enum FloatTy {
 pub enum FieldTy {
     Bls12381Base,
     Bls12381Scalar,
+    Bn254Base,
+    Bn254Scalar,
+    Secp256k1Base,
+    Secp256k1Scalar,
     Curve25519Base,
     Curve25519Scalar,
     PallasBase,
     PallasScalar,
+    Bls12381Gt,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CurveTy {
     Bls12381,
+    Bn254,
+    Secp256k1,
     Curve25519,
     Pallas,
     Vesta,
+    Bls12381G2,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
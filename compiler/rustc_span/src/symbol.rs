@@ -315,15 +315,23 @@
         __awaitee,
         __try_var,
         __zkllvm_curve_bls12381,
+        __zkllvm_curve_bls12381_g2,
+        __zkllvm_curve_bn254,
         __zkllvm_curve_curve25519,
         __zkllvm_curve_pallas,
+        __zkllvm_curve_secp256k1,
         __zkllvm_curve_vesta,
         __zkllvm_field_bls12381_base,
+        __zkllvm_field_bls12381_gt,
         __zkllvm_field_bls12381_scalar,
+        __zkllvm_field_bn254_base,
+        __zkllvm_field_bn254_scalar,
         __zkllvm_field_curve25519_base,
         __zkllvm_field_curve25519_scalar,
         __zkllvm_field_pallas_base,
         __zkllvm_field_pallas_scalar,
+        __zkllvm_field_secp256k1_base,
+        __zkllvm_field_secp256k1_scalar,
         _d,
         _e,
         _task_context,
@@ -1164,6 +1172,7 @@
         primitive,
         print_macro,
         println_macro,
+        private_input,
         proc_dash_macro: "proc-macro",
         proc_macro,
         proc_macro_attribute,
@@ -1195,6 +1204,7 @@
         pub_macro_rules,
         pub_restricted,
         public,
+        public_input,
         pure,
         pushpop_unsafe,
         qreg,
@@ -1478,6 +1488,16 @@
         slice_patterns,
         slicing_syntax,
         soft,
+        soft_curve_add,
+        soft_curve_div,
+        soft_curve_mul,
+        soft_curve_neg,
+        soft_curve_sub,
+        soft_field_add,
+        soft_field_div,
+        soft_field_mul,
+        soft_field_neg,
+        soft_field_sub,
         specialization,
         speed,
         spotlight,
@@ -1714,6 +1734,21 @@
         yeet_desugar_details,
         yeet_expr,
         ymm_reg,
+        zk_bls12_optimal_ate_pairing,
+        zk_curve25519_decompress,
+        zk_ecdsa_verify,
+        zk_exit_check,
+        zk_gt_multiplication,
+        zk_hash_to_curve,
+        zk_is_in_g1_check,
+        zk_is_in_g2_check,
+        zk_is_on_curve,
+        zk_msm,
+        zk_poseidon,
+        zk_sha2_256,
+        zk_sha2_256_bls12381,
+        zk_sha2_256_compress,
+        zk_sha2_512,
         zmm_reg,
     }
 }
@@ -65,13 +65,17 @@ mod attr_impl {
     // The subset of llvm::Attribute needed for arguments, packed into a bitfield.
     bitflags::bitflags! {
         #[derive(Default, HashStable_Generic)]
-        pub struct ArgAttribute: u8 {
+        pub struct ArgAttribute: u16 {
             const NoAlias   = 1 << 1;
             const NoCapture = 1 << 2;
             const NonNull   = 1 << 3;
             const ReadOnly  = 1 << 4;
             const InReg     = 1 << 5;
             const NoUndef = 1 << 6;
+            /// `#[public_input]` parameter of a `#[circuit]` function.
+            const PublicInput = 1 << 7;
+            /// `#[private_input]` parameter of a `#[circuit]` function.
+            const PrivateInput = 1 << 8;
         }
     }
 }
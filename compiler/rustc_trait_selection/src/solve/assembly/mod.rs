@@ -526,13 +526,23 @@ fn assemble_non_blanket_impl_candidates<G: GoalKind<'tcx>>(
                 let (
                     Bls12381Base
                     | Bls12381Scalar
+                    | Bn254Base
+                    | Bn254Scalar
+                    | Secp256k1Base
+                    | Secp256k1Scalar
                     | Curve25519Base
                     | Curve25519Scalar
                     | PallasBase
-                    | PallasScalar): ty::FieldTy;
+                    | PallasScalar
+                    | Bls12381Gt): ty::FieldTy;
+                // Field literals never have an extension field type.
                 let possible_fields = [
                     SimplifiedType::Field(Bls12381Base),
                     SimplifiedType::Field(Bls12381Scalar),
+                    SimplifiedType::Field(Bn254Base),
+                    SimplifiedType::Field(Bn254Scalar),
+                    SimplifiedType::Field(Secp256k1Base),
+                    SimplifiedType::Field(Secp256k1Scalar),
                     SimplifiedType::Field(Curve25519Base),
                     SimplifiedType::Field(Curve25519Scalar),
                     SimplifiedType::Field(PallasBase),
@@ -1,7 +1,7 @@
 use rustc_hir as hir;
 use rustc_hir::lang_items::LangItem;
 use rustc_middle::query::Providers;
-use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::middle::codegen_fn_attrs::{CircuitInput, CodegenFnAttrFlags};
 use rustc_middle::ty::layout::{
     fn_can_unwind, FnAbiError, HasParamEnv, HasTyCtxt, LayoutCx, LayoutOf, TyAndLayout,
 };
@@ -549,11 +549,9 @@ fn fn_abi_adjust_for_abi<'tcx>(
             }
         };
 
-        let fn_is_circuit = fn_def_id
-            .map(|def_id| {
-                cx.tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::CIRCUIT)
-            })
-            .unwrap_or(false);
+        let circuit_attrs = fn_def_id
+            .map(|def_id| cx.tcx.codegen_fn_attrs(def_id))
+            .filter(|attrs| attrs.flags.contains(CodegenFnAttrFlags::CIRCUIT));
 
         let circuit_args_byval = |arg: &mut ArgAbi<'tcx, Ty<'tcx>>| {
             if arg.is_indirect() {
@@ -561,11 +559,37 @@ fn fn_abi_adjust_for_abi<'tcx>(
             }
         };
 
+        // Tells the assigner whether the argument belongs to the statement or the witness.
+        let circuit_input_attr = |arg: &mut ArgAbi<'tcx, Ty<'tcx>>, input: CircuitInput| {
+            let attr = match input {
+                CircuitInput::Public => ArgAttribute::PublicInput,
+                CircuitInput::Private => ArgAttribute::PrivateInput,
+            };
+            match &mut arg.mode {
+                PassMode::Ignore => {}
+                PassMode::Direct(attrs) | PassMode::Indirect { attrs, .. } => {
+                    attrs.set(attr);
+                }
+                PassMode::Pair(a, b) => {
+                    a.set(attr);
+                    b.set(attr);
+                }
+                PassMode::Cast(cast, _) => {
+                    cast.attrs.set(attr);
+                }
+            }
+        };
+
         fixup(&mut fn_abi.ret, None);
         for (arg_idx, arg) in fn_abi.args.iter_mut().enumerate() {
             fixup(arg, Some(arg_idx));
-            if fn_is_circuit && cx.tcx.sess.target.is_like_assigner {
-                circuit_args_byval(arg);                
+            if let Some(circuit_attrs) = circuit_attrs
+                && cx.tcx.sess.target.is_like_assigner
+            {
+                circuit_args_byval(arg);
+                if let Some(&Some(input)) = circuit_attrs.circuit_inputs.get(arg_idx) {
+                    circuit_input_attr(arg, input);
+                }
             }
         }
     } else {
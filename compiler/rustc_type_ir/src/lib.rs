@@ -541,10 +541,15 @@ pub fn to_signed(self) -> IntTy {
 pub enum FieldTy {
     Bls12381Base,
     Bls12381Scalar,
+    Bn254Base,
+    Bn254Scalar,
+    Secp256k1Base,
+    Secp256k1Scalar,
     Curve25519Base,
     Curve25519Scalar,
     PallasBase,
     PallasScalar,
+    Bls12381Gt,
 }
 
 impl FieldTy {
@@ -552,10 +557,15 @@ pub fn name_str(self) -> &'static str {
         match self {
             FieldTy::Bls12381Base => "__zkllvm_field_bls12381_base",
             FieldTy::Bls12381Scalar => "__zkllvm_field_bls12381_scalar",
+            FieldTy::Bn254Base => "__zkllvm_field_bn254_base",
+            FieldTy::Bn254Scalar => "__zkllvm_field_bn254_scalar",
+            FieldTy::Secp256k1Base => "__zkllvm_field_secp256k1_base",
+            FieldTy::Secp256k1Scalar => "__zkllvm_field_secp256k1_scalar",
             FieldTy::Curve25519Base => "__zkllvm_field_curve25519_base",
             FieldTy::Curve25519Scalar => "__zkllvm_field_curve25519_scalar",
             FieldTy::PallasBase => "__zkllvm_field_pallas_base",
             FieldTy::PallasScalar => "__zkllvm_field_pallas_scalar",
+            FieldTy::Bls12381Gt => "__zkllvm_field_bls12381_gt",
         }
     }
 
@@ -563,10 +573,15 @@ pub fn bit_width(self) -> u64 {
         match self {
             FieldTy::Bls12381Base => 384, // actual is 381
             FieldTy::Bls12381Scalar => 256, // actual is 255
+            FieldTy::Bn254Base => 256, // actual is 254
+            FieldTy::Bn254Scalar => 256, // actual is 254
+            FieldTy::Secp256k1Base => 256,
+            FieldTy::Secp256k1Scalar => 256,
             FieldTy::Curve25519Base => 256, // actual is 255
             FieldTy::Curve25519Scalar => 256, // actual is 253
             FieldTy::PallasBase => 256, // actual is 255
             FieldTy::PallasScalar => 256, // actual is 255
+            FieldTy::Bls12381Gt => 4608, // actual is 4572
         }
     }
 
@@ -574,10 +589,27 @@ pub fn modulus_as_hex(self) -> &'static str {
         match self {
             FieldTy::Bls12381Base => "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
             FieldTy::Bls12381Scalar => "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+            FieldTy::Bn254Base => "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+            FieldTy::Bn254Scalar => "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            FieldTy::Secp256k1Base => "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            FieldTy::Secp256k1Scalar => "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
             FieldTy::Curve25519Base => "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
             FieldTy::Curve25519Scalar => "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed",
             FieldTy::PallasBase => "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
             FieldTy::PallasScalar => "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001",
+            // Coefficients of extension field elements are reduced by the prime subfield modulus.
+            FieldTy::Bls12381Gt => FieldTy::Bls12381Base.modulus_as_hex(),
+        }
+    }
+
+    /// Returns the degree of the field over its prime subfield.
+    ///
+    /// Extension fields have no built-in arithmetic and no literals, their elements are
+    /// stored as `degree` consecutive coefficients from the prime subfield.
+    pub fn degree(self) -> u64 {
+        match self {
+            FieldTy::Bls12381Gt => 12,
+            _ => 1,
         }
     }
 }
@@ -586,27 +618,75 @@ pub fn modulus_as_hex(self) -> &'static str {
 #[derive(Encodable, Decodable, HashStable_Generic)]
 pub enum CurveTy {
     Bls12381,
+    Bn254,
+    Secp256k1,
     Curve25519,
     Pallas,
     Vesta,
+    Bls12381G2,
 }
 
 impl CurveTy {
     pub fn name_str(self) -> &'static str {
         match self {
             CurveTy::Bls12381 => "__zkllvm_curve_bls12381",
+            CurveTy::Bn254 => "__zkllvm_curve_bn254",
+            CurveTy::Secp256k1 => "__zkllvm_curve_secp256k1",
             CurveTy::Curve25519 => "__zkllvm_curve_curve25519",
             CurveTy::Pallas => "__zkllvm_curve_pallas",
             CurveTy::Vesta => "__zkllvm_curve_vesta",
+            CurveTy::Bls12381G2 => "__zkllvm_curve_bls12381_g2",
         }
     }
 
     pub fn bit_width(self) -> u64 {
         match self {
             CurveTy::Bls12381 => 640, // actual is 636
+            CurveTy::Bn254 => 512, // actual is 508
+            CurveTy::Secp256k1 => 512,
             CurveTy::Curve25519 => 512, // actual is 508
             CurveTy::Pallas => 512, // actual is 510
             CurveTy::Vesta => 512, // actual is 510
+            CurveTy::Bls12381G2 => 1536, // actual is 1524
+        }
+    }
+
+    /// Returns the field over which the curve is defined, i.e. the field of point coordinates.
+    ///
+    /// For curves over an extension field this is the prime subfield, see [`CurveTy::degree`].
+    pub fn base_field(self) -> FieldTy {
+        match self {
+            CurveTy::Bls12381 => FieldTy::Bls12381Base,
+            CurveTy::Bn254 => FieldTy::Bn254Base,
+            CurveTy::Secp256k1 => FieldTy::Secp256k1Base,
+            CurveTy::Curve25519 => FieldTy::Curve25519Base,
+            CurveTy::Pallas => FieldTy::PallasBase,
+            CurveTy::Vesta => FieldTy::PallasScalar,
+            CurveTy::Bls12381G2 => FieldTy::Bls12381Base,
+        }
+    }
+
+    /// Returns the degree of the field of point coordinates over [`CurveTy::base_field`].
+    ///
+    /// Curves over extension fields have no built-in arithmetic, each of their coordinates
+    /// is stored as `degree` consecutive coefficients from the base field.
+    pub fn degree(self) -> u64 {
+        match self {
+            CurveTy::Bls12381G2 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the scalar field of the curve group, i.e. the field of point multipliers.
+    pub fn scalar_field(self) -> FieldTy {
+        match self {
+            CurveTy::Bls12381 => FieldTy::Bls12381Scalar,
+            CurveTy::Bn254 => FieldTy::Bn254Scalar,
+            CurveTy::Secp256k1 => FieldTy::Secp256k1Scalar,
+            CurveTy::Curve25519 => FieldTy::Curve25519Scalar,
+            CurveTy::Pallas => FieldTy::PallasScalar,
+            CurveTy::Vesta => FieldTy::PallasBase,
+            CurveTy::Bls12381G2 => FieldTy::Bls12381Scalar,
         }
     }
 }
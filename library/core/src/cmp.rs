@@ -1302,17 +1302,43 @@ fn ne(&self, _other: &()) -> bool {
     #[cfg(not(bootstrap))]
     partial_eq_impl! {
         __zkllvm_curve_bls12381
+        __zkllvm_curve_bn254
+        __zkllvm_curve_secp256k1
         __zkllvm_curve_curve25519
         __zkllvm_curve_pallas
         __zkllvm_curve_vesta
         __zkllvm_field_bls12381_base
         __zkllvm_field_bls12381_scalar
+        __zkllvm_field_bn254_base
+        __zkllvm_field_bn254_scalar
+        __zkllvm_field_secp256k1_base
+        __zkllvm_field_secp256k1_scalar
         __zkllvm_field_curve25519_base
         __zkllvm_field_curve25519_scalar
         __zkllvm_field_pallas_base
         __zkllvm_field_pallas_scalar
     }
 
+    // Extension types have no built-in comparison, so compare their coefficients.
+
+    #[cfg(not(bootstrap))]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    impl PartialEq for __zkllvm_curve_bls12381_g2 {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.to_affine() == other.to_affine()
+        }
+    }
+
+    #[cfg(not(bootstrap))]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    impl PartialEq for __zkllvm_field_bls12381_gt {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.coefficients() == other.coefficients()
+        }
+    }
+
     macro_rules! eq_impl {
         ($($t:ty)*) => ($(
             #[stable(feature = "rust1", since = "1.0.0")]
@@ -1325,11 +1351,19 @@ impl Eq for $t {}
     #[cfg(not(bootstrap))]
     eq_impl! {
         __zkllvm_curve_bls12381
+        __zkllvm_curve_bls12381_g2
+        __zkllvm_curve_bn254
+        __zkllvm_curve_secp256k1
         __zkllvm_curve_curve25519
         __zkllvm_curve_pallas
         __zkllvm_curve_vesta
         __zkllvm_field_bls12381_base
+        __zkllvm_field_bls12381_gt
         __zkllvm_field_bls12381_scalar
+        __zkllvm_field_bn254_base
+        __zkllvm_field_bn254_scalar
+        __zkllvm_field_secp256k1_base
+        __zkllvm_field_secp256k1_scalar
         __zkllvm_field_curve25519_base
         __zkllvm_field_curve25519_scalar
         __zkllvm_field_pallas_base
@@ -1458,6 +1492,10 @@ fn clamp(self, min: bool, max: bool) -> bool {
     ord_impl! {
         __zkllvm_field_bls12381_base
         __zkllvm_field_bls12381_scalar
+        __zkllvm_field_bn254_base
+        __zkllvm_field_bn254_scalar
+        __zkllvm_field_secp256k1_base
+        __zkllvm_field_secp256k1_scalar
         __zkllvm_field_curve25519_base
         __zkllvm_field_curve25519_scalar
         __zkllvm_field_pallas_base
@@ -207,6 +207,45 @@ fn from(small: bool) -> Self {
     }
 }
 
+// Unsigned <-> Field
+// Every field modulus is wider than 128 bits, so integers always fit into fields.
+// The other way round only elements smaller than 2^128 can be converted, anything
+// else is an error rather than being truncated.
+#[cfg(not(bootstrap))]
+macro_rules! impl_field_conv {
+    ($($Field:ty)*) => {$(
+        impl_from! { u64, $Field, #[stable(feature = "rust1", since = "1.0.0")] }
+        impl_from! { u128, $Field, #[stable(feature = "rust1", since = "1.0.0")] }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl TryFrom<$Field> for u128 {
+            type Error = TryFromIntError;
+
+            /// Try to create an integer from a field element. This returns
+            /// an error if the element is outside of the range of `u128`.
+            #[inline]
+            fn try_from(value: $Field) -> Result<Self, Self::Error> {
+                let low = value.truncate_to_u128();
+                if low as $Field == value { Ok(low) } else { Err(TryFromIntError(())) }
+            }
+        }
+    )*}
+}
+
+#[cfg(not(bootstrap))]
+impl_field_conv! {
+    __zkllvm_field_bls12381_base
+    __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
+    __zkllvm_field_curve25519_base
+    __zkllvm_field_curve25519_scalar
+    __zkllvm_field_pallas_base
+    __zkllvm_field_pallas_scalar
+}
+
 // no possible bounds violation
 macro_rules! try_from_unbounded {
     ($source:ty, $($target:ty),*) => {$(
@@ -180,6 +180,14 @@ fn default() -> $t {
 #[cfg(not(bootstrap))]
 default_impl! { __zkllvm_field_bls12381_scalar, 0g, "Returns the default value of `0`" }
 #[cfg(not(bootstrap))]
+default_impl! { __zkllvm_field_bn254_base, 0g, "Returns the default value of `0`" }
+#[cfg(not(bootstrap))]
+default_impl! { __zkllvm_field_bn254_scalar, 0g, "Returns the default value of `0`" }
+#[cfg(not(bootstrap))]
+default_impl! { __zkllvm_field_secp256k1_base, 0g, "Returns the default value of `0`" }
+#[cfg(not(bootstrap))]
+default_impl! { __zkllvm_field_secp256k1_scalar, 0g, "Returns the default value of `0`" }
+#[cfg(not(bootstrap))]
 default_impl! { __zkllvm_field_curve25519_base, 0g, "Returns the default value of `0`" }
 #[cfg(not(bootstrap))]
 default_impl! { __zkllvm_field_curve25519_scalar, 0g, "Returns the default value of `0`" }
@@ -205,6 +213,9 @@ fn default() -> $t {
 #[cfg(not(bootstrap))]
 curve_default_impl! {
     __zkllvm_curve_bls12381
+    __zkllvm_curve_bls12381_g2
+    __zkllvm_curve_bn254
+    __zkllvm_curve_secp256k1
     __zkllvm_curve_curve25519
     __zkllvm_curve_pallas
     __zkllvm_curve_vesta
@@ -7,6 +7,8 @@
 use crate::ptr;
 use crate::slice;
 use crate::str;
+#[cfg(all(not(bootstrap), not(target_arch = "assigner")))]
+use crate::zk::repr::{CurveRepr, FieldRepr};
 
 #[doc(hidden)]
 trait DisplayInt:
@@ -203,33 +205,6 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
   u8 u16 u32 u64 u128 usize
 }
 
-#[cfg(not(bootstrap))]
-macro_rules! debug_unimplemented {
-    ($($T:ident)*) => {$(
-        #[stable(feature = "rust1", since = "1.0.0")]
-        impl fmt::Debug for $T {
-            #[inline]
-            fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                unimplemented!()
-            }
-        }
-    )*};
-}
-
-#[cfg(not(bootstrap))]
-debug_unimplemented! {
-    __zkllvm_curve_bls12381
-    __zkllvm_curve_curve25519
-    __zkllvm_curve_pallas
-    __zkllvm_curve_vesta
-    __zkllvm_field_bls12381_base
-    __zkllvm_field_bls12381_scalar
-    __zkllvm_field_curve25519_base
-    __zkllvm_field_curve25519_scalar
-    __zkllvm_field_pallas_base
-    __zkllvm_field_pallas_scalar
-}
-
 // 2 digit decimal look up table
 static DEC_DIGITS_LUT: &[u8; 200] = b"0001020304050607080910111213141516171819\
       2021222324252627282930313233343536373839\
@@ -714,28 +689,223 @@ fn u128_mulhi(x: u128, y: u128) -> u128 {
     x_hi as u128 * y_hi as u128 + high1 + high2
 }
 
+/// Radix a field element is printed in.
+#[cfg(not(bootstrap))]
+#[derive(Clone, Copy)]
+enum FieldRadix {
+    Decimal,
+    LowerHex,
+    UpperHex,
+}
+
+/// Formats the canonical value of a field element.
+#[cfg(all(not(bootstrap), not(target_arch = "assigner")))]
+fn fmt_field<F: FieldRepr>(value: F, radix: FieldRadix, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // 48 bytes, the largest field size, hold at most 116 decimal or 96 hex digits.
+    let mut bytes = [0u8; 48];
+    let bytes = &mut bytes[..F::SIZE];
+    value.write_be_bytes(bytes);
+    let mut buf = [b'0'; 116];
+    let mut curr = buf.len();
+    match radix {
+        FieldRadix::Decimal => {
+            // Most significant limb first.
+            let mut limbs = [0u64; 6];
+            for (limb, chunk) in limbs[6 - F::SIZE / 8..].iter_mut().zip(bytes.chunks_exact(8)) {
+                *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+            }
+            loop {
+                // Divide by `10^19`, the largest power of ten fitting into `u64`.
+                const DIVISOR: u128 = 10_000_000_000_000_000_000;
+                let mut rem = 0;
+                for limb in limbs.iter_mut() {
+                    let n = (rem << 64) | *limb as u128;
+                    *limb = (n / DIVISOR) as u64;
+                    rem = n % DIVISOR;
+                }
+                let is_last = limbs.iter().all(|&limb| limb == 0);
+                // All chunks but the leading one are padded with zeros to 19 digits.
+                for _ in 0..19 {
+                    curr -= 1;
+                    buf[curr] = b'0' + (rem % 10) as u8;
+                    rem /= 10;
+                    if is_last && rem == 0 {
+                        break;
+                    }
+                }
+                if is_last {
+                    break;
+                }
+            }
+        }
+        FieldRadix::LowerHex | FieldRadix::UpperHex => {
+            let digits = match radix {
+                FieldRadix::UpperHex => b"0123456789ABCDEF",
+                _ => b"0123456789abcdef",
+            };
+            for &byte in bytes.iter().rev() {
+                curr -= 2;
+                buf[curr] = digits[(byte >> 4) as usize];
+                buf[curr + 1] = digits[(byte & 0xf) as usize];
+            }
+            // Strip leading zeros, but keep at least one digit.
+            while curr < buf.len() - 1 && buf[curr] == b'0' {
+                curr += 1;
+            }
+        }
+    }
+    // SAFETY: only ASCII digits are written to `buf`.
+    let digits = unsafe { str::from_utf8_unchecked(&buf[curr..]) };
+    let prefix = if let FieldRadix::Decimal = radix { "" } else { "0x" };
+    f.pad_integral(true, prefix, digits)
+}
+
+/// Formats a curve point as its coordinates `(x, y)`, each formatted with `fmt_coordinate`.
+///
+/// The neutral element of Weierstrass curves has no affine coordinates and is printed
+/// as `(0, 1)`.
+#[cfg(all(not(bootstrap), not(target_arch = "assigner")))]
+fn fmt_curve<C: CurveRepr>(
+    point: C,
+    f: &mut fmt::Formatter<'_>,
+    fmt_coordinate: fn(&C::Base, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let (x, y) = point.coordinates();
+    f.write_str("(")?;
+    fmt_coordinate(&x, f)?;
+    f.write_str(", ")?;
+    fmt_coordinate(&y, f)?;
+    f.write_str(")")
+}
+
+// The assigner cannot print values from a circuit, so formatting does nothing there.
+
+#[cfg(all(not(bootstrap), target_arch = "assigner"))]
+fn fmt_field<F>(_value: F, _radix: FieldRadix, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    Ok(())
+}
+
+#[cfg(all(not(bootstrap), target_arch = "assigner"))]
+fn fmt_curve<C, B>(
+    _point: C,
+    _f: &mut fmt::Formatter<'_>,
+    _fmt_coordinate: fn(&B, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    Ok(())
+}
+
 #[cfg(not(bootstrap))]
-macro_rules! display_unimplemented {
+macro_rules! impl_field_fmt {
     ($($T:ident)*) => {$(
         #[stable(feature = "rust1", since = "1.0.0")]
         impl fmt::Display for $T {
-            fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                unimplemented!()
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_field(*self, FieldRadix::Decimal, f)
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl fmt::LowerHex for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_field(*self, FieldRadix::LowerHex, f)
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl fmt::UpperHex for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_field(*self, FieldRadix::UpperHex, f)
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl fmt::Debug for $T {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.debug_lower_hex() {
+                    fmt::LowerHex::fmt(self, f)
+                } else if f.debug_upper_hex() {
+                    fmt::UpperHex::fmt(self, f)
+                } else {
+                    fmt::Display::fmt(self, f)
+                }
             }
         }
     )*};
 }
 
 #[cfg(not(bootstrap))]
-display_unimplemented! {
-    __zkllvm_curve_bls12381
-    __zkllvm_curve_curve25519
-    __zkllvm_curve_pallas
-    __zkllvm_curve_vesta
+impl_field_fmt! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
+
+#[cfg(not(bootstrap))]
+macro_rules! impl_curve_fmt {
+    ($($T:ident, $Base:ident;)*) => {$(
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl fmt::Display for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_curve(*self, f, <$Base as fmt::Display>::fmt)
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl fmt::Debug for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_curve(*self, f, <$Base as fmt::Debug>::fmt)
+            }
+        }
+    )*};
+}
+
+#[cfg(not(bootstrap))]
+impl_curve_fmt! {
+    __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base;
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_base;
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_base;
+    __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base;
+    __zkllvm_curve_pallas, __zkllvm_field_pallas_base;
+    __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar;
+}
+
+/// Formats the base field coefficients of an element of an extension type.
+#[cfg(all(not(bootstrap), not(target_arch = "assigner")))]
+fn fmt_coefficients(
+    coefficients: &[__zkllvm_field_bls12381_base],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    fmt::Debug::fmt(coefficients, f)
+}
+
+#[cfg(all(not(bootstrap), target_arch = "assigner"))]
+fn fmt_coefficients(
+    _coefficients: &[__zkllvm_field_bls12381_base],
+    _f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    Ok(())
+}
+
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl fmt::Debug for __zkllvm_curve_bls12381_g2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_coefficients(&crate::intrinsics::assigner_g2_coordinates(*self), f)
+    }
+}
+
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl fmt::Debug for __zkllvm_field_bls12381_gt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_coefficients(&self.coefficients(), f)
+    }
+}
@@ -2502,30 +2502,203 @@ pub fn const_eval_select<ARG: Tuple, F, G, RET>(
 #[cfg(not(bootstrap))]
 extern "rust-intrinsic" {
     /// Initialize Bls12381 element with two base field elements.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
     pub fn assigner_curve_init_bls12381(
         x: __zkllvm_field_bls12381_base,
         y: __zkllvm_field_bls12381_base,
     ) -> __zkllvm_curve_bls12381;
 
+    /// Initialize Bn254 element with two base field elements.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_curve_init_bn254(
+        x: __zkllvm_field_bn254_base,
+        y: __zkllvm_field_bn254_base,
+    ) -> __zkllvm_curve_bn254;
+
+    /// Initialize Secp256k1 element with two base field elements.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_curve_init_secp256k1(
+        x: __zkllvm_field_secp256k1_base,
+        y: __zkllvm_field_secp256k1_base,
+    ) -> __zkllvm_curve_secp256k1;
+
     /// Initialize Curve25519 element with two base field elements.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
     pub fn assigner_curve_init_curve25519(
         x: __zkllvm_field_curve25519_base,
         y: __zkllvm_field_curve25519_base,
     ) -> __zkllvm_curve_curve25519;
 
     /// Initialize Pallas element with two base field elements.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
     pub fn assigner_curve_init_pallas(
         x: __zkllvm_field_pallas_base,
         y: __zkllvm_field_pallas_base,
     ) -> __zkllvm_curve_pallas;
 
     /// Initialize Vesta element with two base field elements.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
     pub fn assigner_curve_init_vesta(
         x: __zkllvm_field_pallas_scalar,
         y: __zkllvm_field_pallas_scalar,
     ) -> __zkllvm_curve_vesta;
 }
 
+#[cfg(not(bootstrap))]
+extern "rust-intrinsic" {
+    /// Get `x` coordinate of Bls12381 element.
+    pub fn assigner_curve_x_bls12381(
+        point: __zkllvm_curve_bls12381,
+    ) -> __zkllvm_field_bls12381_base;
+
+    /// Get `y` coordinate of Bls12381 element.
+    pub fn assigner_curve_y_bls12381(
+        point: __zkllvm_curve_bls12381,
+    ) -> __zkllvm_field_bls12381_base;
+
+    /// Get `x` coordinate of Bn254 element.
+    pub fn assigner_curve_x_bn254(point: __zkllvm_curve_bn254) -> __zkllvm_field_bn254_base;
+
+    /// Get `y` coordinate of Bn254 element.
+    pub fn assigner_curve_y_bn254(point: __zkllvm_curve_bn254) -> __zkllvm_field_bn254_base;
+
+    /// Get `x` coordinate of Secp256k1 element.
+    pub fn assigner_curve_x_secp256k1(
+        point: __zkllvm_curve_secp256k1,
+    ) -> __zkllvm_field_secp256k1_base;
+
+    /// Get `y` coordinate of Secp256k1 element.
+    pub fn assigner_curve_y_secp256k1(
+        point: __zkllvm_curve_secp256k1,
+    ) -> __zkllvm_field_secp256k1_base;
+
+    /// Get `x` coordinate of Curve25519 element.
+    pub fn assigner_curve_x_curve25519(
+        point: __zkllvm_curve_curve25519,
+    ) -> __zkllvm_field_curve25519_base;
+
+    /// Get `y` coordinate of Curve25519 element.
+    pub fn assigner_curve_y_curve25519(
+        point: __zkllvm_curve_curve25519,
+    ) -> __zkllvm_field_curve25519_base;
+
+    /// Get `x` coordinate of Pallas element.
+    pub fn assigner_curve_x_pallas(point: __zkllvm_curve_pallas) -> __zkllvm_field_pallas_base;
+
+    /// Get `y` coordinate of Pallas element.
+    pub fn assigner_curve_y_pallas(point: __zkllvm_curve_pallas) -> __zkllvm_field_pallas_base;
+
+    /// Get `x` coordinate of Vesta element.
+    pub fn assigner_curve_x_vesta(point: __zkllvm_curve_vesta) -> __zkllvm_field_pallas_scalar;
+
+    /// Get `y` coordinate of Vesta element.
+    pub fn assigner_curve_y_vesta(point: __zkllvm_curve_vesta) -> __zkllvm_field_pallas_scalar;
+}
+
+#[cfg(not(bootstrap))]
+extern "rust-intrinsic" {
+    /// Returns the lowest 128 bits of the canonical value of `x`.
+    ///
+    /// `F` must be a prime field type.
+    pub fn assigner_field_to_int<F>(x: F) -> u128;
+}
+
+#[cfg(not(bootstrap))]
+extern "rust-intrinsic" {
+    /// Check that two base field elements are coordinates of a Bls12381 element.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_is_on_curve_bls12381(
+        x: __zkllvm_field_bls12381_base,
+        y: __zkllvm_field_bls12381_base,
+    ) -> bool;
+
+    /// Check that two base field elements are coordinates of a Bn254 element.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_is_on_curve_bn254(
+        x: __zkllvm_field_bn254_base,
+        y: __zkllvm_field_bn254_base,
+    ) -> bool;
+
+    /// Check that two base field elements are coordinates of a Secp256k1 element.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_is_on_curve_secp256k1(
+        x: __zkllvm_field_secp256k1_base,
+        y: __zkllvm_field_secp256k1_base,
+    ) -> bool;
+
+    /// Check that two base field elements are coordinates of a Curve25519 element.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_is_on_curve_curve25519(
+        x: __zkllvm_field_curve25519_base,
+        y: __zkllvm_field_curve25519_base,
+    ) -> bool;
+
+    /// Check that two base field elements are coordinates of a Pallas element.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_is_on_curve_pallas(
+        x: __zkllvm_field_pallas_base,
+        y: __zkllvm_field_pallas_base,
+    ) -> bool;
+
+    /// Check that two base field elements are coordinates of a Vesta element.
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub fn assigner_is_on_curve_vesta(
+        x: __zkllvm_field_pallas_scalar,
+        y: __zkllvm_field_pallas_scalar,
+    ) -> bool;
+}
+
+#[cfg(not(bootstrap))]
+extern "rust-intrinsic" {
+    /// Multi-scalar multiplication of `len` Bls12381 points by `len` scalars,
+    /// `points[0] * scalars[0] + ... + points[len - 1] * scalars[len - 1]`.
+    pub fn assigner_msm_bls12381(
+        points: *const __zkllvm_curve_bls12381,
+        scalars: *const __zkllvm_field_bls12381_scalar,
+        len: usize,
+    ) -> __zkllvm_curve_bls12381;
+
+    /// Multi-scalar multiplication of `len` Bn254 points by `len` scalars,
+    /// `points[0] * scalars[0] + ... + points[len - 1] * scalars[len - 1]`.
+    pub fn assigner_msm_bn254(
+        points: *const __zkllvm_curve_bn254,
+        scalars: *const __zkllvm_field_bn254_scalar,
+        len: usize,
+    ) -> __zkllvm_curve_bn254;
+
+    /// Multi-scalar multiplication of `len` Secp256k1 points by `len` scalars,
+    /// `points[0] * scalars[0] + ... + points[len - 1] * scalars[len - 1]`.
+    pub fn assigner_msm_secp256k1(
+        points: *const __zkllvm_curve_secp256k1,
+        scalars: *const __zkllvm_field_secp256k1_scalar,
+        len: usize,
+    ) -> __zkllvm_curve_secp256k1;
+
+    /// Multi-scalar multiplication of `len` Curve25519 points by `len` scalars,
+    /// `points[0] * scalars[0] + ... + points[len - 1] * scalars[len - 1]`.
+    pub fn assigner_msm_curve25519(
+        points: *const __zkllvm_curve_curve25519,
+        scalars: *const __zkllvm_field_curve25519_scalar,
+        len: usize,
+    ) -> __zkllvm_curve_curve25519;
+
+    /// Multi-scalar multiplication of `len` Pallas points by `len` scalars,
+    /// `points[0] * scalars[0] + ... + points[len - 1] * scalars[len - 1]`.
+    pub fn assigner_msm_pallas(
+        points: *const __zkllvm_curve_pallas,
+        scalars: *const __zkllvm_field_pallas_scalar,
+        len: usize,
+    ) -> __zkllvm_curve_pallas;
+
+    /// Multi-scalar multiplication of `len` Vesta points by `len` scalars,
+    /// `points[0] * scalars[0] + ... + points[len - 1] * scalars[len - 1]`.
+    pub fn assigner_msm_vesta(
+        points: *const __zkllvm_curve_vesta,
+        scalars: *const __zkllvm_field_pallas_base,
+        len: usize,
+    ) -> __zkllvm_curve_vesta;
+}
+
 /// Compute SHA2-256 hash.
 #[cfg(not(bootstrap))]
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -2547,6 +2720,100 @@ pub fn assigner_sha2_256(
     unsafe { assigner_sha2_256(x[0], x[1], y[0], y[1]) }
 }
 
+/// SHA2-256 compression function.
+///
+/// Compresses a 512-bit message `block` into the 256-bit hash `state`, as in section
+/// 6.2.2 of FIPS 180-4, and returns the new state. Both are given as 128-bit big-endian
+/// chunks, most significant first, which must be less than `2^128`.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_sha2_256_compress(
+    state: [__zkllvm_field_pallas_base; 2],
+    block: [__zkllvm_field_pallas_base; 4],
+) -> [__zkllvm_field_pallas_base; 2] {
+    extern "rust-intrinsic" {
+        pub fn assigner_sha2_256_compress(
+            s1: __zkllvm_field_pallas_base,
+            s2: __zkllvm_field_pallas_base,
+            b1: __zkllvm_field_pallas_base,
+            b2: __zkllvm_field_pallas_base,
+            b3: __zkllvm_field_pallas_base,
+            b4: __zkllvm_field_pallas_base,
+        ) -> [__zkllvm_field_pallas_base; 2];
+    }
+
+    let [s1, s2] = state;
+    let [b1, b2, b3, b4] = block;
+    unsafe { assigner_sha2_256_compress(s1, s2, b1, b2, b3, b4) }
+}
+
+/// Poseidon sponge over the permutation `permute` of width 3.
+///
+/// The capacity element is initialized with the number of elements, which are then
+/// absorbed two at a time, the last pair padded with zero.
+#[cfg(not(bootstrap))]
+#[inline]
+fn poseidon_sponge<F: crate::ops::Field + From<u64>>(
+    x: &[F],
+    permute: fn([F; 3]) -> [F; 3],
+) -> F {
+    let mut state = [F::from(x.len() as u64), F::ZERO, F::ZERO];
+    for chunk in x.chunks(2) {
+        for (value, input) in state[1..].iter_mut().zip(chunk) {
+            *value += *input;
+        }
+        state = permute(state);
+    }
+    if x.is_empty() {
+        state = permute(state);
+    }
+    state[1]
+}
+
+/// Compute Poseidon hash of Pallas base field elements.
+///
+/// The permutation is computed by the `llvm.assigner.poseidon` gadget of the assigner.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_poseidon_pallas_base(
+    x: &[__zkllvm_field_pallas_base],
+) -> __zkllvm_field_pallas_base {
+    extern "rust-intrinsic" {
+        pub fn assigner_poseidon_pallas_base(
+            x1: __zkllvm_field_pallas_base,
+            x2: __zkllvm_field_pallas_base,
+            x3: __zkllvm_field_pallas_base,
+        ) -> [__zkllvm_field_pallas_base; 3];
+    }
+
+    poseidon_sponge(x, |s| unsafe { assigner_poseidon_pallas_base(s[0], s[1], s[2]) })
+}
+
+/// Compute Poseidon hash of BLS12-381 scalar field elements.
+///
+/// The permutation is computed by the `llvm.assigner.poseidon` gadget of the assigner.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_poseidon_bls12381_scalar(
+    x: &[__zkllvm_field_bls12381_scalar],
+) -> __zkllvm_field_bls12381_scalar {
+    extern "rust-intrinsic" {
+        pub fn assigner_poseidon_bls12381_scalar(
+            x1: __zkllvm_field_bls12381_scalar,
+            x2: __zkllvm_field_bls12381_scalar,
+            x3: __zkllvm_field_bls12381_scalar,
+        ) -> [__zkllvm_field_bls12381_scalar; 3];
+    }
+
+    poseidon_sponge(x, |s| unsafe { assigner_poseidon_bls12381_scalar(s[0], s[1], s[2]) })
+}
+
 /// Compute SHA2-256 hash for BLS12-381 base field.
 #[cfg(not(bootstrap))]
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -2591,24 +2858,41 @@ pub fn assigner_sha2_512(
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_allowed_through_unstable_modules]
 #[inline]
-pub fn assigner_bls12_optimal_ate_pairing(
+pub fn assigner_bls12_pairing(
     x: __zkllvm_curve_bls12381,
-    y: [__zkllvm_field_bls12381_base; 4],
-) -> [__zkllvm_field_bls12381_base; 12] {
+    y: __zkllvm_curve_bls12381_g2,
+) -> __zkllvm_field_bls12381_gt {
     extern "rust-intrinsic" {
         pub fn assigner_bls12_optimal_ate_pairing(
             x: __zkllvm_curve_bls12381,
-            y1: __zkllvm_field_bls12381_base,
-            y2: __zkllvm_field_bls12381_base,
-            y3: __zkllvm_field_bls12381_base,
-            y4: __zkllvm_field_bls12381_base,
-        ) -> [__zkllvm_field_bls12381_base; 12];
+            y: __zkllvm_curve_bls12381_g2,
+        ) -> __zkllvm_field_bls12381_gt;
     }
 
-    unsafe { assigner_bls12_optimal_ate_pairing(x, y[0], y[1], y[2], y[3]) }
+    unsafe { assigner_bls12_optimal_ate_pairing(x, y) }
+}
+
+/// BSL12 optimal ate pairing of g2 coordinates `[x.c0, x.c1, y.c0, y.c1]`, returning the
+/// coefficients of the result.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[deprecated(since = "1.0.0", note = "use `assigner_bls12_pairing` instead")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_bls12_optimal_ate_pairing(
+    x: __zkllvm_curve_bls12381,
+    y: [__zkllvm_field_bls12381_base; 4],
+) -> [__zkllvm_field_bls12381_base; 12] {
+    // SAFETY: the coordinates were never checked by this function.
+    let y = unsafe { assigner_g2_init(y) };
+    assigner_gt_coefficients(assigner_bls12_pairing(x, y))
 }
 
 /// Hash to curve.
+///
+/// Maps `x` to G1 with `map_to_curve_svdw` of RFC 9380, section 6.6.1, with `Z = -3`,
+/// and clears the cofactor. This is `encode_to_curve` of the
+/// `BLS12381G1_XMD:SHA-256_SVDW_NU_` suite, with `x` taking the place of `hash_to_field`.
 #[cfg(not(bootstrap))]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_allowed_through_unstable_modules]
@@ -2639,17 +2923,23 @@ pub fn assigner_is_in_g1_check(x: __zkllvm_curve_bls12381) -> bool {
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_allowed_through_unstable_modules]
 #[inline]
-pub fn assigner_is_in_g2_check(x: [__zkllvm_field_bls12381_base; 4]) -> bool {
+pub fn assigner_is_in_g2(x: __zkllvm_curve_bls12381_g2) -> bool {
     extern "rust-intrinsic" {
-        pub fn assigner_is_in_g2_check(
-            x1: __zkllvm_field_bls12381_base,
-            x2: __zkllvm_field_bls12381_base,
-            x3: __zkllvm_field_bls12381_base,
-            x4: __zkllvm_field_bls12381_base,
-        ) -> bool;
+        pub fn assigner_is_in_g2_check(x: __zkllvm_curve_bls12381_g2) -> bool;
     }
 
-    unsafe { assigner_is_in_g2_check(x[0], x[1], x[2], x[3]) }
+    unsafe { assigner_is_in_g2_check(x) }
+}
+
+/// Check if g2 coordinates `[x.c0, x.c1, y.c0, y.c1]` are in g2.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[deprecated(since = "1.0.0", note = "use `assigner_is_in_g2` instead")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_is_in_g2_check(x: [__zkllvm_field_bls12381_base; 4]) -> bool {
+    // SAFETY: the coordinates are what is being checked.
+    assigner_is_in_g2(unsafe { assigner_g2_init(x) })
 }
 
 /// gT multiplication.
@@ -2657,12 +2947,142 @@ pub fn assigner_is_in_g2_check(
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_allowed_through_unstable_modules]
 #[inline]
+pub fn assigner_gt_mul(
+    x: __zkllvm_field_bls12381_gt,
+    y: __zkllvm_field_bls12381_gt,
+) -> __zkllvm_field_bls12381_gt {
+    extern "rust-intrinsic" {
+        pub fn assigner_gt_multiplication(
+            x: __zkllvm_field_bls12381_gt,
+            y: __zkllvm_field_bls12381_gt,
+        ) -> __zkllvm_field_bls12381_gt;
+    }
+
+    unsafe { assigner_gt_multiplication(x, y) }
+}
+
+/// gT multiplication of coefficients over the base field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[deprecated(since = "1.0.0", note = "use `assigner_gt_mul` instead")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
 pub fn assigner_gt_multiplication(
     x: [__zkllvm_field_bls12381_base; 12],
     y: [__zkllvm_field_bls12381_base; 12],
 ) -> [__zkllvm_field_bls12381_base; 12] {
+    // SAFETY: the coefficients were never checked by this function.
+    let (x, y) = unsafe { (assigner_gt_init(x), assigner_gt_init(y)) };
+    assigner_gt_coefficients(assigner_gt_mul(x, y))
+}
+
+/// Recover the `x` coordinate of a Curve25519 point from its `y` coordinate and the
+/// parity of `x`.
+///
+/// The result is unspecified if there is no such point, so it has to be checked,
+/// for example with `assigner_is_on_curve_curve25519`.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_curve_decompress_curve25519(
+    y: __zkllvm_field_curve25519_base,
+    sign: bool,
+) -> __zkllvm_field_curve25519_base {
     extern "rust-intrinsic" {
-        pub fn assigner_gt_multiplication(
+        pub fn assigner_curve_decompress_curve25519(
+            y: __zkllvm_field_curve25519_base,
+            sign: bool,
+        ) -> __zkllvm_field_curve25519_base;
+    }
+
+    unsafe { assigner_curve_decompress_curve25519(y, sign) }
+}
+
+/// Verify secp256k1 ECDSA signature `(r, s)` of message hash `hash` against public key `key`.
+///
+/// The hash is given already reduced to the scalar field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_ecdsa_verify(
+    key: __zkllvm_curve_secp256k1,
+    hash: __zkllvm_field_secp256k1_scalar,
+    r: __zkllvm_field_secp256k1_scalar,
+    s: __zkllvm_field_secp256k1_scalar,
+) -> bool {
+    extern "rust-intrinsic" {
+        pub fn assigner_ecdsa_verify(
+            key: __zkllvm_curve_secp256k1,
+            hash: __zkllvm_field_secp256k1_scalar,
+            r: __zkllvm_field_secp256k1_scalar,
+            s: __zkllvm_field_secp256k1_scalar,
+        ) -> bool;
+    }
+
+    unsafe { assigner_ecdsa_verify(key, hash, r, s) }
+}
+
+/// Multi-scalar multiplication `points[0] * scalars[0] + ... + points[N - 1] * scalars[N - 1]`.
+///
+/// Generic over the built-in curve types, see [`CurveGroup::msm`](crate::ops::CurveGroup::msm).
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_msm<C: crate::ops::CurveGroup, const N: usize>(
+    points: &[C; N],
+    scalars: &[C::Scalar; N],
+) -> C {
+    C::msm(points, scalars)
+}
+
+/// Create g2 element from its coordinates `[x.c0, x.c1, y.c0, y.c1]`.
+///
+/// The coordinates are not checked.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub unsafe fn assigner_g2_init(x: [__zkllvm_field_bls12381_base; 4]) -> __zkllvm_curve_bls12381_g2 {
+    extern "rust-intrinsic" {
+        pub fn assigner_g2_init(
+            x1: __zkllvm_field_bls12381_base,
+            x2: __zkllvm_field_bls12381_base,
+            x3: __zkllvm_field_bls12381_base,
+            x4: __zkllvm_field_bls12381_base,
+        ) -> __zkllvm_curve_bls12381_g2;
+    }
+
+    unsafe { assigner_g2_init(x[0], x[1], x[2], x[3]) }
+}
+
+/// Get coordinates `[x.c0, x.c1, y.c0, y.c1]` of g2 element.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_g2_coordinates(x: __zkllvm_curve_bls12381_g2) -> [__zkllvm_field_bls12381_base; 4] {
+    extern "rust-intrinsic" {
+        pub fn assigner_g2_coordinates(
+            x: __zkllvm_curve_bls12381_g2,
+        ) -> [__zkllvm_field_bls12381_base; 4];
+    }
+
+    unsafe { assigner_g2_coordinates(x) }
+}
+
+/// Create gT element from its coefficients over the base field.
+///
+/// The coefficients are not checked.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub unsafe fn assigner_gt_init(x: [__zkllvm_field_bls12381_base; 12]) -> __zkllvm_field_bls12381_gt {
+    extern "rust-intrinsic" {
+        pub fn assigner_gt_init(
             x1: __zkllvm_field_bls12381_base,
             x2: __zkllvm_field_bls12381_base,
             x3: __zkllvm_field_bls12381_base,
@@ -2675,25 +3095,27 @@ pub fn assigner_gt_multiplication(
             x10: __zkllvm_field_bls12381_base,
             x11: __zkllvm_field_bls12381_base,
             x12: __zkllvm_field_bls12381_base,
-            y1: __zkllvm_field_bls12381_base,
-            y2: __zkllvm_field_bls12381_base,
-            y3: __zkllvm_field_bls12381_base,
-            y4: __zkllvm_field_bls12381_base,
-            y5: __zkllvm_field_bls12381_base,
-            y6: __zkllvm_field_bls12381_base,
-            y7: __zkllvm_field_bls12381_base,
-            y8: __zkllvm_field_bls12381_base,
-            y9: __zkllvm_field_bls12381_base,
-            y10: __zkllvm_field_bls12381_base,
-            y11: __zkllvm_field_bls12381_base,
-            y12: __zkllvm_field_bls12381_base,
+        ) -> __zkllvm_field_bls12381_gt;
+    }
+
+    unsafe {
+        assigner_gt_init(x[0], x[1], x[2], x[3], x[4], x[5], x[6], x[7], x[8], x[9], x[10], x[11])
+    }
+}
+
+/// Get coefficients of gT element over the base field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_allowed_through_unstable_modules]
+#[inline]
+pub fn assigner_gt_coefficients(x: __zkllvm_field_bls12381_gt) -> [__zkllvm_field_bls12381_base; 12] {
+    extern "rust-intrinsic" {
+        pub fn assigner_gt_coefficients(
+            x: __zkllvm_field_bls12381_gt,
         ) -> [__zkllvm_field_bls12381_base; 12];
     }
 
-    unsafe { assigner_gt_multiplication(
-        x[0], x[1], x[2], x[3], x[4], x[5], x[6], x[7], x[8], x[9], x[10], x[11],
-        y[0], y[1], y[2], y[3], y[4], y[5], y[6], y[7], y[8], y[9], y[10], y[11],
-    ) }
+    unsafe { assigner_gt_coefficients(x) }
 }
 
 // Some functions are defined here because they accidentally got made
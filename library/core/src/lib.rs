@@ -164,6 +164,7 @@
 #![feature(const_unicode_case_lookup)]
 #![feature(const_unsafecell_get_mut)]
 #![feature(const_waker)]
+#![feature(const_zk_curve)]
 #![feature(core_panic)]
 #![feature(duration_consts_float)]
 #![feature(internal_impls_macro)]
@@ -323,6 +324,11 @@ pub mod assert_matches {
 #[cfg(not(bootstrap))]
 #[path = "num/curves.rs"]
 pub mod curves;
+#[cfg(not(bootstrap))]
+#[path = "num/fields.rs"]
+pub mod fields;
+#[cfg(not(bootstrap))]
+pub mod zk;
 
 #[macro_use]
 pub mod num;
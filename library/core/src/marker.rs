@@ -292,11 +292,17 @@ pub trait StructuralEq {
     #[unstable(feature = "structural_match", issue = "31434")]
     StructuralEq for
         __zkllvm_curve_bls12381,
+        __zkllvm_curve_bn254,
+        __zkllvm_curve_secp256k1,
         __zkllvm_curve_curve25519,
         __zkllvm_curve_pallas,
         __zkllvm_curve_vesta,
         __zkllvm_field_bls12381_base,
         __zkllvm_field_bls12381_scalar,
+        __zkllvm_field_bn254_base,
+        __zkllvm_field_bn254_scalar,
+        __zkllvm_field_secp256k1_base,
+        __zkllvm_field_secp256k1_scalar,
         __zkllvm_field_curve25519_base,
         __zkllvm_field_curve25519_scalar,
         __zkllvm_field_pallas_base,
@@ -512,11 +518,19 @@ pub trait Copy: Clone {
     #[stable(feature = "rust1", since = "1.0.0")]
     Copy for
         __zkllvm_curve_bls12381,
+        __zkllvm_curve_bls12381_g2,
+        __zkllvm_curve_bn254,
+        __zkllvm_curve_secp256k1,
         __zkllvm_curve_curve25519,
         __zkllvm_curve_pallas,
         __zkllvm_curve_vesta,
         __zkllvm_field_bls12381_base,
+        __zkllvm_field_bls12381_gt,
         __zkllvm_field_bls12381_scalar,
+        __zkllvm_field_bn254_base,
+        __zkllvm_field_bn254_scalar,
+        __zkllvm_field_secp256k1_base,
+        __zkllvm_field_secp256k1_scalar,
         __zkllvm_field_curve25519_base,
         __zkllvm_field_curve25519_scalar,
         __zkllvm_field_pallas_base,
@@ -0,0 +1,424 @@
+//! Extension fields of the BLS12-381 base field.
+//!
+//! The extension fields form the usual tower:
+//!
+//! * `Fp2 = Fp[u] / (u^2 + 1)`,
+//! * `Fp6 = Fp2[v] / (v^3 - (u + 1))`,
+//! * `Fp12 = Fp6[w] / (w^2 - v)`.
+//!
+//! The assigner has no native types for these fields, so their arithmetic is written
+//! in terms of the base field. `Fp12` multiplication is not lowered to the
+//! `assigner_gt_multiplication` gadget either, because its operands are not necessarily
+//! elements of the pairing target group. Elements that are known to be in the target
+//! group, such as pairing results, should be multiplied as `__zkllvm_field_bls12381_gt`,
+//! whose multiplication is the gadget, and converted to `Fp12` with `From` afterwards.
+
+#![stable(feature = "rust1", since = "1.0.0")]
+
+use crate::fmt;
+use crate::ops::{Add, AddAssign, Div, DivAssign, Field, Mul, MulAssign, Neg, Sub, SubAssign};
+
+type Fp = __zkllvm_field_bls12381_base;
+type Gt = __zkllvm_field_bls12381_gt;
+
+/// `(u + 1)^((p - 1) / 3)`, the factor `v` gains when raised to the power of `p`.
+const FROB_V: Fp2 = Fp2 {
+    c0: 0x0g,
+    c1: 0x1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaacg,
+};
+
+/// `(u + 1)^(2 (p - 1) / 3)`, the factor `v^2` gains when raised to the power of `p`.
+const FROB_V2: Fp2 = Fp2 {
+    c0: 0x1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaadg,
+    c1: 0x0g,
+};
+
+/// `(u + 1)^((p - 1) / 6)`, the factor `w` gains when raised to the power of `p`.
+const FROB_W: Fp2 = Fp2 {
+    c0: 0x1904d3bf02bb0667c231beb4202c0d1f0fd603fd3cbd5f4f7b2443d784bab9c4f67ea53d63e7813d8d0775ed92235fb8g,
+    c1: 0xfc3e2b36c4e03288e9e902231f9fb854a14787b6c7b36fec0c8ec971f63c5f282d5ac14d6c7ec22cf78a126ddc4af3g,
+};
+
+/// Element `c0 + c1 * u` of `Fp2 = Fp[u] / (u^2 + 1)`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub struct Fp2 {
+    /// Coefficient of `1`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c0: Fp,
+    /// Coefficient of `u`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c1: Fp,
+}
+
+/// Element `c0 + c1 * v + c2 * v^2` of `Fp6 = Fp2[v] / (v^3 - (u + 1))`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub struct Fp6 {
+    /// Coefficient of `1`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c0: Fp2,
+    /// Coefficient of `v`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c1: Fp2,
+    /// Coefficient of `v^2`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c2: Fp2,
+}
+
+/// Element `c0 + c1 * w` of `Fp12 = Fp6[w] / (w^2 - v)`.
+///
+/// Elements of the pairing target group `__zkllvm_field_bls12381_gt` convert into it.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub struct Fp12 {
+    /// Coefficient of `1`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c0: Fp6,
+    /// Coefficient of `w`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub c1: Fp6,
+}
+
+impl Fp2 {
+    /// The additive identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub const ZERO: Fp2 = Fp2 { c0: 0x0g, c1: 0x0g };
+
+    /// The multiplicative identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub const ONE: Fp2 = Fp2 { c0: 0x1g, c1: 0x0g };
+
+    /// Returns the conjugate `c0 - c1 * u`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn conjugate(self) -> Fp2 {
+        Fp2 { c0: self.c0, c1: -self.c1 }
+    }
+
+    /// Raises the element to the power of `p`, which is the same as conjugating it.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn frobenius_map(self) -> Fp2 {
+        self.conjugate()
+    }
+
+    /// Multiplies by `u + 1`, the non-residue used to build `Fp6`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn mul_by_nonresidue(self) -> Fp2 {
+        Fp2 { c0: self.c0 - self.c1, c1: self.c0 + self.c1 }
+    }
+
+    /// Inverts a non-zero element.
+    fn inv(self) -> Fp2 {
+        let t = Fp::ONE / (self.c0 * self.c0 + self.c1 * self.c1);
+        Fp2 { c0: self.c0 * t, c1: -self.c1 * t }
+    }
+}
+
+impl Fp6 {
+    /// The additive identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub const ZERO: Fp6 = Fp6 { c0: Fp2::ZERO, c1: Fp2::ZERO, c2: Fp2::ZERO };
+
+    /// The multiplicative identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub const ONE: Fp6 = Fp6 { c0: Fp2::ONE, c1: Fp2::ZERO, c2: Fp2::ZERO };
+
+    /// Raises the element to the power of `p`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn frobenius_map(self) -> Fp6 {
+        Fp6 {
+            c0: self.c0.frobenius_map(),
+            c1: self.c1.frobenius_map() * FROB_V,
+            c2: self.c2.frobenius_map() * FROB_V2,
+        }
+    }
+
+    /// Multiplies by `v`, the non-residue used to build `Fp12`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn mul_by_nonresidue(self) -> Fp6 {
+        Fp6 { c0: self.c2.mul_by_nonresidue(), c1: self.c0, c2: self.c1 }
+    }
+
+    /// Inverts a non-zero element.
+    fn inv(self) -> Fp6 {
+        let Fp6 { c0, c1, c2 } = self;
+        let t0 = c0 * c0 - (c1 * c2).mul_by_nonresidue();
+        let t1 = (c2 * c2).mul_by_nonresidue() - c0 * c1;
+        let t2 = c1 * c1 - c0 * c2;
+        let t = (c0 * t0 + (c2 * t1 + c1 * t2).mul_by_nonresidue()).inv();
+        Fp6 { c0: t0 * t, c1: t1 * t, c2: t2 * t }
+    }
+}
+
+impl Fp12 {
+    /// The additive identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub const ZERO: Fp12 = Fp12 { c0: Fp6::ZERO, c1: Fp6::ZERO };
+
+    /// The multiplicative identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub const ONE: Fp12 = Fp12 { c0: Fp6::ONE, c1: Fp6::ZERO };
+
+    /// Creates an element from its 12 base field coefficients, given in the nesting
+    /// order `c0.c0.c0`, `c0.c0.c1`, `c0.c1.c0`, ..., `c1.c2.c1`.
+    #[inline]
+    #[must_use]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn from_coefficients(c: [Fp; 12]) -> Fp12 {
+        let fp2 = |i: usize| Fp2 { c0: c[i], c1: c[i + 1] };
+        let fp6 = |i: usize| Fp6 { c0: fp2(i), c1: fp2(i + 2), c2: fp2(i + 4) };
+        Fp12 { c0: fp6(0), c1: fp6(6) }
+    }
+
+    /// Returns the 12 base field coefficients of the element,
+    /// in the order accepted by [`from_coefficients`](Self::from_coefficients).
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn coefficients(self) -> [Fp; 12] {
+        let mut coefficients = [0x0g; 12];
+        let fp2s = [self.c0.c0, self.c0.c1, self.c0.c2, self.c1.c0, self.c1.c1, self.c1.c2];
+        for (i, c) in fp2s.into_iter().enumerate() {
+            coefficients[2 * i] = c.c0;
+            coefficients[2 * i + 1] = c.c1;
+        }
+        coefficients
+    }
+
+    /// Returns the conjugate `c0 - c1 * w`, which is the element raised to the power of `p^6`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn conjugate(self) -> Fp12 {
+        Fp12 { c0: self.c0, c1: -self.c1 }
+    }
+
+    /// Raises the element to the power of `p`.
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn frobenius_map(self) -> Fp12 {
+        let c1 = self.c1.frobenius_map();
+        Fp12 {
+            c0: self.c0.frobenius_map(),
+            c1: Fp6 { c0: c1.c0 * FROB_W, c1: c1.c1 * FROB_W, c2: c1.c2 * FROB_W },
+        }
+    }
+
+    /// Inverts a non-zero element.
+    fn inv(self) -> Fp12 {
+        let t = (self.c0 * self.c0 - (self.c1 * self.c1).mul_by_nonresidue()).inv();
+        Fp12 { c0: self.c0 * t, c1: -self.c1 * t }
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Mul for Fp2 {
+    type Output = Fp2;
+
+    #[inline]
+    fn mul(self, rhs: Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0 * rhs.c0 - self.c1 * rhs.c1,
+            c1: self.c0 * rhs.c1 + self.c1 * rhs.c0,
+        }
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Mul for Fp6 {
+    type Output = Fp6;
+
+    #[inline]
+    fn mul(self, rhs: Fp6) -> Fp6 {
+        let (a, b) = (self, rhs);
+        Fp6 {
+            c0: a.c0 * b.c0 + (a.c1 * b.c2 + a.c2 * b.c1).mul_by_nonresidue(),
+            c1: a.c0 * b.c1 + a.c1 * b.c0 + (a.c2 * b.c2).mul_by_nonresidue(),
+            c2: a.c0 * b.c2 + a.c1 * b.c1 + a.c2 * b.c0,
+        }
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Mul for Fp12 {
+    type Output = Fp12;
+
+    #[inline]
+    fn mul(self, rhs: Fp12) -> Fp12 {
+        let (a, b) = (self, rhs);
+        Fp12 {
+            c0: a.c0 * b.c0 + (a.c1 * b.c1).mul_by_nonresidue(),
+            c1: a.c0 * b.c1 + a.c1 * b.c0,
+        }
+    }
+}
+
+macro_rules! impl_extension_field {
+    ($($t:ident { $($c:ident),* })*) => {$(
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl Add for $t {
+            type Output = $t;
+
+            #[inline]
+            fn add(self, rhs: $t) -> $t {
+                $t { $($c: self.$c + rhs.$c),* }
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl Sub for $t {
+            type Output = $t;
+
+            #[inline]
+            fn sub(self, rhs: $t) -> $t {
+                $t { $($c: self.$c - rhs.$c),* }
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl Neg for $t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                $t { $($c: -self.$c),* }
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl Div for $t {
+            type Output = $t;
+
+            /// Multiplies by the inverse of `rhs`, which panics when dividing by zero.
+            #[inline]
+            fn div(self, rhs: $t) -> $t {
+                self * rhs.inv()
+            }
+        }
+
+        #[stable(feature = "op_assign_traits", since = "1.8.0")]
+        impl AddAssign for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: $t) {
+                *self = *self + rhs;
+            }
+        }
+
+        #[stable(feature = "op_assign_traits", since = "1.8.0")]
+        impl SubAssign for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $t) {
+                *self = *self - rhs;
+            }
+        }
+
+        #[stable(feature = "op_assign_traits", since = "1.8.0")]
+        impl MulAssign for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        #[stable(feature = "op_assign_traits", since = "1.8.0")]
+        impl DivAssign for $t {
+            #[inline]
+            fn div_assign(&mut self, rhs: $t) {
+                *self = *self / rhs;
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut tuple = f.debug_tuple("");
+                $(tuple.field(&format_args!("{}", self.$c));)*
+                tuple.finish()
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl Field for $t {
+            const ZERO: Self = <$t>::ZERO;
+            const ONE: Self = <$t>::ONE;
+
+            #[inline]
+            fn inverse(self) -> Option<Self> {
+                if self == Self::ZERO { None } else { Some(self.inv()) }
+            }
+
+            #[inline]
+            fn pow(self, mut exp: u128) -> Self {
+                let mut base = self;
+                let mut acc = Self::ONE;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc = acc * base;
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        base = base * base;
+                    }
+                }
+                acc
+            }
+        }
+    )*}
+}
+
+impl_extension_field! {
+    Fp2 { c0, c1 }
+    Fp6 { c0, c1, c2 }
+    Fp12 { c0, c1 }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl From<Fp> for Fp2 {
+    #[inline]
+    fn from(c0: Fp) -> Fp2 {
+        Fp2 { c0, c1: 0x0g }
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl From<Fp2> for Fp6 {
+    #[inline]
+    fn from(c0: Fp2) -> Fp6 {
+        Fp6 { c0, c1: Fp2::ZERO, c2: Fp2::ZERO }
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl From<Fp6> for Fp12 {
+    #[inline]
+    fn from(c0: Fp6) -> Fp12 {
+        Fp12 { c0, c1: Fp6::ZERO }
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl From<Gt> for Fp12 {
+    #[inline]
+    fn from(value: Gt) -> Fp12 {
+        Fp12::from_coefficients(value.coefficients())
+    }
+}
@@ -2,13 +2,15 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use crate::fields::bls12381::Fp2;
 use crate::intrinsics;
 
 impl __zkllvm_curve_bls12381 {
     /// Create curve element from its base field coordinates.
     #[inline(always)]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub unsafe fn from_coordinates(
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub const unsafe fn from_coordinates(
         x: __zkllvm_field_bls12381_base,
         y: __zkllvm_field_bls12381_base,
     ) -> Self {
@@ -16,11 +18,38 @@ pub unsafe fn from_coordinates(
     }
 }
 
+impl __zkllvm_curve_bn254 {
+    /// Create curve element from its base field coordinates.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub const unsafe fn from_coordinates(
+        x: __zkllvm_field_bn254_base,
+        y: __zkllvm_field_bn254_base,
+    ) -> Self {
+        unsafe { intrinsics::assigner_curve_init_bn254(x, y) }
+    }
+}
+
+impl __zkllvm_curve_secp256k1 {
+    /// Create curve element from its base field coordinates.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub const unsafe fn from_coordinates(
+        x: __zkllvm_field_secp256k1_base,
+        y: __zkllvm_field_secp256k1_base,
+    ) -> Self {
+        unsafe { intrinsics::assigner_curve_init_secp256k1(x, y) }
+    }
+}
+
 impl __zkllvm_curve_curve25519 {
     /// Create curve element from its base field coordinates.
     #[inline(always)]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub unsafe fn from_coordinates(
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub const unsafe fn from_coordinates(
         x: __zkllvm_field_curve25519_base,
         y: __zkllvm_field_curve25519_base,
     ) -> Self {
@@ -32,7 +61,8 @@ impl __zkllvm_curve_pallas {
     /// Create curve element from its base field coordinates.
     #[inline(always)]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub unsafe fn from_coordinates(
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub const unsafe fn from_coordinates(
         x: __zkllvm_field_pallas_base,
         y: __zkllvm_field_pallas_base,
     ) -> Self {
@@ -44,7 +74,8 @@ impl __zkllvm_curve_vesta {
     /// Create curve element from its base field coordinates.
     #[inline(always)]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub unsafe fn from_coordinates(
+    #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+    pub const unsafe fn from_coordinates(
         x: __zkllvm_field_pallas_scalar,
         y: __zkllvm_field_pallas_scalar,
     ) -> Self {
@@ -62,6 +93,16 @@ mod consts {
     pub const BLS12381_CURVE_ONE_X: __zkllvm_field_bls12381_base = 0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bbg;
     pub const BLS12381_CURVE_ONE_Y: __zkllvm_field_bls12381_base = 0x8b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1g;
 
+    pub const BN254_CURVE_ZERO_X: __zkllvm_field_bn254_base = 0x0g;
+    pub const BN254_CURVE_ZERO_Y: __zkllvm_field_bn254_base = 0x1g;
+    pub const BN254_CURVE_ONE_X: __zkllvm_field_bn254_base = 0x1g;
+    pub const BN254_CURVE_ONE_Y: __zkllvm_field_bn254_base = 0x2g;
+
+    pub const SECP256K1_CURVE_ZERO_X: __zkllvm_field_secp256k1_base = 0x0g;
+    pub const SECP256K1_CURVE_ZERO_Y: __zkllvm_field_secp256k1_base = 0x1g;
+    pub const SECP256K1_CURVE_ONE_X: __zkllvm_field_secp256k1_base = 0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798g;
+    pub const SECP256K1_CURVE_ONE_Y: __zkllvm_field_secp256k1_base = 0x483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8g;
+
     pub const CURVE25519_CURVE_ZERO_X: __zkllvm_field_curve25519_base = 0x0g;
     pub const CURVE25519_CURVE_ZERO_Y: __zkllvm_field_curve25519_base = 0x1g;
     pub const CURVE25519_CURVE_ONE_X: __zkllvm_field_curve25519_base = 0x216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51ag;
@@ -104,7 +145,262 @@ pub fn one() -> Self {
 
 impl_zero_one! {
     __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base, BLS12381_CURVE_ZERO_X, BLS12381_CURVE_ZERO_Y, BLS12381_CURVE_ONE_X, BLS12381_CURVE_ONE_Y
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_base, BN254_CURVE_ZERO_X, BN254_CURVE_ZERO_Y, BN254_CURVE_ONE_X, BN254_CURVE_ONE_Y
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_base, SECP256K1_CURVE_ZERO_X, SECP256K1_CURVE_ZERO_Y, SECP256K1_CURVE_ONE_X, SECP256K1_CURVE_ONE_Y
     __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base, CURVE25519_CURVE_ZERO_X, CURVE25519_CURVE_ZERO_Y, CURVE25519_CURVE_ONE_X, CURVE25519_CURVE_ONE_Y
     __zkllvm_curve_pallas, __zkllvm_field_pallas_base, PALLAS_CURVE_ZERO_X, PALLAS_CURVE_ZERO_Y, PALLAS_CURVE_ONE_X, PALLAS_CURVE_ONE_Y
     __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar, VESTA_CURVE_ZERO_X, VESTA_CURVE_ZERO_Y, VESTA_CURVE_ONE_X, VESTA_CURVE_ONE_Y
 }
+
+macro_rules! impl_group_ops {
+    ($($t:ty, $f:ty, $x:ident, $y:ident, $check:ident;)*) => {
+        $(
+            impl $t {
+                /// Returns the `x` coordinate of the point.
+                #[inline(always)]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn x(self) -> $f {
+                    // SAFETY: every curve element has coordinates.
+                    unsafe { intrinsics::$x(self) }
+                }
+
+                /// Returns the `y` coordinate of the point.
+                #[inline(always)]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn y(self) -> $f {
+                    // SAFETY: every curve element has coordinates.
+                    unsafe { intrinsics::$y(self) }
+                }
+
+                /// Returns the affine coordinates `(x, y)` of the point.
+                ///
+                /// The neutral element is represented as `(0, 1)`.
+                #[inline(always)]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn to_affine(self) -> ($f, $f) {
+                    (self.x(), self.y())
+                }
+
+                /// Returns the point added to itself.
+                ///
+                /// The assigner has no doubling gadget, so this is the addition
+                /// `self + self`, whose gadget also handles equal operands.
+                #[inline(always)]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn double(self) -> Self {
+                    self + self
+                }
+
+                /// Returns `true` if the point is the neutral element.
+                ///
+                /// There is no dedicated gadget either: this compares the point with
+                /// [`zero`](Self::zero) using curve equality.
+                #[inline(always)]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn is_zero(self) -> bool {
+                    self == Self::zero()
+                }
+
+                /// Create curve element from its base field coordinates,
+                /// checking that they satisfy the curve equation.
+                ///
+                /// Returns `None` if `(x, y)` is not a point of the curve. The neutral
+                /// element is accepted as `(0, 1)`.
+                #[inline(always)]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                #[rustc_const_unstable(feature = "const_zk_curve", issue = "none")]
+                pub const fn try_from_coordinates(x: $f, y: $f) -> Option<Self> {
+                    // SAFETY: the check has no preconditions.
+                    if unsafe { intrinsics::$check(x, y) } {
+                        // SAFETY: the point is on the curve.
+                        Some(unsafe { Self::from_coordinates(x, y) })
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_group_ops! {
+    __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base, assigner_curve_x_bls12381, assigner_curve_y_bls12381, assigner_is_on_curve_bls12381;
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_base, assigner_curve_x_bn254, assigner_curve_y_bn254, assigner_is_on_curve_bn254;
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_base, assigner_curve_x_secp256k1, assigner_curve_y_secp256k1, assigner_is_on_curve_secp256k1;
+    __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base, assigner_curve_x_curve25519, assigner_curve_y_curve25519, assigner_is_on_curve_curve25519;
+    __zkllvm_curve_pallas, __zkllvm_field_pallas_base, assigner_curve_x_pallas, assigner_curve_y_pallas, assigner_is_on_curve_pallas;
+    __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar, assigner_curve_x_vesta, assigner_curve_y_vesta, assigner_is_on_curve_vesta;
+}
+
+macro_rules! impl_msm {
+    ($($t:ty, $s:ty, $msm:ident;)*) => {
+        $(
+            impl $t {
+                /// Multi-scalar multiplication: returns the sum of `points[i] * scalars[i]`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `points` and `scalars` have different lengths.
+                #[inline(always)]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn msm(points: &[Self], scalars: &[$s]) -> Self {
+                    assert_eq!(points.len(), scalars.len(), "msm operands have different lengths");
+                    // SAFETY: both pointers are valid for `points.len()` reads.
+                    unsafe { intrinsics::$msm(points.as_ptr(), scalars.as_ptr(), points.len()) }
+                }
+            }
+        )*
+    }
+}
+
+impl_msm! {
+    __zkllvm_curve_bls12381, __zkllvm_field_bls12381_scalar, assigner_msm_bls12381;
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_scalar, assigner_msm_bn254;
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_scalar, assigner_msm_secp256k1;
+    __zkllvm_curve_curve25519, __zkllvm_field_curve25519_scalar, assigner_msm_curve25519;
+    __zkllvm_curve_pallas, __zkllvm_field_pallas_scalar, assigner_msm_pallas;
+    __zkllvm_curve_vesta, __zkllvm_field_pallas_base, assigner_msm_vesta;
+}
+
+impl __zkllvm_curve_bls12381_g2 {
+    /// Create g2 element from its coordinates.
+    ///
+    /// The coordinates are elements of `Fp2 = Fp[u] / (u^2 + 1)`, each given as
+    /// `[c0, c1]`.
+    ///
+    /// # Safety
+    ///
+    /// The coordinates must satisfy the curve equation.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub unsafe fn from_coordinates(
+        x: [__zkllvm_field_bls12381_base; 2],
+        y: [__zkllvm_field_bls12381_base; 2],
+    ) -> Self {
+        unsafe { intrinsics::assigner_g2_init([x[0], x[1], y[0], y[1]]) }
+    }
+
+    /// Returns g2 neutral element.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn zero() -> Self {
+        unsafe { Self::from_coordinates([0x0g, 0x0g], [0x1g, 0x0g]) }
+    }
+
+    /// Returns the `x` coordinate of the point as `[c0, c1]`.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn x(self) -> [__zkllvm_field_bls12381_base; 2] {
+        let [x0, x1, _, _] = intrinsics::assigner_g2_coordinates(self);
+        [x0, x1]
+    }
+
+    /// Returns the `y` coordinate of the point as `[c0, c1]`.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn y(self) -> [__zkllvm_field_bls12381_base; 2] {
+        let [_, _, y0, y1] = intrinsics::assigner_g2_coordinates(self);
+        [y0, y1]
+    }
+
+    /// Returns the affine coordinates `(x, y)` of the point.
+    ///
+    /// The neutral element is represented as `(0, 1)`.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn to_affine(
+        self,
+    ) -> ([__zkllvm_field_bls12381_base; 2], [__zkllvm_field_bls12381_base; 2]) {
+        (self.x(), self.y())
+    }
+
+    /// Returns `true` if the point is the neutral element.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn is_zero(self) -> bool {
+        self == Self::zero()
+    }
+
+    /// Returns `true` if the point lies in the prime order subgroup G2.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn is_in_g2(self) -> bool {
+        intrinsics::assigner_is_in_g2(self)
+    }
+}
+
+/// Arithmetic of g2 points, used by the operator implementations.
+///
+/// The assigner has no gadgets for g2 arithmetic, so it is written in affine coordinates
+/// over `Fp2`, whose arithmetic in turn is written in terms of the base field.
+impl __zkllvm_curve_bls12381_g2 {
+    #[inline]
+    fn from_fp2(x: Fp2, y: Fp2) -> Self {
+        // SAFETY: callers only pass points of the curve.
+        unsafe { Self::from_coordinates([x.c0, x.c1], [y.c0, y.c1]) }
+    }
+
+    #[inline]
+    fn to_fp2(self) -> (Fp2, Fp2) {
+        let [x0, x1, y0, y1] = intrinsics::assigner_g2_coordinates(self);
+        (Fp2 { c0: x0, c1: x1 }, Fp2 { c0: y0, c1: y1 })
+    }
+
+    /// Adds two points.
+    pub(crate) fn add_point(self, rhs: Self) -> Self {
+        if self.is_zero() {
+            return rhs;
+        }
+        if rhs.is_zero() {
+            return self;
+        }
+        let (x1, y1) = self.to_fp2();
+        let (x2, y2) = rhs.to_fp2();
+        let lambda = if x1 != x2 {
+            (y2 - y1) / (x2 - x1)
+        } else if y1 == y2 && y1 != Fp2::ZERO {
+            let xx = x1 * x1;
+            (xx + xx + xx) / (y1 + y1)
+        } else {
+            // Opposite points.
+            return Self::zero();
+        };
+        let x = lambda * lambda - x1 - x2;
+        let y = lambda * (x1 - x) - y1;
+        Self::from_fp2(x, y)
+    }
+
+    /// Negates the point.
+    pub(crate) fn neg_point(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        let (x, y) = self.to_fp2();
+        Self::from_fp2(x, -y)
+    }
+
+    /// Multiplies the point by a scalar with double-and-add.
+    pub(crate) fn mul_scalar(self, scalar: __zkllvm_field_bls12381_scalar) -> Self {
+        let mut acc = Self::zero();
+        for byte in scalar.to_le_bytes().into_iter().rev() {
+            for i in (0..8).rev() {
+                acc = acc.add_point(acc);
+                if (byte >> i) & 1 == 1 {
+                    acc = acc.add_point(self);
+                }
+            }
+        }
+        acc
+    }
+}
+
+impl __zkllvm_curve_bls12381 {
+    /// Computes the optimal ate pairing of the point and a g2 point.
+    #[inline(always)]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn pairing(self, q: __zkllvm_curve_bls12381_g2) -> __zkllvm_field_bls12381_gt {
+        intrinsics::assigner_bls12_pairing(self, q)
+    }
+}
@@ -0,0 +1,296 @@
+//! Finite field functions.
+
+#![stable(feature = "rust1", since = "1.0.0")]
+
+pub mod bls12381;
+
+/// Field moduli as little-endian bytes.
+#[rustfmt::skip]
+mod consts {
+    pub const BLS12381_BASE_MODULUS: [u8; 48] = [
+        0xab, 0xaa, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xb9, 0xff, 0xff, 0x53, 0xb1, 0xfe, 0xff, 0xab, 0x1e,
+        0x24, 0xf6, 0xb0, 0xf6, 0xa0, 0xd2, 0x30, 0x67, 0xbf, 0x12, 0x85, 0xf3, 0x84, 0x4b, 0x77, 0x64,
+        0xd7, 0xac, 0x4b, 0x43, 0xb6, 0xa7, 0x1b, 0x4b, 0x9a, 0xe6, 0x7f, 0x39, 0xea, 0x11, 0x01, 0x1a,
+    ];
+    pub const BLS12381_SCALAR_MODULUS: [u8; 32] = [
+        0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0x02, 0xa4, 0xbd, 0x53,
+        0x05, 0xd8, 0xa1, 0x09, 0x08, 0xd8, 0x39, 0x33, 0x48, 0x7d, 0x9d, 0x29, 0x53, 0xa7, 0xed, 0x73,
+    ];
+    pub const BN254_BASE_MODULUS: [u8; 32] = [
+        0x47, 0xfd, 0x7c, 0xd8, 0x16, 0x8c, 0x20, 0x3c, 0x8d, 0xca, 0x71, 0x68, 0x91, 0x6a, 0x81, 0x97,
+        0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e, 0x64, 0x30,
+    ];
+    pub const BN254_SCALAR_MODULUS: [u8; 32] = [
+        0x01, 0x00, 0x00, 0xf0, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79, 0x48, 0xe8, 0x33, 0x28,
+        0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e, 0x64, 0x30,
+    ];
+    pub const SECP256K1_BASE_MODULUS: [u8; 32] = [
+        0x2f, 0xfc, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    ];
+    pub const SECP256K1_SCALAR_MODULUS: [u8; 32] = [
+        0x41, 0x41, 0x36, 0xd0, 0x8c, 0x5e, 0xd2, 0xbf, 0x3b, 0xa0, 0x48, 0xaf, 0xe6, 0xdc, 0xae, 0xba,
+        0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    ];
+    pub const CURVE25519_BASE_MODULUS: [u8; 32] = [
+        0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+    ];
+    pub const CURVE25519_SCALAR_MODULUS: [u8; 32] = [
+        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+    ];
+    pub const PALLAS_BASE_MODULUS: [u8; 32] = [
+        0x01, 0x00, 0x00, 0x00, 0xed, 0x30, 0x2d, 0x99, 0x1b, 0xf9, 0x4c, 0x09, 0xfc, 0x98, 0x46, 0x22,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+    ];
+    pub const PALLAS_SCALAR_MODULUS: [u8; 32] = [
+        0x01, 0x00, 0x00, 0x00, 0x21, 0xeb, 0x46, 0x8c, 0xdd, 0xa8, 0x94, 0x09, 0xfc, 0x98, 0x46, 0x22,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+    ];
+}
+
+use consts::*;
+
+macro_rules! impl_field_truncate {
+    ($($t:ty)*) => {
+        $(
+            impl $t {
+                /// Returns the lowest 128 bits of the canonical value of the element.
+                ///
+                /// Unlike `u128::try_from`, this never fails: any higher bits are discarded.
+                #[inline]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn truncate_to_u128(self) -> u128 {
+                    // SAFETY: `Self` is a prime field type.
+                    unsafe { crate::intrinsics::assigner_field_to_int(self) }
+                }
+            }
+        )*
+    }
+}
+
+impl_field_truncate! {
+    __zkllvm_field_bls12381_base
+    __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
+    __zkllvm_field_curve25519_base
+    __zkllvm_field_curve25519_scalar
+    __zkllvm_field_pallas_base
+    __zkllvm_field_pallas_scalar
+}
+
+macro_rules! impl_field_consts {
+    ($($t:ty, $size:literal, $bits:literal, $modulus:ident, $shift_inv:literal;)*) => {
+        $(
+            impl $t {
+                /// The additive identity.
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub const ZERO: Self = 0g;
+
+                /// The multiplicative identity.
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub const ONE: Self = 1g;
+
+                /// The field modulus as little-endian bytes.
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub const MODULUS: [u8; $size] = $modulus;
+
+                /// The number of significant bits of the field modulus.
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub const BITS: u32 = $bits;
+
+                /// Returns the canonical value of the element as a little-endian byte array.
+                #[inline]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn to_le_bytes(self) -> [u8; $size] {
+                    // Truncation takes the lowest 128 bits of the canonical value.
+                    // Once they are subtracted, the value is a multiple of 2^128, and
+                    // multiplying it by 2^-128 in the field is the same as shifting it.
+                    const SHIFT_INV: $t = $shift_inv;
+                    let mut bytes = [0; $size];
+                    let mut value = self;
+                    for chunk in bytes.chunks_exact_mut(16) {
+                        let low = value.truncate_to_u128();
+                        chunk.copy_from_slice(&low.to_le_bytes());
+                        value = (value - low as Self) * SHIFT_INV;
+                    }
+                    bytes
+                }
+
+                /// Returns the canonical value of the element as a big-endian byte array.
+                #[inline]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn to_be_bytes(self) -> [u8; $size] {
+                    let mut bytes = self.to_le_bytes();
+                    bytes.reverse();
+                    bytes
+                }
+
+                /// Creates an element from its canonical value as a little-endian byte array.
+                ///
+                /// Returns `None` if the value is not less than the modulus.
+                #[inline]
+                #[must_use]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn from_le_bytes(bytes: [u8; $size]) -> Option<Self> {
+                    // Compare with the modulus starting from the most significant byte.
+                    let mut iter = bytes.iter().rev().zip(Self::MODULUS.iter().rev());
+                    match iter.find(|(byte, modulus)| byte != modulus) {
+                        Some((byte, modulus)) if byte < modulus => {}
+                        _ => return None,
+                    }
+                    let shift: Self = 0x100000000000000000000000000000000g;
+                    let mut value = Self::ZERO;
+                    for chunk in bytes.rchunks_exact(16) {
+                        let low = u128::from_le_bytes(chunk.try_into().unwrap());
+                        value = value * shift + low as Self;
+                    }
+                    Some(value)
+                }
+
+                /// Creates an element from its canonical value as a big-endian byte array.
+                ///
+                /// Returns `None` if the value is not less than the modulus.
+                #[inline]
+                #[must_use]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn from_be_bytes(mut bytes: [u8; $size]) -> Option<Self> {
+                    bytes.reverse();
+                    Self::from_le_bytes(bytes)
+                }
+            }
+        )*
+    }
+}
+
+impl_field_consts! {
+    __zkllvm_field_bls12381_base, 48, 381, BLS12381_BASE_MODULUS,
+        0x41b058bd90786d7ab1d43ef2fe3308e0d417a80d142499904b3863a3c2dad798fc69b3f4e71f1d9c34888a61621a6e8g;
+    __zkllvm_field_bls12381_scalar, 32, 255, BLS12381_SCALAR_MODULUS,
+        0x25ea7194f10b70cd39b72c8d31e64627ed3b3995539ac7e3e7e9bfeefffb13fag;
+    __zkllvm_field_bn254_base, 32, 254, BN254_BASE_MODULUS,
+        0x1e07f71b064ef9b1b5ab34890dfa3d6127a2f342f99058831da790e434ade680g;
+    __zkllvm_field_bn254_scalar, 32, 254, BN254_SCALAR_MODULUS,
+        0x133100d71fdf35792b16366f4f7684df54ad7e14a329e70f18ee753c76f9dc6fg;
+    __zkllvm_field_secp256k1_base, 32, 256, SECP256K1_BASE_MODULUS,
+        0xbcb223fedc24a059d838091dd2253530ffffffffffffffffffffffff434dd931g;
+    __zkllvm_field_secp256k1_scalar, 32, 256, SECP256K1_SCALAR_MODULUS,
+        0x50a51ac834b9ec244b0dff665588b13e9984d5b3cf80ef0fd6a23766a3ee9f22g;
+    __zkllvm_field_curve25519_base, 32, 255, CURVE25519_BASE_MODULUS,
+        0x5e50d79435e50d79435e50d79435e50d7ffffffffffffffffffffffffffffff2g;
+    __zkllvm_field_curve25519_scalar, 32, 253, CURVE25519_SCALAR_MODULUS,
+        0xb1a206f2fdba84ffd2b51da312547e1be7b606e16e35233b538369544d13a5eg;
+    __zkllvm_field_pallas_base, 32, 255, PALLAS_BASE_MODULUS,
+        0x1a71d82f60a84f6ca64b4c3b3fffffffce29a69424b6b2eaa0cd79c640dd966ag;
+    __zkllvm_field_pallas_scalar, 32, 255, PALLAS_SCALAR_MODULUS,
+        0x35b20ea460e4c3a72311bac83fffffffdcc1d2e1af2d5301df9a668efebc83b2g;
+}
+
+macro_rules! impl_field_ops {
+    ($($t:ty)*) => {
+        $(
+            impl $t {
+                /// Returns the multiplicative inverse of the element,
+                /// or `None` if the element is zero.
+                #[inline]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn inverse(self) -> Option<Self> {
+                    if self == Self::ZERO { None } else { Some(Self::ONE / self) }
+                }
+
+                /// Raises the element to the power of `exp`, using exponentiation by squaring.
+                ///
+                /// Any element raised to the power of zero is one, including zero itself.
+                #[inline]
+                #[must_use = "this returns the result of the operation, \
+                              without modifying the original"]
+                #[stable(feature = "rust1", since = "1.0.0")]
+                pub fn pow(self, mut exp: u128) -> Self {
+                    let mut base = self;
+                    let mut acc = Self::ONE;
+                    while exp > 0 {
+                        if exp & 1 == 1 {
+                            acc = acc * base;
+                        }
+                        exp >>= 1;
+                        if exp > 0 {
+                            base = base * base;
+                        }
+                    }
+                    acc
+                }
+            }
+        )*
+    }
+}
+
+impl_field_ops! {
+    __zkllvm_field_bls12381_base
+    __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
+    __zkllvm_field_curve25519_base
+    __zkllvm_field_curve25519_scalar
+    __zkllvm_field_pallas_base
+    __zkllvm_field_pallas_scalar
+}
+
+impl __zkllvm_field_bls12381_gt {
+    /// Creates an element from its 12 base field coefficients.
+    ///
+    /// The element belongs to the tower `Fp2 = Fp[u] / (u^2 + 1)`,
+    /// `Fp6 = Fp2[v] / (v^3 - (u + 1))`, `Fp12 = Fp6[w] / (w^2 - v)`, and the coefficients
+    /// are given in the nesting order `c0.c0.c0`, `c0.c0.c1`, `c0.c1.c0`, ..., `c1.c2.c1`.
+    ///
+    /// # Safety
+    ///
+    /// The element must belong to the target group of the pairing.
+    #[inline]
+    #[must_use]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub unsafe fn from_coefficients(coefficients: [__zkllvm_field_bls12381_base; 12]) -> Self {
+        unsafe { crate::intrinsics::assigner_gt_init(coefficients) }
+    }
+
+    /// Returns the 12 base field coefficients of the element,
+    /// in the order accepted by [`from_coefficients`](Self::from_coefficients).
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn coefficients(self) -> [__zkllvm_field_bls12381_base; 12] {
+        crate::intrinsics::assigner_gt_coefficients(self)
+    }
+
+    /// Returns the identity of the target group.
+    #[inline]
+    #[must_use]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn one() -> Self {
+        let mut coefficients = [0x0g; 12];
+        coefficients[0] = 0x1g;
+        // SAFETY: one is the identity of every multiplicative group.
+        unsafe { Self::from_coefficients(coefficients) }
+    }
+
+    /// Returns `true` if the element is the identity of the target group.
+    #[inline]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    pub fn is_one(self) -> bool {
+        self == Self::one()
+    }
+}
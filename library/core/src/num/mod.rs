@@ -47,6 +47,8 @@ macro_rules! unlikely {
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 mod saturating;
 mod wrapping;
+#[cfg(not(bootstrap))]
+mod zk_soft;
 
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 pub use saturating::Saturating;
@@ -0,0 +1,147 @@
+//! Affine arithmetic on the supported elliptic curves.
+//!
+//! This mirrors the curve arithmetic used by the compiler to evaluate curve
+//! operations in constants, so that host builds and const evaluation agree.
+
+use super::field::{Fe, FieldParams, Limbs};
+
+/// Shape of the curve equation, with a canonical (not Montgomery) coefficient.
+pub(super) enum CurveModel {
+    /// Short Weierstrass curve `y^2 = x^3 + b`.
+    ///
+    /// There is no affine point for the neutral element, so it is represented
+    /// by `(0, 1)`, which does not belong to any of these curves.
+    Weierstrass { b: Limbs },
+    /// Twisted Edwards curve `-x^2 + y^2 = 1 + d * x^2 * y^2`.
+    ///
+    /// Neutral element is `(0, 1)`.
+    TwistedEdwards { d: Limbs },
+}
+
+/// A curve point in affine coordinates, kept in Montgomery form.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) struct Point {
+    pub(super) x: Fe,
+    pub(super) y: Fe,
+}
+
+/// Curve description the point arithmetic works with.
+pub(super) struct Curve<'a> {
+    pub(super) field: &'a FieldParams,
+    pub(super) model: &'a CurveModel,
+}
+
+impl Curve<'_> {
+    /// Returns the neutral element of the curve group.
+    pub(super) fn zero(&self) -> Point {
+        Point { x: Fe::ZERO, y: Fe::one(self.field) }
+    }
+
+    fn is_zero(&self, p: Point) -> bool {
+        p == self.zero()
+    }
+
+    /// Checks that the point satisfies the curve equation.
+    pub(super) fn is_on_curve(&self, p: Point) -> bool {
+        if self.is_zero(p) {
+            return true;
+        }
+        let f = self.field;
+        let xx = p.x.mul(p.x, f);
+        let yy = p.y.mul(p.y, f);
+        match self.model {
+            CurveModel::Weierstrass { b } => yy == xx.mul(p.x, f).add(Fe::from_canonical(b, f), f),
+            CurveModel::TwistedEdwards { d } => {
+                let dxxyy = Fe::from_canonical(d, f).mul(xx, f).mul(yy, f);
+                yy.sub(xx, f) == Fe::one(f).add(dxxyy, f)
+            }
+        }
+    }
+
+    pub(super) fn neg(&self, p: Point) -> Point {
+        let f = self.field;
+        match self.model {
+            CurveModel::Weierstrass { .. } if self.is_zero(p) => p,
+            CurveModel::Weierstrass { .. } => Point { x: p.x, y: p.y.neg(f) },
+            CurveModel::TwistedEdwards { .. } => Point { x: p.x.neg(f), y: p.y },
+        }
+    }
+
+    /// Adds two points. Both points must be on the curve.
+    pub(super) fn add(&self, p: Point, q: Point) -> Point {
+        let f = self.field;
+        match self.model {
+            CurveModel::Weierstrass { .. } => {
+                if self.is_zero(p) {
+                    return q;
+                }
+                if self.is_zero(q) {
+                    return p;
+                }
+                if p.x == q.x {
+                    // Either the same point or the opposite one.
+                    return if p.y == q.y { self.double(p) } else { self.zero() };
+                }
+                let lambda = q.y.sub(p.y, f).mul(q.x.sub(p.x, f).inv(f), f);
+                self.weierstrass_chord(p, lambda, q.x)
+            }
+            CurveModel::TwistedEdwards { d } => {
+                // Edwards addition law is complete, so denominators are never zero
+                // for points on the curve.
+                let one = Fe::one(f);
+                let d = Fe::from_canonical(d, f);
+                let x1y2 = p.x.mul(q.y, f);
+                let y1x2 = p.y.mul(q.x, f);
+                let x1x2 = p.x.mul(q.x, f);
+                let y1y2 = p.y.mul(q.y, f);
+                let dxxyy = d.mul(x1x2, f).mul(y1y2, f);
+                let x = x1y2.add(y1x2, f).mul(one.add(dxxyy, f).inv(f), f);
+                let y = y1y2.add(x1x2, f).mul(one.sub(dxxyy, f).inv(f), f);
+                Point { x, y }
+            }
+        }
+    }
+
+    pub(super) fn sub(&self, p: Point, q: Point) -> Point {
+        self.add(p, self.neg(q))
+    }
+
+    /// Doubles the point. The point must be on the curve.
+    fn double(&self, p: Point) -> Point {
+        let f = self.field;
+        match self.model {
+            CurveModel::Weierstrass { .. } => {
+                if self.is_zero(p) || p.y.is_zero() {
+                    // Points of order two are doubled to zero.
+                    return self.zero();
+                }
+                let xx = p.x.mul(p.x, f);
+                let three_xx = xx.add(xx, f).add(xx, f);
+                let lambda = three_xx.mul(p.y.add(p.y, f).inv(f), f);
+                self.weierstrass_chord(p, lambda, p.x)
+            }
+            CurveModel::TwistedEdwards { .. } => self.add(p, p),
+        }
+    }
+
+    /// Returns the third intersection of the line with slope `lambda` through `p`
+    /// and the point with abscissa `other_x`, reflected over the `x` axis.
+    fn weierstrass_chord(&self, p: Point, lambda: Fe, other_x: Fe) -> Point {
+        let f = self.field;
+        let x = lambda.mul(lambda, f).sub(p.x, f).sub(other_x, f);
+        let y = lambda.mul(p.x.sub(x, f), f).sub(p.y, f);
+        Point { x, y }
+    }
+
+    /// Multiplies the point by a canonical scalar.
+    pub(super) fn mul(&self, p: Point, scalar: &Limbs) -> Point {
+        let mut acc = self.zero();
+        for i in (0..scalar.len() * 64).rev() {
+            acc = self.double(acc);
+            if (scalar[i / 64] >> (i % 64)) & 1 == 1 {
+                acc = self.add(acc, p);
+            }
+        }
+        acc
+    }
+}
@@ -0,0 +1,197 @@
+//! Montgomery arithmetic over prime fields of up to 384 bits.
+
+/// Maximal number of 64-bit limbs in a field value.
+pub(super) const MAX_LIMBS: usize = 6;
+
+/// Little-endian 64-bit limbs of a field value.
+///
+/// Fields narrower than `MAX_LIMBS` limbs keep the unused upper limbs zeroed.
+pub(super) type Limbs = [u64; MAX_LIMBS];
+
+/// Constants needed to do Montgomery arithmetic in a prime field.
+pub(super) struct FieldParams {
+    /// Number of used limbs.
+    n: usize,
+    modulus: Limbs,
+    /// `-modulus^(-1) mod 2^64`.
+    inv: u64,
+    /// `R^2 mod modulus`, where `R = 2^(64 * n)`.
+    r2: Limbs,
+}
+
+impl FieldParams {
+    pub(super) const fn new(modulus: Limbs, n: usize) -> Self {
+        // Exponentiation by `2^63 - 1` gives the inverse of an odd number modulo `2^64`.
+        let mut inv = 1u64;
+        let mut i = 0;
+        while i < 63 {
+            inv = inv.wrapping_mul(inv);
+            inv = inv.wrapping_mul(modulus[0]);
+            i += 1;
+        }
+        let inv = inv.wrapping_neg();
+
+        // Compute `R^2` by doubling one `2 * 64 * n` times.
+        let mut r2 = [0; MAX_LIMBS];
+        r2[0] = 1;
+        let mut i = 0;
+        while i < 2 * 64 * n {
+            r2 = add_mod(&r2, &r2, &modulus, n);
+            i += 1;
+        }
+
+        FieldParams { n, modulus, inv, r2 }
+    }
+}
+
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let (d, b1) = a.overflowing_sub(b);
+    let (d, b2) = d.overflowing_sub(borrow);
+    (d, (b1 | b2) as u64)
+}
+
+/// Returns `a + b * c + carry` as a pair of low and high limbs.
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Subtracts `p` from `a` if `a` (extended with the `high` limb) is not less than `p`.
+const fn reduce_once(a: &Limbs, high: u64, p: &Limbs, n: usize) -> Limbs {
+    let mut d = [0; MAX_LIMBS];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < n {
+        (d[i], borrow) = sbb(a[i], p[i], borrow);
+        i += 1;
+    }
+    if high != 0 || borrow == 0 { d } else { *a }
+}
+
+const fn add_mod(a: &Limbs, b: &Limbs, p: &Limbs, n: usize) -> Limbs {
+    let mut s = [0; MAX_LIMBS];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < n {
+        (s[i], carry) = adc(a[i], b[i], carry);
+        i += 1;
+    }
+    reduce_once(&s, carry, p, n)
+}
+
+const fn sub_mod(a: &Limbs, b: &Limbs, p: &Limbs, n: usize) -> Limbs {
+    let mut d = [0; MAX_LIMBS];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < n {
+        (d[i], borrow) = sbb(a[i], b[i], borrow);
+        i += 1;
+    }
+    if borrow == 0 {
+        return d;
+    }
+    let mut carry = 0;
+    let mut i = 0;
+    while i < n {
+        (d[i], carry) = adc(d[i], p[i], carry);
+        i += 1;
+    }
+    d
+}
+
+/// Montgomery multiplication: returns `a * b * R^(-1) mod p` (CIOS method).
+fn mont_mul(a: &Limbs, b: &Limbs, params: &FieldParams) -> Limbs {
+    let (n, p) = (params.n, &params.modulus);
+    let mut t = [0u64; MAX_LIMBS + 2];
+    for i in 0..n {
+        let mut carry = 0;
+        for j in 0..n {
+            (t[j], carry) = mac(t[j], a[j], b[i], carry);
+        }
+        (t[n], t[n + 1]) = adc(t[n], carry, 0);
+
+        let m = t[0].wrapping_mul(params.inv);
+        let (_, mut carry) = mac(t[0], m, p[0], 0);
+        for j in 1..n {
+            (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+        }
+        (t[n - 1], carry) = adc(t[n], carry, 0);
+        t[n] = t[n + 1] + carry;
+        t[n + 1] = 0;
+    }
+    let mut res = [0; MAX_LIMBS];
+    res[..n].copy_from_slice(&t[..n]);
+    reduce_once(&res, t[n], p, n)
+}
+
+/// A field element in Montgomery form.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) struct Fe(Limbs);
+
+impl Fe {
+    pub(super) const ZERO: Fe = Fe([0; MAX_LIMBS]);
+
+    /// Converts a canonical value into Montgomery form.
+    pub(super) fn from_canonical(limbs: &Limbs, params: &FieldParams) -> Fe {
+        Fe(mont_mul(limbs, &params.r2, params))
+    }
+
+    /// Converts back into a canonical value.
+    pub(super) fn to_canonical(self, params: &FieldParams) -> Limbs {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+        mont_mul(&self.0, &one, params)
+    }
+
+    pub(super) fn one(params: &FieldParams) -> Fe {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+        Fe::from_canonical(&one, params)
+    }
+
+    pub(super) fn is_zero(self) -> bool {
+        self == Fe::ZERO
+    }
+
+    pub(super) fn add(self, rhs: Fe, params: &FieldParams) -> Fe {
+        Fe(add_mod(&self.0, &rhs.0, &params.modulus, params.n))
+    }
+
+    pub(super) fn sub(self, rhs: Fe, params: &FieldParams) -> Fe {
+        Fe(sub_mod(&self.0, &rhs.0, &params.modulus, params.n))
+    }
+
+    pub(super) fn neg(self, params: &FieldParams) -> Fe {
+        Fe::ZERO.sub(self, params)
+    }
+
+    pub(super) fn mul(self, rhs: Fe, params: &FieldParams) -> Fe {
+        Fe(mont_mul(&self.0, &rhs.0, params))
+    }
+
+    /// Raises `self` to the power of a canonical (not Montgomery) exponent.
+    pub(super) fn pow(self, exp: &Limbs, params: &FieldParams) -> Fe {
+        let mut acc = Fe::one(params);
+        for i in (0..params.n * 64).rev() {
+            acc = acc.mul(acc, params);
+            if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                acc = acc.mul(self, params);
+            }
+        }
+        acc
+    }
+
+    /// Returns the multiplicative inverse, or zero for zero.
+    pub(super) fn inv(self, params: &FieldParams) -> Fe {
+        // The modulus is prime, so by Fermat's little theorem `a^(p - 2) == a^(-1)`.
+        let mut two = [0; MAX_LIMBS];
+        two[0] = 2;
+        let exp = sub_mod(&params.modulus, &two, &[u64::MAX; MAX_LIMBS], params.n);
+        self.pow(&exp, params)
+    }
+}
@@ -0,0 +1,297 @@
+//! Software implementation of field and curve arithmetic.
+//!
+//! On the assigner target field and curve operations are lowered to the native
+//! `GaloisField` and `EllipticCurve` LLVM types. Any other target has no such types,
+//! so there field values are stored as plain integers of the field size, curve values
+//! as pairs of such integers (`x` and then `y`), and the compiler lowers every
+//! operation to a call of one of the lang items below. The only exception is the
+//! field remainder `%`, which is always zero and so is folded by the compiler.
+//!
+//! All values passed to the runtime must be reduced modulo the field modulus.
+
+use crate::mem;
+use crate::panicking::panic_nounwind;
+use crate::ptr;
+
+mod curve;
+mod field;
+
+use curve::{Curve, CurveModel, Point};
+use field::{Fe, FieldParams, Limbs, MAX_LIMBS};
+
+/// Field types supported by the software runtime.
+trait SoftField: Sized {
+    const PARAMS: FieldParams;
+}
+
+/// Curve types supported by the software runtime.
+trait SoftCurve: Sized {
+    /// Field of point coordinates.
+    type Base: SoftField;
+    /// Field of scalars the points may be multiplied by.
+    type Scalar: SoftField;
+
+    const MODEL: CurveModel;
+}
+
+macro_rules! soft_field_impl {
+    ($($t:ty, $n:literal, [$($limb:literal),*])*) => {$(
+        impl SoftField for $t {
+            const PARAMS: FieldParams = {
+                let mut modulus = [0; MAX_LIMBS];
+                let limbs: [u64; $n] = [$($limb),*];
+                let mut i = 0;
+                while i < $n {
+                    modulus[i] = limbs[i];
+                    i += 1;
+                }
+                FieldParams::new(modulus, $n)
+            };
+        }
+    )*}
+}
+
+soft_field_impl! {
+    __zkllvm_field_bls12381_base, 6, [
+        0xb9feffffffffaaab, 0x1eabfffeb153ffff, 0x6730d2a0f6b0f624,
+        0x64774b84f38512bf, 0x4b1ba7b6434bacd7, 0x1a0111ea397fe69a
+    ]
+    __zkllvm_field_bls12381_scalar, 4, [
+        0xffffffff00000001, 0x53bda402fffe5bfe, 0x3339d80809a1d805, 0x73eda753299d7d48
+    ]
+    __zkllvm_field_bn254_base, 4, [
+        0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029
+    ]
+    __zkllvm_field_bn254_scalar, 4, [
+        0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029
+    ]
+    __zkllvm_field_secp256k1_base, 4, [
+        0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff
+    ]
+    __zkllvm_field_secp256k1_scalar, 4, [
+        0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff
+    ]
+    __zkllvm_field_curve25519_base, 4, [
+        0xffffffffffffffed, 0xffffffffffffffff, 0xffffffffffffffff, 0x7fffffffffffffff
+    ]
+    __zkllvm_field_curve25519_scalar, 4, [
+        0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0x0000000000000000, 0x1000000000000000
+    ]
+    __zkllvm_field_pallas_base, 4, [
+        0x992d30ed00000001, 0x224698fc094cf91b, 0x0000000000000000, 0x4000000000000000
+    ]
+    __zkllvm_field_pallas_scalar, 4, [
+        0x8c46eb2100000001, 0x224698fc0994a8dd, 0x0000000000000000, 0x4000000000000000
+    ]
+}
+
+const fn small(value: u64) -> Limbs {
+    let mut limbs = [0; MAX_LIMBS];
+    limbs[0] = value;
+    limbs
+}
+
+impl SoftCurve for __zkllvm_curve_bls12381 {
+    type Base = __zkllvm_field_bls12381_base;
+    type Scalar = __zkllvm_field_bls12381_scalar;
+    const MODEL: CurveModel = CurveModel::Weierstrass { b: small(4) };
+}
+
+impl SoftCurve for __zkllvm_curve_bn254 {
+    type Base = __zkllvm_field_bn254_base;
+    type Scalar = __zkllvm_field_bn254_scalar;
+    const MODEL: CurveModel = CurveModel::Weierstrass { b: small(3) };
+}
+
+impl SoftCurve for __zkllvm_curve_secp256k1 {
+    type Base = __zkllvm_field_secp256k1_base;
+    type Scalar = __zkllvm_field_secp256k1_scalar;
+    const MODEL: CurveModel = CurveModel::Weierstrass { b: small(7) };
+}
+
+impl SoftCurve for __zkllvm_curve_curve25519 {
+    type Base = __zkllvm_field_curve25519_base;
+    type Scalar = __zkllvm_field_curve25519_scalar;
+    const MODEL: CurveModel = CurveModel::TwistedEdwards {
+        d: [0x75eb4dca135978a3, 0x00700a4d4141d8ab, 0x8cc740797779e898, 0x52036cee2b6ffe73, 0, 0],
+    };
+}
+
+impl SoftCurve for __zkllvm_curve_pallas {
+    type Base = __zkllvm_field_pallas_base;
+    type Scalar = __zkllvm_field_pallas_scalar;
+    const MODEL: CurveModel = CurveModel::Weierstrass { b: small(5) };
+}
+
+impl SoftCurve for __zkllvm_curve_vesta {
+    type Base = __zkllvm_field_pallas_scalar;
+    type Scalar = __zkllvm_field_pallas_base;
+    const MODEL: CurveModel = CurveModel::Weierstrass { b: small(5) };
+}
+
+/// Reads a canonical field value of `size` bytes.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `size` bytes, `size` must not exceed `8 * MAX_LIMBS`.
+unsafe fn read_limbs(ptr: *const u8, size: usize) -> Limbs {
+    let mut bytes = [0u8; 8 * MAX_LIMBS];
+    // SAFETY: the caller guarantees `ptr` is valid for `size` bytes.
+    unsafe { ptr::copy_nonoverlapping(ptr, bytes.as_mut_ptr(), size) };
+    if cfg!(target_endian = "big") {
+        bytes[..size].reverse();
+    }
+    let mut limbs = [0; MAX_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Writes a canonical field value of `size` bytes.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `size` bytes, `size` must not exceed `8 * MAX_LIMBS`.
+unsafe fn write_limbs(ptr: *mut u8, size: usize, limbs: &Limbs) {
+    let mut bytes = [0u8; 8 * MAX_LIMBS];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    if cfg!(target_endian = "big") {
+        bytes[..size].reverse();
+    }
+    // SAFETY: the caller guarantees `ptr` is valid for `size` bytes.
+    unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, size) };
+}
+
+unsafe fn read_field<F: SoftField>(ptr: *const F) -> Fe {
+    // SAFETY: the caller guarantees `ptr` points to a value of `F`.
+    let limbs = unsafe { read_limbs(ptr.cast(), mem::size_of::<F>()) };
+    Fe::from_canonical(&limbs, &F::PARAMS)
+}
+
+unsafe fn write_field<F: SoftField>(ptr: *mut F, value: Fe) {
+    // SAFETY: the caller guarantees `ptr` is valid for writes of `F`.
+    unsafe { write_limbs(ptr.cast(), mem::size_of::<F>(), &value.to_canonical(&F::PARAMS)) }
+}
+
+unsafe fn read_point<C: SoftCurve>(ptr: *const C) -> Point {
+    let ptr = ptr.cast::<C::Base>();
+    // SAFETY: the caller guarantees `ptr` points to a value of `C`, which is laid out
+    // as two values of `C::Base`.
+    unsafe { Point { x: read_field(ptr), y: read_field(ptr.add(1)) } }
+}
+
+unsafe fn write_point<C: SoftCurve>(ptr: *mut C, point: Point) {
+    let ptr = ptr.cast::<C::Base>();
+    // SAFETY: the caller guarantees `ptr` is valid for writes of `C`, which is laid out
+    // as two values of `C::Base`.
+    unsafe {
+        write_field(ptr, point.x);
+        write_field(ptr.add(1), point.y);
+    }
+}
+
+macro_rules! soft_field_binop {
+    ($(
+        $(#[$attr:meta])*
+        $lang:literal, $name:ident, |$a:ident, $b:ident, $p:ident| $body:expr;
+    )*) => {$(
+        $(#[$attr])*
+        #[lang = $lang]
+        #[rustc_nounwind]
+        unsafe fn $name<F: SoftField>(out: *mut F, lhs: *const F, rhs: *const F) {
+            let $p = &F::PARAMS;
+            // SAFETY: the compiler passes pointers to valid field values.
+            unsafe {
+                let ($a, $b) = (read_field(lhs), read_field(rhs));
+                write_field(out, $body);
+            }
+        }
+    )*}
+}
+
+soft_field_binop! {
+    "soft_field_add", soft_field_add, |a, b, p| a.add(b, p);
+    "soft_field_sub", soft_field_sub, |a, b, p| a.sub(b, p);
+    "soft_field_mul", soft_field_mul, |a, b, p| a.mul(b, p);
+    /// Field division, which panics when dividing by zero.
+    "soft_field_div", soft_field_div, |a, b, p| {
+        if b.is_zero() {
+            panic_nounwind("attempt to divide by zero");
+        }
+        a.mul(b.inv(p), p)
+    };
+}
+
+#[lang = "soft_field_neg"]
+#[rustc_nounwind]
+unsafe fn soft_field_neg<F: SoftField>(out: *mut F, val: *const F) {
+    // SAFETY: the compiler passes pointers to valid field values.
+    unsafe { write_field(out, read_field(val).neg(&F::PARAMS)) }
+}
+
+#[lang = "soft_curve_add"]
+#[rustc_nounwind]
+unsafe fn soft_curve_add<C: SoftCurve>(out: *mut C, lhs: *const C, rhs: *const C) {
+    let curve = Curve { field: &C::Base::PARAMS, model: &C::MODEL };
+    // SAFETY: the compiler passes pointers to valid curve values.
+    unsafe { write_point(out, curve.add(read_point(lhs), read_point(rhs))) }
+}
+
+#[lang = "soft_curve_sub"]
+#[rustc_nounwind]
+unsafe fn soft_curve_sub<C: SoftCurve>(out: *mut C, lhs: *const C, rhs: *const C) {
+    let curve = Curve { field: &C::Base::PARAMS, model: &C::MODEL };
+    // SAFETY: the compiler passes pointers to valid curve values.
+    unsafe { write_point(out, curve.sub(read_point(lhs), read_point(rhs))) }
+}
+
+#[lang = "soft_curve_neg"]
+#[rustc_nounwind]
+unsafe fn soft_curve_neg<C: SoftCurve>(out: *mut C, val: *const C) {
+    let curve = Curve { field: &C::Base::PARAMS, model: &C::MODEL };
+    // SAFETY: the compiler passes pointers to valid curve values.
+    unsafe { write_point(out, curve.neg(read_point(val))) }
+}
+
+#[lang = "soft_curve_mul"]
+#[rustc_nounwind]
+unsafe fn soft_curve_mul<C: SoftCurve>(out: *mut C, point: *const C, scalar: *const C::Scalar) {
+    let curve = Curve { field: &C::Base::PARAMS, model: &C::MODEL };
+    // SAFETY: the compiler passes pointers to valid curve and field values.
+    unsafe {
+        let scalar = read_limbs(scalar.cast(), mem::size_of::<C::Scalar>());
+        write_point(out, curve.mul(read_point(point), &scalar));
+    }
+}
+
+/// Multiplication by the inverse of the scalar, which panics when dividing by zero.
+#[lang = "soft_curve_div"]
+#[rustc_nounwind]
+unsafe fn soft_curve_div<C: SoftCurve>(out: *mut C, point: *const C, scalar: *const C::Scalar) {
+    let curve = Curve { field: &C::Base::PARAMS, model: &C::MODEL };
+    // SAFETY: the compiler passes pointers to valid curve and field values.
+    unsafe {
+        let scalar = read_field(scalar);
+        if scalar.is_zero() {
+            panic_nounwind("attempt to divide by zero");
+        }
+        let scalar = scalar.inv(&C::Scalar::PARAMS).to_canonical(&C::Scalar::PARAMS);
+        write_point(out, curve.mul(read_point(point), &scalar));
+    }
+}
+
+/// Checks that `(x, y)` is a point of the curve `C`.
+///
+/// This replaces the `assigner_is_on_curve_*` intrinsics outside the assigner, so unlike
+/// the functions above it takes the same arguments as the intrinsics.
+#[lang = "zk_is_on_curve"]
+#[rustc_nounwind]
+fn is_on_curve<C: SoftCurve>(x: C::Base, y: C::Base) -> bool {
+    let curve = Curve { field: &C::Base::PARAMS, model: &C::MODEL };
+    // SAFETY: the references point to valid field values.
+    let point = unsafe { Point { x: read_field(&x), y: read_field(&y) } };
+    curve.is_on_curve(point)
+}
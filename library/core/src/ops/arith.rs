@@ -111,17 +111,37 @@ fn add(self, other: $t) -> $t { self + other }
 #[cfg(not(bootstrap))]
 add_impl! {
     __zkllvm_curve_bls12381
+    __zkllvm_curve_bn254
+    __zkllvm_curve_secp256k1
     __zkllvm_curve_curve25519
     __zkllvm_curve_pallas
     __zkllvm_curve_vesta
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Add for __zkllvm_curve_bls12381_g2 {
+    type Output = __zkllvm_curve_bls12381_g2;
+
+    #[inline]
+    fn add(self, other: __zkllvm_curve_bls12381_g2) -> __zkllvm_curve_bls12381_g2 {
+        self.add_point(other)
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_binop! { impl Add, add for __zkllvm_curve_bls12381_g2, __zkllvm_curve_bls12381_g2 }
+
 /// The subtraction operator `-`.
 ///
 /// Note that `Rhs` is `Self` by default, but this is not mandatory. For
@@ -233,17 +253,37 @@ fn sub(self, other: $t) -> $t { self - other }
 #[cfg(not(bootstrap))]
 sub_impl! {
     __zkllvm_curve_bls12381
+    __zkllvm_curve_bn254
+    __zkllvm_curve_secp256k1
     __zkllvm_curve_curve25519
     __zkllvm_curve_pallas
     __zkllvm_curve_vesta
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Sub for __zkllvm_curve_bls12381_g2 {
+    type Output = __zkllvm_curve_bls12381_g2;
+
+    #[inline]
+    fn sub(self, other: __zkllvm_curve_bls12381_g2) -> __zkllvm_curve_bls12381_g2 {
+        self.add_point(other.neg_point())
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_binop! { impl Sub, sub for __zkllvm_curve_bls12381_g2, __zkllvm_curve_bls12381_g2 }
+
 /// The multiplication operator `*`.
 ///
 /// Note that `Rhs` is `Self` by default, but this is not mandatory.
@@ -377,12 +417,30 @@ fn mul(self, other: $t) -> $t { self * other }
 mul_impl! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Mul for __zkllvm_field_bls12381_gt {
+    type Output = __zkllvm_field_bls12381_gt;
+
+    #[inline]
+    fn mul(self, other: __zkllvm_field_bls12381_gt) -> __zkllvm_field_bls12381_gt {
+        crate::intrinsics::assigner_gt_mul(self, other)
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_binop! { impl Mul, mul for __zkllvm_field_bls12381_gt, __zkllvm_field_bls12381_gt }
+
 #[cfg(not(bootstrap))]
 macro_rules! mul_curve_impl {
     ($($t:ty, $s:ty)*) => ($(
@@ -413,11 +471,41 @@ fn mul(self, other: $t) -> $t { self * other }
 #[cfg(not(bootstrap))]
 mul_curve_impl! {
     __zkllvm_curve_bls12381, __zkllvm_field_bls12381_scalar
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_scalar
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_scalar
     __zkllvm_curve_curve25519, __zkllvm_field_curve25519_scalar
     __zkllvm_curve_pallas, __zkllvm_field_pallas_scalar
     __zkllvm_curve_vesta, __zkllvm_field_pallas_base
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Mul<__zkllvm_field_bls12381_scalar> for __zkllvm_curve_bls12381_g2 {
+    type Output = __zkllvm_curve_bls12381_g2;
+
+    #[inline]
+    fn mul(self, other: __zkllvm_field_bls12381_scalar) -> __zkllvm_curve_bls12381_g2 {
+        self.mul_scalar(other)
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_binop! { impl Mul, mul for __zkllvm_curve_bls12381_g2, __zkllvm_field_bls12381_scalar }
+
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Mul<__zkllvm_curve_bls12381_g2> for __zkllvm_field_bls12381_scalar {
+    type Output = __zkllvm_curve_bls12381_g2;
+
+    #[inline]
+    fn mul(self, other: __zkllvm_curve_bls12381_g2) -> __zkllvm_curve_bls12381_g2 {
+        other.mul_scalar(self)
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_binop! { impl Mul, mul for __zkllvm_field_bls12381_scalar, __zkllvm_curve_bls12381_g2 }
+
 /// The division operator `/`.
 ///
 /// Note that `Rhs` is `Self` by default, but this is not mandatory.
@@ -578,8 +666,7 @@ fn div(self, other: $t) -> $t { self / other }
 #[cfg(not(bootstrap))]
 macro_rules! div_impl_field {
     ($($t:ty)*) => ($(
-        /// This operation rounds towards zero, truncating any
-        /// fractional part of the exact result.
+        /// This operation multiplies `self` by the multiplicative inverse of `other`.
         ///
         /// # Panics
         ///
@@ -600,6 +687,10 @@ fn div(self, other: $t) -> $t { self / other }
 div_impl_field! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
@@ -624,6 +715,8 @@ fn div(self, other: $s) -> $t { self / other }
 #[cfg(not(bootstrap))]
 div_curve_impl! {
     __zkllvm_curve_bls12381, __zkllvm_field_bls12381_scalar
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_scalar
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_scalar
     __zkllvm_curve_curve25519, __zkllvm_field_curve25519_scalar
     __zkllvm_curve_pallas, __zkllvm_field_pallas_scalar
     __zkllvm_curve_vesta, __zkllvm_field_pallas_base
@@ -748,12 +841,11 @@ fn rem(self, other: $t) -> $t { self % other }
 #[cfg(not(bootstrap))]
 macro_rules! rem_impl_field {
     ($($t:ty)*) => ($(
-        /// This operation satisfies `n % d == n - (n / d) * d`. The
-        /// result has the same sign as the left operand.
+        /// Division in a field is exact: every non-zero element divides any
+        /// other one, so the remainder is always zero.
         ///
-        /// # Panics
-        ///
-        /// This operation will panic if `other == 0`.
+        /// Outside the assigner the compiler folds this operation to zero and
+        /// never calls the software runtime. It does not panic if `other == 0`.
         #[stable(feature = "rust1", since = "1.0.0")]
         impl Rem for $t {
             type Output = $t;
@@ -770,6 +862,10 @@ fn rem(self, other: $t) -> $t { self % other }
 rem_impl_field! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
@@ -871,17 +967,37 @@ fn neg(self) -> $t { -self }
 #[cfg(not(bootstrap))]
 neg_impl_field! {
     __zkllvm_curve_bls12381
+    __zkllvm_curve_bn254
+    __zkllvm_curve_secp256k1
     __zkllvm_curve_curve25519
     __zkllvm_curve_pallas
     __zkllvm_curve_vesta
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl Neg for __zkllvm_curve_bls12381_g2 {
+    type Output = __zkllvm_curve_bls12381_g2;
+
+    #[inline]
+    fn neg(self) -> __zkllvm_curve_bls12381_g2 {
+        self.neg_point()
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_unop! { impl Neg, neg for __zkllvm_curve_bls12381_g2 }
+
 /// The addition assignment operator `+=`.
 ///
 /// # Examples
@@ -951,17 +1067,35 @@ fn add_assign(&mut self, other: $t) { *self += other }
 #[cfg(not(bootstrap))]
 add_assign_impl! {
     __zkllvm_curve_bls12381
+    __zkllvm_curve_bn254
+    __zkllvm_curve_secp256k1
     __zkllvm_curve_curve25519
     __zkllvm_curve_pallas
     __zkllvm_curve_vesta
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "op_assign_traits", since = "1.8.0")]
+impl AddAssign for __zkllvm_curve_bls12381_g2 {
+    #[inline]
+    fn add_assign(&mut self, other: __zkllvm_curve_bls12381_g2) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_op_assign! { impl AddAssign, add_assign for __zkllvm_curve_bls12381_g2, __zkllvm_curve_bls12381_g2 }
+
 /// The subtraction assignment operator `-=`.
 ///
 /// # Examples
@@ -1031,17 +1165,35 @@ fn sub_assign(&mut self, other: $t) { *self -= other }
 #[cfg(not(bootstrap))]
 sub_assign_impl! {
     __zkllvm_curve_bls12381
+    __zkllvm_curve_bn254
+    __zkllvm_curve_secp256k1
     __zkllvm_curve_curve25519
     __zkllvm_curve_pallas
     __zkllvm_curve_vesta
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "op_assign_traits", since = "1.8.0")]
+impl SubAssign for __zkllvm_curve_bls12381_g2 {
+    #[inline]
+    fn sub_assign(&mut self, other: __zkllvm_curve_bls12381_g2) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_op_assign! { impl SubAssign, sub_assign for __zkllvm_curve_bls12381_g2, __zkllvm_curve_bls12381_g2 }
+
 /// The multiplication assignment operator `*=`.
 ///
 /// # Examples
@@ -1103,12 +1255,28 @@ fn mul_assign(&mut self, other: $t) { *self *= other }
 mul_assign_impl! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
     __zkllvm_field_pallas_scalar
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "op_assign_traits", since = "1.8.0")]
+impl MulAssign for __zkllvm_field_bls12381_gt {
+    #[inline]
+    fn mul_assign(&mut self, other: __zkllvm_field_bls12381_gt) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_op_assign! { impl MulAssign, mul_assign for __zkllvm_field_bls12381_gt, __zkllvm_field_bls12381_gt }
+
 #[cfg(not(bootstrap))]
 macro_rules! mul_assign_curve_impl {
     ($($t:ty, $s:ty)+) => ($(
@@ -1126,11 +1294,25 @@ fn mul_assign(&mut self, other: $s) { *self *= other }
 #[cfg(not(bootstrap))]
 mul_assign_curve_impl! {
     __zkllvm_curve_bls12381, __zkllvm_field_bls12381_scalar
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_scalar
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_scalar
     __zkllvm_curve_curve25519, __zkllvm_field_curve25519_scalar
     __zkllvm_curve_pallas, __zkllvm_field_pallas_scalar
     __zkllvm_curve_vesta, __zkllvm_field_pallas_base
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "op_assign_traits", since = "1.8.0")]
+impl MulAssign<__zkllvm_field_bls12381_scalar> for __zkllvm_curve_bls12381_g2 {
+    #[inline]
+    fn mul_assign(&mut self, other: __zkllvm_field_bls12381_scalar) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(not(bootstrap))]
+forward_ref_op_assign! { impl MulAssign, mul_assign for __zkllvm_curve_bls12381_g2, __zkllvm_field_bls12381_scalar }
+
 /// The division assignment operator `/=`.
 ///
 /// # Examples
@@ -1191,6 +1373,10 @@ fn div_assign(&mut self, other: $t) { *self /= other }
 div_assign_impl! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
@@ -1213,6 +1399,8 @@ fn div_assign(&mut self, other: $s) { *self /= other }
 #[cfg(not(bootstrap))]
 div_assign_curve_impl! {
     __zkllvm_curve_bls12381, __zkllvm_field_bls12381_scalar
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_scalar
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_scalar
     __zkllvm_curve_curve25519, __zkllvm_field_curve25519_scalar
     __zkllvm_curve_pallas, __zkllvm_field_pallas_scalar
     __zkllvm_curve_vesta, __zkllvm_field_pallas_base
@@ -1282,6 +1470,10 @@ fn rem_assign(&mut self, other: $t) { *self %= other }
 rem_assign_impl! {
     __zkllvm_field_bls12381_base
     __zkllvm_field_bls12381_scalar
+    __zkllvm_field_bn254_base
+    __zkllvm_field_bn254_scalar
+    __zkllvm_field_secp256k1_base
+    __zkllvm_field_secp256k1_scalar
     __zkllvm_field_curve25519_base
     __zkllvm_field_curve25519_scalar
     __zkllvm_field_pallas_base
@@ -0,0 +1,294 @@
+use crate::fmt::{Debug, Display};
+use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Elements of a finite field.
+///
+/// Implemented by all built-in field types, so that gadgets can be written once
+/// for any of them.
+#[stable(feature = "rust1", since = "1.0.0")]
+pub trait Field:
+    Copy
+    + Default
+    + Debug
+    + Display
+    + Eq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// The additive identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    const ONE: Self;
+
+    /// Returns the multiplicative inverse of the element,
+    /// or `None` if the element is zero.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn inverse(self) -> Option<Self>;
+
+    /// Raises the element to the power of `exp`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn pow(self, exp: u128) -> Self;
+
+    /// Returns `true` if the element is zero.
+    #[inline]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    /// Squares the element.
+    #[inline]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn square(self) -> Self {
+        self * self
+    }
+
+    /// Doubles the element.
+    #[inline]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn double(self) -> Self {
+        self + self
+    }
+}
+
+/// Elements of a prime field, which have a canonical integer value below the modulus.
+#[stable(feature = "rust1", since = "1.0.0")]
+pub trait PrimeField: Field + From<u64> + From<u128> {
+    /// Byte array holding the canonical value of an element.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    type Repr: Copy + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// The field modulus as little-endian bytes.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    const MODULUS: Self::Repr;
+
+    /// The number of significant bits of the field modulus.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    const BITS: u32;
+
+    /// Returns the canonical value of the element as little-endian bytes.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn to_le_bytes(self) -> Self::Repr;
+
+    /// Returns the canonical value of the element as big-endian bytes.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn to_be_bytes(self) -> Self::Repr;
+
+    /// Creates an element from its canonical value as little-endian bytes.
+    ///
+    /// Returns `None` if the value is not less than the modulus.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn from_le_bytes(bytes: Self::Repr) -> Option<Self>;
+
+    /// Creates an element from its canonical value as big-endian bytes.
+    ///
+    /// Returns `None` if the value is not less than the modulus.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn from_be_bytes(bytes: Self::Repr) -> Option<Self>;
+}
+
+/// Points of an elliptic curve group.
+///
+/// Implemented by all built-in curve types. Points are multiplied by elements of
+/// the scalar field of the group.
+#[stable(feature = "rust1", since = "1.0.0")]
+pub trait CurveGroup:
+    Copy
+    + Default
+    + Debug
+    + Display
+    + Eq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign<Self::Scalar>
+{
+    /// Field of point coordinates.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    type Base: PrimeField;
+
+    /// Field of point multipliers.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    type Scalar: PrimeField;
+
+    /// Returns the neutral element of the group.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn zero() -> Self;
+
+    /// Returns the generator of the group.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn generator() -> Self;
+
+    /// Creates a point from its coordinates.
+    ///
+    /// # Safety
+    ///
+    /// The coordinates must satisfy the curve equation.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    unsafe fn from_coordinates(x: Self::Base, y: Self::Base) -> Self;
+
+    /// Creates a point from its coordinates, checking that they satisfy the curve equation.
+    ///
+    /// Returns `None` if `(x, y)` is not a point of the curve.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn try_from_coordinates(x: Self::Base, y: Self::Base) -> Option<Self>;
+
+    /// Returns the affine coordinates `(x, y)` of the point.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn to_affine(self) -> (Self::Base, Self::Base);
+
+    /// Returns the point added to itself.
+    #[inline]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn double(self) -> Self {
+        self + self
+    }
+
+    /// Returns `true` if the point is the neutral element.
+    #[inline]
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn is_zero(self) -> bool {
+        self == Self::zero()
+    }
+
+    /// Multi-scalar multiplication: returns the sum of `points[i] * scalars[i]`.
+    ///
+    /// Built-in curves compute it with a single `assigner_msm_*` intrinsic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    fn msm(points: &[Self], scalars: &[Self::Scalar]) -> Self {
+        assert_eq!(points.len(), scalars.len(), "msm operands have different lengths");
+        let mut acc = Self::zero();
+        for (&point, &scalar) in points.iter().zip(scalars) {
+            acc += point * scalar;
+        }
+        acc
+    }
+}
+
+macro_rules! field_impl {
+    ($($t:ty, $size:literal;)*) => {$(
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl Field for $t {
+            const ZERO: Self = <$t>::ZERO;
+            const ONE: Self = <$t>::ONE;
+
+            #[inline]
+            fn inverse(self) -> Option<Self> {
+                <$t>::inverse(self)
+            }
+
+            #[inline]
+            fn pow(self, exp: u128) -> Self {
+                <$t>::pow(self, exp)
+            }
+        }
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl PrimeField for $t {
+            type Repr = [u8; $size];
+
+            const MODULUS: Self::Repr = <$t>::MODULUS;
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Repr {
+                <$t>::to_le_bytes(self)
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Repr {
+                <$t>::to_be_bytes(self)
+            }
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Repr) -> Option<Self> {
+                <$t>::from_le_bytes(bytes)
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Repr) -> Option<Self> {
+                <$t>::from_be_bytes(bytes)
+            }
+        }
+    )*}
+}
+
+field_impl! {
+    __zkllvm_field_bls12381_base, 48;
+    __zkllvm_field_bls12381_scalar, 32;
+    __zkllvm_field_bn254_base, 32;
+    __zkllvm_field_bn254_scalar, 32;
+    __zkllvm_field_secp256k1_base, 32;
+    __zkllvm_field_secp256k1_scalar, 32;
+    __zkllvm_field_curve25519_base, 32;
+    __zkllvm_field_curve25519_scalar, 32;
+    __zkllvm_field_pallas_base, 32;
+    __zkllvm_field_pallas_scalar, 32;
+}
+
+macro_rules! curve_group_impl {
+    ($($t:ty, $base:ty, $scalar:ty;)*) => {$(
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl CurveGroup for $t {
+            type Base = $base;
+            type Scalar = $scalar;
+
+            #[inline]
+            fn zero() -> Self {
+                <$t>::zero()
+            }
+
+            #[inline]
+            fn generator() -> Self {
+                <$t>::one()
+            }
+
+            #[inline]
+            unsafe fn from_coordinates(x: $base, y: $base) -> Self {
+                // SAFETY: the caller upholds the safety contract.
+                unsafe { <$t>::from_coordinates(x, y) }
+            }
+
+            #[inline]
+            fn try_from_coordinates(x: $base, y: $base) -> Option<Self> {
+                <$t>::try_from_coordinates(x, y)
+            }
+
+            #[inline]
+            fn to_affine(self) -> ($base, $base) {
+                <$t>::to_affine(self)
+            }
+
+            #[inline]
+            fn msm(points: &[Self], scalars: &[$scalar]) -> Self {
+                <$t>::msm(points, scalars)
+            }
+        }
+    )*}
+}
+
+curve_group_impl! {
+    __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar;
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_base, __zkllvm_field_bn254_scalar;
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_base, __zkllvm_field_secp256k1_scalar;
+    __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base, __zkllvm_field_curve25519_scalar;
+    __zkllvm_curve_pallas, __zkllvm_field_pallas_base, __zkllvm_field_pallas_scalar;
+    __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar, __zkllvm_field_pallas_base;
+}
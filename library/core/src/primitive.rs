@@ -71,9 +71,24 @@
 pub use __zkllvm_field_bls12381_base;
 #[cfg(not(bootstrap))]
 #[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_field_bls12381_gt;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
 pub use __zkllvm_field_bls12381_scalar;
 #[cfg(not(bootstrap))]
 #[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_field_bn254_base;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_field_bn254_scalar;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_field_secp256k1_base;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_field_secp256k1_scalar;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
 pub use __zkllvm_field_curve25519_base;
 #[cfg(not(bootstrap))]
 #[stable(feature = "core_primitive", since = "1.43.0")]
@@ -90,6 +105,15 @@
 pub use __zkllvm_curve_bls12381;
 #[cfg(not(bootstrap))]
 #[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_curve_bls12381_g2;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_curve_bn254;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
+pub use __zkllvm_curve_secp256k1;
+#[cfg(not(bootstrap))]
+#[stable(feature = "core_primitive", since = "1.43.0")]
 pub use __zkllvm_curve_curve25519;
 #[cfg(not(bootstrap))]
 #[stable(feature = "core_primitive", since = "1.43.0")]
@@ -1268,6 +1268,13 @@ mod prim_usize {}
 #[stable(feature = "rust1", since = "1.0.0")]
 mod prim_zkllvm_field_bls12381_base {}
 
+#[rustc_doc_primitive = "__zkllvm_field_bls12381_gt"]
+//
+/// Bls12381 pairing target group, a subgroup of the degree 12 extension of the base field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_field_bls12381_gt {}
+
 #[rustc_doc_primitive = "__zkllvm_field_bls12381_scalar"]
 //
 /// Bls12381 scalar field.
@@ -1275,6 +1282,34 @@ mod prim_zkllvm_field_bls12381_base {}
 #[stable(feature = "rust1", since = "1.0.0")]
 mod prim_zkllvm_field_bls12381_scalar {}
 
+#[rustc_doc_primitive = "__zkllvm_field_bn254_base"]
+//
+/// Bn254 base field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_field_bn254_base {}
+
+#[rustc_doc_primitive = "__zkllvm_field_bn254_scalar"]
+//
+/// Bn254 scalar field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_field_bn254_scalar {}
+
+#[rustc_doc_primitive = "__zkllvm_field_secp256k1_base"]
+//
+/// Secp256k1 base field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_field_secp256k1_base {}
+
+#[rustc_doc_primitive = "__zkllvm_field_secp256k1_scalar"]
+//
+/// Secp256k1 scalar field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_field_secp256k1_scalar {}
+
 #[rustc_doc_primitive = "__zkllvm_field_curve25519_base"]
 //
 /// Curve25519 base field.
@@ -1310,6 +1345,27 @@ mod prim_zkllvm_field_pallas_scalar {}
 #[stable(feature = "rust1", since = "1.0.0")]
 mod prim_zkllvm_curve_bls12381 {}
 
+#[rustc_doc_primitive = "__zkllvm_curve_bls12381_g2"]
+//
+/// Bls12381 G2 curve, defined over the quadratic extension of the base field.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_curve_bls12381_g2 {}
+
+#[rustc_doc_primitive = "__zkllvm_curve_bn254"]
+//
+/// Bn254 curve.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_curve_bn254 {}
+
+#[rustc_doc_primitive = "__zkllvm_curve_secp256k1"]
+//
+/// Secp256k1 curve.
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+mod prim_zkllvm_curve_secp256k1 {}
+
 #[rustc_doc_primitive = "__zkllvm_curve_curve25519"]
 //
 /// Curve25519 curve.
@@ -0,0 +1,215 @@
+//! BLS12-381 pairing and subgroup checks.
+//!
+//! The extension fields are the tower of `fields::bls12381`. `Fp12` elements are passed
+//! around as their 12 base field coefficients in the nesting order: `c0.c0.c0`,
+//! `c0.c0.c1`, `c0.c1.c0`, ..., `c1.c2.c1`. G2 points are passed as `[x.c0, x.c1, y.c0, y.c1]`.
+//! Both G1 and G2 represent the neutral element as `(0, 1)`.
+//!
+//! This is a reference implementation, which favors simplicity over speed.
+
+use super::repr::CurveRepr;
+use crate::fields::bls12381::{Fp12, Fp2, Fp6};
+
+type Fp = __zkllvm_field_bls12381_base;
+type Fr = __zkllvm_field_bls12381_scalar;
+type G1 = __zkllvm_curve_bls12381;
+
+/// Absolute value of the curve parameter `x = -0xd201000000010000`.
+const X_ABS: u64 = 0xd201000000010000;
+
+/// Order of G1 and G2, little-endian 64-bit limbs.
+const R: [u64; 4] =
+    [0xffffffff00000001, 0x53bda402fffe5bfe, 0x3339d80809a1d805, 0x73eda753299d7d48];
+
+/// `p^2 + 1`, little-endian 64-bit limbs.
+const P2_PLUS_ONE: [u64; 12] = [
+    0x26aa00001c718e3a, 0x7ced6b1d76382eab, 0x162c338362113cfd, 0x66bf91ed3e71b743,
+    0x292e85a87091a049, 0x1d68619c86185c7b, 0xf53149330978ef01, 0x50a62cfd16ddca6e,
+    0x66e59e49349e8bd0, 0xe2dc90e50e7046b4, 0x4bd278eaa22f25e9, 0x02a437a4b8c35fc7,
+];
+
+/// `(p^4 - p^2 + 1) / r`, little-endian 64-bit limbs.
+const HARD_PART: [u64; 20] = [
+    0xe516c3f438e3ba79, 0xfa9912aae208ccf1, 0x905ce937335d5b68, 0xc71a2629b0dea236,
+    0x83774940996754c8, 0x21d160aeb6a1e799, 0x2ed0b283ed237db4, 0x915c97f36c6f1821,
+    0x67f17fcbde783765, 0x2378b9039096d1b7, 0x7988f8761bdc51dc, 0x2076995003fc77a1,
+    0x827eca0ba621315b, 0xe5a72bce8d63cb9f, 0xf68f7764c28b6f8a, 0x2f230063cf081517,
+    0x94506632528d6a9a, 0xd3cde88eeb996ca3, 0xc0bd38c3195c899e, 0x000f686b3d807d01,
+];
+
+/// Iterates over the bits of a little-endian number, starting from the most significant one.
+fn bits_be(limbs: &[u64]) -> impl Iterator<Item = bool> + '_ {
+    (0..limbs.len() * 64).rev().map(|i| (limbs[i / 64] >> (i % 64)) & 1 == 1)
+}
+
+/// Embeds a base field element into `Fp12`.
+fn fp12(c: Fp) -> Fp12 {
+    Fp12::from(Fp6::from(Fp2::from(c)))
+}
+
+/// Raises to the power of a little-endian number.
+fn pow(f: Fp12, exp: &[u64]) -> Fp12 {
+    let mut acc = Fp12::ONE;
+    for bit in bits_be(exp) {
+        acc = acc * acc;
+        if bit {
+            acc = acc * f;
+        }
+    }
+    acc
+}
+
+/// Affine point on the twist `y^2 = x^3 + 4 (u + 1)` over `Fp2`.
+#[derive(Clone, Copy, PartialEq)]
+struct G2 {
+    x: Fp2,
+    y: Fp2,
+}
+
+impl G2 {
+    const ZERO: G2 = G2 { x: Fp2::ZERO, y: Fp2::ONE };
+
+    fn from_coeffs(c: [Fp; 4]) -> G2 {
+        G2 { x: Fp2 { c0: c[0], c1: c[1] }, y: Fp2 { c0: c[2], c1: c[3] } }
+    }
+
+    fn is_on_curve(self) -> bool {
+        let b = Fp2 { c0: 0x4g, c1: 0x4g };
+        self == G2::ZERO || self.y * self.y == self.x * self.x * self.x + b
+    }
+
+    /// Adds two points. Both points must be on the curve.
+    fn add(self, rhs: G2) -> G2 {
+        if self == G2::ZERO {
+            return rhs;
+        }
+        if rhs == G2::ZERO {
+            return self;
+        }
+        let lambda = if self.x != rhs.x {
+            (rhs.y - self.y) / (rhs.x - self.x)
+        } else if self.y == rhs.y && self.y != Fp2::ZERO {
+            let xx = self.x * self.x;
+            (xx + xx + xx) / (self.y + self.y)
+        } else {
+            // Opposite points.
+            return G2::ZERO;
+        };
+        let x = lambda * lambda - self.x - rhs.x;
+        let y = lambda * (self.x - x) - self.y;
+        G2 { x, y }
+    }
+
+    /// Multiplies by a little-endian number.
+    fn mul(self, scalar: &[u64]) -> G2 {
+        let mut acc = G2::ZERO;
+        for bit in bits_be(scalar) {
+            acc = acc.add(acc);
+            if bit {
+                acc = acc.add(self);
+            }
+        }
+        acc
+    }
+}
+
+/// Affine point of the curve `y^2 = x^3 + 4` over `Fp12`, which contains both G1 and
+/// the image of G2.
+#[derive(Clone, Copy)]
+struct G12 {
+    x: Fp12,
+    y: Fp12,
+}
+
+impl G12 {
+    fn from_g1(x: Fp, y: Fp) -> G12 {
+        G12 { x: fp12(x), y: fp12(y) }
+    }
+
+    /// Maps a point of the twist to the curve: `(x, y) -> (x / w^2, y / w^3)`.
+    fn from_g2(q: G2) -> G12 {
+        // `w^6 = u + 1`, so `1 / w^2 = v^2 / (u + 1)` and `1 / w^3 = v w / (u + 1)`.
+        let xi_inv = Fp2::ONE / Fp2 { c0: 0x1g, c1: 0x1g };
+        G12 {
+            x: Fp12 { c0: Fp6 { c2: q.x * xi_inv, ..Fp6::ZERO }, c1: Fp6::ZERO },
+            y: Fp12 { c0: Fp6::ZERO, c1: Fp6 { c1: q.y * xi_inv, ..Fp6::ZERO } },
+        }
+    }
+
+    /// Returns the third intersection of the line with slope `lambda` through `self`
+    /// and the point with abscissa `other_x`, reflected over the `x` axis.
+    fn chord(self, lambda: Fp12, other_x: Fp12) -> G12 {
+        let x = lambda * lambda - self.x - other_x;
+        let y = lambda * (self.x - x) - self.y;
+        G12 { x, y }
+    }
+
+    /// Evaluates at `p` the line with slope `lambda` through `self`.
+    fn line(self, lambda: Fp12, p: G12) -> Fp12 {
+        (p.y - self.y) - lambda * (p.x - self.x)
+    }
+}
+
+/// Computes `f_{x, Q}(P)` for the optimal ate pairing.
+fn miller_loop(p: G12, q: G12) -> Fp12 {
+    let three = fp12(0x3g);
+    let two = fp12(0x2g);
+    let mut f = Fp12::ONE;
+    let mut t = q;
+    for i in (0..63).rev() {
+        let lambda = three * t.x * t.x / (two * t.y);
+        f = f * f * t.line(lambda, p);
+        t = t.chord(lambda, t.x);
+        if (X_ABS >> i) & 1 == 1 {
+            let lambda = (q.y - t.y) / (q.x - t.x);
+            f = f * t.line(lambda, p);
+            t = t.chord(lambda, q.x);
+        }
+    }
+    // `x` is negative.
+    f.conjugate()
+}
+
+/// Raises to the power of `(p^12 - 1) / r`.
+fn final_exponentiation(f: Fp12) -> Fp12 {
+    // `(p^12 - 1) / r = (p^6 - 1) (p^2 + 1) (p^4 - p^2 + 1) / r`.
+    let f = f.conjugate() / f;
+    pow(pow(f, &P2_PLUS_ONE), &HARD_PART)
+}
+
+/// Checks that the point belongs to the prime order subgroup G1.
+pub(super) fn is_in_g1(p: G1) -> bool {
+    let (x, y) = p.coordinates();
+    if (x, y) == (0x0g, 0x1g) {
+        return true;
+    }
+    if y * y != x * x * x + 0x4g {
+        return false;
+    }
+    // Points of G1 are exactly the ones with `[r]P = 0`, `r` itself is zero
+    // in the scalar field, so check `[r - 1]P = -P` instead.
+    let minus_one: Fr = 0x0g - 0x1g;
+    p * minus_one == G1::zero() - p
+}
+
+/// Checks that the point belongs to the prime order subgroup G2.
+pub(super) fn is_in_g2(q: [Fp; 4]) -> bool {
+    let q = G2::from_coeffs(q);
+    q.is_on_curve() && q.mul(&R) == G2::ZERO
+}
+
+/// Computes the optimal ate pairing of a G1 and a G2 point.
+pub(super) fn pairing(p: G1, q: [Fp; 4]) -> [Fp; 12] {
+    let (x, y) = p.coordinates();
+    let q = G2::from_coeffs(q);
+    if (x, y) == (0x0g, 0x1g) || q == G2::ZERO {
+        return Fp12::ONE.coefficients();
+    }
+    let f = miller_loop(G12::from_g1(x, y), G12::from_g2(q));
+    final_exponentiation(f).coefficients()
+}
+
+/// Multiplies two GT elements.
+pub(super) fn gt_mul(a: [Fp; 12], b: [Fp; 12]) -> [Fp; 12] {
+    (Fp12::from_coefficients(a) * Fp12::from_coefficients(b)).coefficients()
+}
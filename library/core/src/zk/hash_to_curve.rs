@@ -0,0 +1,114 @@
+//! Mapping of BLS12-381 base field elements to G1.
+//!
+//! This is `map_to_curve_svdw` of RFC 9380, section 6.6.1, with `Z = -3`, followed by
+//! `clear_cofactor` with `h_eff = 0xd201000000010001`. Together they are `encode_to_curve`
+//! of the `BLS12381G1_XMD:SHA-256_SVDW_NU_` suite, section 8.8.1, except that the input
+//! is already a field element, so there is no `hash_to_field` step.
+//!
+//! The square roots are computed as `x^((p + 1) / 4)`, since `p = 3 mod 4`.
+
+use super::repr::FieldRepr;
+
+type Fp = __zkllvm_field_bls12381_base;
+type Fr = __zkllvm_field_bls12381_scalar;
+type G1 = __zkllvm_curve_bls12381;
+
+/// Curve coefficient `B` of `y^2 = x^3 + B`, the coefficient `A` is zero.
+const B: Fp = 0x4g;
+/// `Z = -3`.
+#[rustfmt::skip]
+const Z: Fp = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaa8g;
+/// `g(Z)`.
+#[rustfmt::skip]
+const C1: Fp = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa94g;
+/// `-Z / 2`.
+#[rustfmt::skip]
+const C2: Fp = 0xd0088f51cbff34d258dd3db21a5d66bb23ba5c279c2895fb39869507b587b120f55ffff58a9ffffdcff7fffffffd557g;
+/// `sqrt(-g(Z) * 3 Z^2)` with `sgn0(C3) = 0`.
+#[rustfmt::skip]
+const C3: Fp = 0xc855b97020fc106fa9c2de78c9f5d835bbc17c0487afe401c9cd0dcff9fd40bdf8033cd3095a4cd124d794808f153aag;
+/// `-4 g(Z) / (3 Z^2)`.
+#[rustfmt::skip]
+const C4: Fp = 0x6bde8333e50911e85400f0953d13fc61090b4c3a76e5a319f7e6f7f108cb197962c97b3cf28bda11d421c71c71c5babg;
+
+/// Effective cofactor of G1, `1 - x` for the curve parameter `x`.
+const H_EFF: Fr = 0xd201000000010001g;
+
+/// `p - 2`, little-endian 64-bit limbs.
+const P_MINUS_2: [u64; 6] = [
+    0xb9feffffffffaaa9, 0x1eabfffeb153ffff, 0x6730d2a0f6b0f624,
+    0x64774b84f38512bf, 0x4b1ba7b6434bacd7, 0x1a0111ea397fe69a,
+];
+
+/// `(p - 1) / 2`, little-endian 64-bit limbs.
+const P_MINUS_1_HALF: [u64; 6] = [
+    0xdcff7fffffffd555, 0x0f55ffff58a9ffff, 0xb39869507b587b12,
+    0xb23ba5c279c2895f, 0x258dd3db21a5d66b, 0x0d0088f51cbff34d,
+];
+
+/// `(p + 1) / 4`, little-endian 64-bit limbs.
+const P_PLUS_1_QUARTER: [u64; 6] = [
+    0xee7fbfffffffeaab, 0x07aaffffac54ffff, 0xd9cc34a83dac3d89,
+    0xd91dd2e13ce144af, 0x92c6e9ed90d2eb35, 0x0680447a8e5ff9a6,
+];
+
+/// Raises to the power of a little-endian number.
+fn pow(x: Fp, exp: &[u64; 6]) -> Fp {
+    let mut acc: Fp = 0x1g;
+    for i in (0..exp.len() * 64).rev() {
+        acc = acc * acc;
+        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+            acc = acc * x;
+        }
+    }
+    acc
+}
+
+/// Inverse of a non-zero element, zero for zero.
+fn inv0(x: Fp) -> Fp {
+    pow(x, &P_MINUS_2)
+}
+
+fn is_square(x: Fp) -> bool {
+    let legendre = pow(x, &P_MINUS_1_HALF);
+    legendre == 0x0g || legendre == 0x1g
+}
+
+/// Parity of the canonical value.
+fn sgn0(x: Fp) -> bool {
+    let mut bytes = [0; Fp::SIZE];
+    x.write_be_bytes(&mut bytes);
+    bytes[Fp::SIZE - 1] & 1 == 1
+}
+
+/// Right-hand side of the curve equation.
+fn g(x: Fp) -> Fp {
+    x * x * x + B
+}
+
+/// Maps `u` to a point of G1.
+pub(super) fn hash_to_curve(u: Fp) -> G1 {
+    let tv1 = u * u * C1;
+    let tv2 = 0x1g + tv1;
+    let tv1 = 0x1g - tv1;
+    let tv3 = inv0(tv1 * tv2);
+    let tv4 = u * tv1 * tv3 * C3;
+    let x1 = C2 - tv4;
+    let x2 = C2 + tv4;
+    let x = if is_square(g(x1)) {
+        x1
+    } else if is_square(g(x2)) {
+        x2
+    } else {
+        let x3 = tv2 * tv2 * tv3;
+        x3 * x3 * C4 + Z
+    };
+    let mut y = pow(g(x), &P_PLUS_1_QUARTER);
+    if sgn0(u) != sgn0(y) {
+        y = -y;
+    }
+    // SAFETY: `g(x)` is a square for one of the three candidates, so `(x, y)` is a point
+    // of the curve.
+    let point = unsafe { G1::from_coordinates(x, y) };
+    point * H_EFF
+}
@@ -0,0 +1,388 @@
+//! Cryptographic primitives for circuits.
+//!
+//! The functions of this module are built on the `assigner_*` intrinsics where the
+//! assigner has a gadget for them, and give the same results on every target.
+
+#![stable(feature = "rust1", since = "1.0.0")]
+
+use crate::intrinsics;
+#[cfg(not(target_arch = "assigner"))]
+use crate::ops::CurveGroup;
+#[cfg(not(target_arch = "assigner"))]
+use crate::panicking::panic_nounwind;
+#[cfg(not(target_arch = "assigner"))]
+use crate::slice;
+#[cfg(not(target_arch = "assigner"))]
+use repr::{CurveRepr, FieldRepr};
+
+#[cfg(not(target_arch = "assigner"))]
+mod bls12_381;
+#[cfg(not(target_arch = "assigner"))]
+mod hash_to_curve;
+#[cfg(not(target_arch = "assigner"))]
+mod msm;
+#[cfg(not(target_arch = "assigner"))]
+mod poseidon;
+#[cfg(not(target_arch = "assigner"))]
+pub(crate) mod repr;
+mod sha2;
+
+type PallasBase = __zkllvm_field_pallas_base;
+type Curve25519 = __zkllvm_curve_curve25519;
+type Curve25519Base = __zkllvm_field_curve25519_base;
+type Curve25519Scalar = __zkllvm_field_curve25519_scalar;
+#[cfg(not(target_arch = "assigner"))]
+type Bls12381Base = __zkllvm_field_bls12381_base;
+#[cfg(not(target_arch = "assigner"))]
+type Bls12381G2 = __zkllvm_curve_bls12381_g2;
+#[cfg(not(target_arch = "assigner"))]
+type Bls12381Gt = __zkllvm_field_bls12381_gt;
+#[cfg(not(target_arch = "assigner"))]
+type Secp256k1 = __zkllvm_curve_secp256k1;
+#[cfg(not(target_arch = "assigner"))]
+type Secp256k1Scalar = __zkllvm_field_secp256k1_scalar;
+
+/// Computes the SHA-256 digest of `data`.
+///
+/// A message of exactly 64 bytes is hashed by the `sha2_256` gadget of the assigner, which
+/// computes the whole hash. Messages of any other length are padded and their blocks are
+/// chained through the `sha2_256_compress` gadget.
+#[must_use]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    match <&[u8; 64]>::try_from(data) {
+        Ok(block) => {
+            let x = [pack(block, 0), pack(block, 1)];
+            let y = [pack(block, 2), pack(block, 3)];
+            unpack(intrinsics::assigner_sha2_256(x, y))
+        }
+        Err(_) => {
+            // The initial hash value of FIPS 180-4, section 5.3.3.
+            let mut state: [PallasBase; 2] =
+                [0x6a09e667bb67ae853c6ef372a54ff53ag, 0x510e527f9b05688c1f83d9ab5be0cd19g];
+            sha2::pad_and_compress::<64, 8>(&[data], |block| {
+                let block = [pack(block, 0), pack(block, 1), pack(block, 2), pack(block, 3)];
+                state = intrinsics::assigner_sha2_256_compress(state, block);
+            });
+            unpack(state)
+        }
+    }
+}
+
+/// Returns the `i`-th 16 bytes of `bytes` as a big-endian 128-bit chunk.
+fn pack(bytes: &[u8], i: usize) -> PallasBase {
+    u128::from_be_bytes(bytes[16 * i..16 * (i + 1)].try_into().unwrap()) as PallasBase
+}
+
+/// Joins big-endian 128-bit chunks into bytes.
+fn unpack(chunks: [PallasBase; 2]) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    for (chunk, value) in bytes.chunks_exact_mut(16).zip(chunks) {
+        chunk.copy_from_slice(&value.truncate_to_u128().to_be_bytes());
+    }
+    bytes
+}
+
+/// Verifies an Ed25519 `signature` of `message` made with `public_key`, as specified
+/// in RFC 8032.
+///
+/// Returns `false` if the public key or the signature is not canonically encoded.
+/// The group equation is checked without the cofactor, `[S]B = R + [k]A`.
+///
+/// The challenge `k` of a 64-byte message is computed by the `sha2_512` gadget of the
+/// assigner, messages of any other length are hashed in software.
+#[must_use]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub fn ed25519_verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    let (r_bytes, s_bytes) = signature.split_at(32);
+    let (Some(a), Some(r)) = (decompress(public_key), decompress(r_bytes.try_into().unwrap()))
+    else {
+        return false;
+    };
+    let Some(s) = Curve25519Scalar::from_le_bytes(s_bytes.try_into().unwrap()) else {
+        return false;
+    };
+
+    let k = match <&[u8; 64]>::try_from(message) {
+        Ok(block) => {
+            let block = [pack(block, 0), pack(block, 1), pack(block, 2), pack(block, 3)];
+            intrinsics::assigner_sha2_512(r, a, block)
+        }
+        Err(_) => {
+            // The digest is a little-endian number, reduce it 128 bits at a time.
+            let digest = sha2::sha512(&[r_bytes, public_key.as_slice(), message]);
+            let shift: Curve25519Scalar = 0x100000000000000000000000000000000g;
+            let mut k = Curve25519Scalar::ZERO;
+            for bytes in digest.rchunks_exact(16) {
+                k = k * shift + u128::from_le_bytes(bytes.try_into().unwrap()) as Curve25519Scalar;
+            }
+            k
+        }
+    };
+
+    Curve25519::one() * s == r + a * k
+}
+
+/// Decodes a Curve25519 point from its RFC 8032 encoding: the `y` coordinate
+/// in little-endian, with the parity of `x` in the most significant bit.
+fn decompress(bytes: &[u8; 32]) -> Option<Curve25519> {
+    let mut y = *bytes;
+    let sign = y[31] >> 7 == 1;
+    y[31] &= 0x7f;
+    let y = Curve25519Base::from_le_bytes(y)?;
+    let x = intrinsics::assigner_curve_decompress_curve25519(y, sign);
+    if (x.truncate_to_u128() & 1 == 1) != sign {
+        return None;
+    }
+    Curve25519::try_from_coordinates(x, y)
+}
+
+// Software implementations of the `assigner_*` intrinsics.
+//
+// The assigner lowers these intrinsics to circuit gadgets. On any other target the
+// compiler calls the lang items below instead, so that circuits can be run and tested
+// natively. The functions take the same arguments as the intrinsics they replace and
+// must produce bit-identical results.
+
+/// Returns the canonical value of a field element used as a 128-bit message chunk.
+#[cfg(not(target_arch = "assigner"))]
+fn chunk(value: PallasBase) -> u128 {
+    match value.to_u128() {
+        Some(value) => value,
+        None => panic_nounwind("sha2 message chunk does not fit into 128 bits"),
+    }
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_exit_check"]
+#[rustc_nounwind]
+fn exit_check(value: bool) {
+    if !value {
+        panic_nounwind("assigner exit check failed");
+    }
+}
+
+/// SHA-256 of two 256-bit blocks, each given as two 128-bit halves, most significant first.
+///
+/// Returns the digest split into the same halves.
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_sha2_256"]
+#[rustc_nounwind]
+fn sha2_256(x1: PallasBase, y1: PallasBase, x2: PallasBase, y2: PallasBase) -> [PallasBase; 2] {
+    let mut message = [0; 64];
+    for (bytes, value) in message.chunks_exact_mut(16).zip([x1, y1, x2, y2]) {
+        bytes.copy_from_slice(&chunk(value).to_be_bytes());
+    }
+    let digest = sha2::sha256(&message);
+    let (high, low) = digest.split_at(16);
+    [
+        PallasBase::from_u128(u128::from_be_bytes(high.try_into().unwrap())),
+        PallasBase::from_u128(u128::from_be_bytes(low.try_into().unwrap())),
+    ]
+}
+
+/// SHA-256 compression function, with the state and the block given as 128-bit chunks,
+/// most significant first.
+///
+/// Returns the new state split into the same chunks.
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_sha2_256_compress"]
+#[rustc_nounwind]
+fn sha2_256_compress(
+    s1: PallasBase,
+    s2: PallasBase,
+    b1: PallasBase,
+    b2: PallasBase,
+    b3: PallasBase,
+    b4: PallasBase,
+) -> [PallasBase; 2] {
+    let mut state = [0; 8];
+    for (words, value) in state.chunks_exact_mut(4).zip([s1, s2]) {
+        for (word, bytes) in words.iter_mut().zip(chunk(value).to_be_bytes().chunks_exact(4)) {
+            *word = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+    }
+    let mut block = [0; 64];
+    for (bytes, value) in block.chunks_exact_mut(16).zip([b1, b2, b3, b4]) {
+        bytes.copy_from_slice(&chunk(value).to_be_bytes());
+    }
+    sha2::compress256(&mut state, &block);
+    let mut halves = [0; 2];
+    for (half, words) in halves.iter_mut().zip(state.chunks_exact(4)) {
+        *half = words.iter().fold(0u128, |acc, &word| (acc << 32) | word as u128);
+    }
+    halves.map(PallasBase::from_u128)
+}
+
+/// SHA-256 of the big-endian encoding of the value.
+///
+/// The digest is read back as a big-endian number, which always fits into the field.
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_sha2_256_bls12381"]
+#[rustc_nounwind]
+fn sha2_256_bls12381(x: Bls12381Base) -> Bls12381Base {
+    let mut bytes = [0; Bls12381Base::SIZE];
+    x.write_be_bytes(&mut bytes);
+    let mut value = [0; Bls12381Base::SIZE];
+    value[Bls12381Base::SIZE - 32..].copy_from_slice(&sha2::sha256(&bytes));
+    Bls12381Base::read_be_bytes(&value)
+}
+
+/// Ed25519 challenge `SHA-512(R || A || M) mod L`.
+///
+/// The points are encoded as in RFC 8032, the message is given as four 128-bit chunks,
+/// which are hashed big-endian.
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_sha2_512"]
+#[rustc_nounwind]
+fn sha2_512(
+    r: __zkllvm_curve_curve25519,
+    a: __zkllvm_curve_curve25519,
+    z1: PallasBase,
+    z2: PallasBase,
+    z3: PallasBase,
+    z4: PallasBase,
+) -> __zkllvm_field_curve25519_scalar {
+    let mut message = [0; 128];
+    let (points, chunks) = message.split_at_mut(64);
+    for (bytes, point) in points.chunks_exact_mut(32).zip([r, a]) {
+        let (x, y) = point.coordinates();
+        let mut x_bytes = [0; 32];
+        x.write_be_bytes(&mut x_bytes);
+        y.write_be_bytes(bytes);
+        bytes.reverse();
+        bytes[31] |= (x_bytes[31] & 1) << 7;
+    }
+    for (bytes, value) in chunks.chunks_exact_mut(16).zip([z1, z2, z3, z4]) {
+        bytes.copy_from_slice(&chunk(value).to_be_bytes());
+    }
+
+    // The digest is a little-endian number, reduce it 128 bits at a time.
+    let digest = sha2::sha512(&[&message]);
+    let shift: __zkllvm_field_curve25519_scalar = 0x100000000000000000000000000000000g;
+    let mut acc: __zkllvm_field_curve25519_scalar = 0x0g;
+    for bytes in digest.rchunks_exact(16) {
+        let value = u128::from_le_bytes(bytes.try_into().unwrap());
+        acc = acc * shift + __zkllvm_field_curve25519_scalar::from_u128(value);
+    }
+    acc
+}
+
+/// Recovers the `x` coordinate of a Curve25519 point from its `y` coordinate and the
+/// parity of `x`, as in RFC 8032, section 5.1.3.
+///
+/// The result is unspecified if there is no such point, the caller has to check it.
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_curve25519_decompress"]
+#[rustc_nounwind]
+fn curve25519_decompress(y: Curve25519Base, sign: bool) -> Curve25519Base {
+    const D: Curve25519Base = 0x52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3g;
+    const SQRT_M1: Curve25519Base =
+        0x2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0g;
+
+    let u = y * y - Curve25519Base::ONE;
+    let v = D * y * y + Curve25519Base::ONE;
+    // The candidate root is `u v^3 (u v^7)^((p - 5) / 8)`, where `(p - 5) / 8 = 2^252 - 3`
+    // is split into `(2^124 - 1) 2^128 + (2^128 - 3)`.
+    let v3 = v * v * v;
+    let w = u * v3 * v3 * v;
+    let mut t = w.pow((1 << 124) - 1);
+    for _ in 0..128 {
+        t = t * t;
+    }
+    let mut x = u * v3 * t * w.pow(u128::MAX - 2);
+    if v * x * x == -u {
+        x = x * SQRT_M1;
+    }
+    if (x.truncate_to_u128() & 1 == 1) != sign { -x } else { x }
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_poseidon"]
+#[rustc_nounwind]
+fn poseidon<F: poseidon::Params>(x1: F, x2: F, x3: F) -> [F; 3] {
+    poseidon::permute([x1, x2, x3])
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_msm"]
+#[rustc_nounwind]
+fn msm<C: CurveGroup>(points: *const C, scalars: *const C::Scalar, len: usize) -> C {
+    // SAFETY: the intrinsic takes pointers to `len` points and scalars.
+    let (points, scalars) =
+        unsafe { (slice::from_raw_parts(points, len), slice::from_raw_parts(scalars, len)) };
+    msm::pippenger(points, scalars)
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_bls12_optimal_ate_pairing"]
+#[rustc_nounwind]
+fn bls12_optimal_ate_pairing(p: __zkllvm_curve_bls12381, q: Bls12381G2) -> Bls12381Gt {
+    let f = bls12_381::pairing(p, intrinsics::assigner_g2_coordinates(q));
+    // SAFETY: the pairing result is an element of the target group.
+    unsafe { intrinsics::assigner_gt_init(f) }
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_hash_to_curve"]
+#[rustc_nounwind]
+fn hash_to_curve(x: Bls12381Base) -> __zkllvm_curve_bls12381 {
+    hash_to_curve::hash_to_curve(x)
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_is_in_g1_check"]
+#[rustc_nounwind]
+fn is_in_g1_check(p: __zkllvm_curve_bls12381) -> bool {
+    bls12_381::is_in_g1(p)
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_is_in_g2_check"]
+#[rustc_nounwind]
+fn is_in_g2_check(q: Bls12381G2) -> bool {
+    bls12_381::is_in_g2(intrinsics::assigner_g2_coordinates(q))
+}
+
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_gt_multiplication"]
+#[rustc_nounwind]
+fn gt_multiplication(x: Bls12381Gt, y: Bls12381Gt) -> Bls12381Gt {
+    let f = bls12_381::gt_mul(
+        intrinsics::assigner_gt_coefficients(x),
+        intrinsics::assigner_gt_coefficients(y),
+    );
+    // SAFETY: the target group is closed under multiplication.
+    unsafe { intrinsics::assigner_gt_init(f) }
+}
+
+/// ECDSA verification, as described in SEC 1, section 4.1.4.
+#[cfg(not(target_arch = "assigner"))]
+#[lang = "zk_ecdsa_verify"]
+#[rustc_nounwind]
+fn ecdsa_verify(
+    key: Secp256k1,
+    hash: Secp256k1Scalar,
+    r: Secp256k1Scalar,
+    s: Secp256k1Scalar,
+) -> bool {
+    if key.is_zero() || r == Secp256k1Scalar::ZERO {
+        return false;
+    }
+    let Some(w) = s.inverse() else {
+        return false;
+    };
+    let point = Secp256k1::one() * (hash * w) + key * (r * w);
+    if point.is_zero() {
+        return false;
+    }
+
+    // Reduce the `x` coordinate modulo the group order, 128 bits at a time.
+    let shift: Secp256k1Scalar = 0x100000000000000000000000000000000g;
+    let mut x = Secp256k1Scalar::ZERO;
+    for bytes in point.x().to_le_bytes().rchunks_exact(16) {
+        let value = u128::from_le_bytes(bytes.try_into().unwrap());
+        x = x * shift + Secp256k1Scalar::from_u128(value);
+    }
+    x == r
+}
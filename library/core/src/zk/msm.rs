@@ -0,0 +1,66 @@
+//! Multi-scalar multiplication with Pippenger's bucket method.
+//!
+//! Scalars are split into windows of `c` bits. For every window, each point is added
+//! to the bucket of its digit, then the buckets are summed with their digits as weights
+//! using running sums. That costs about `n + 2^(c + 1)` additions per window instead of
+//! the `c` additions per point of double-and-add.
+
+use crate::ops::{CurveGroup, PrimeField};
+
+/// The largest window width, which bounds the bucket array kept on the stack.
+const MAX_WINDOW: usize = 8;
+
+/// Number of points processed at a time, their scalars are converted to bytes on the stack.
+/// A chunk this large already gets the largest window.
+const CHUNK: usize = 1 << MAX_WINDOW;
+
+/// Returns `c <= MAX_WINDOW` bits of the little-endian `bytes` starting at bit `start`.
+fn digit(bytes: &[u8], start: usize, c: usize) -> usize {
+    // The window spans at most two bytes.
+    let byte = |i: usize| bytes.get(i).map_or(0, |&byte| byte as usize);
+    let word = byte(start / 8) | byte(start / 8 + 1) << 8;
+    (word >> (start % 8)) & ((1 << c) - 1)
+}
+
+/// Returns the sum of `points[i] * scalars[i]`.
+pub(super) fn pippenger<C: CurveGroup>(points: &[C], scalars: &[C::Scalar]) -> C {
+    let mut bytes = [C::Scalar::ZERO.to_le_bytes(); CHUNK];
+    let mut acc = C::zero();
+    for (points, scalars) in points.chunks(CHUNK).zip(scalars.chunks(CHUNK)) {
+        for (bytes, &scalar) in bytes.iter_mut().zip(scalars) {
+            *bytes = scalar.to_le_bytes();
+        }
+        acc += pippenger_chunk(points, &bytes[..points.len()]);
+    }
+    acc
+}
+
+/// Returns the sum of `points[i] * scalars[i]`, with the scalars given as little-endian bytes.
+fn pippenger_chunk<C: CurveGroup>(points: &[C], scalars: &[<C::Scalar as PrimeField>::Repr]) -> C {
+    // A window of about `log2(n)` bits balances bucket accumulation and summation.
+    let c = ((usize::BITS - points.len().leading_zeros()) as usize).clamp(1, MAX_WINDOW);
+    let bits = C::Scalar::BITS as usize;
+    let mut acc = C::zero();
+    for window in (0..bits.div_ceil(c)).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+        let mut buckets = [C::zero(); (1 << MAX_WINDOW) - 1];
+        for (&point, scalar) in points.iter().zip(scalars) {
+            let digit = digit(scalar.as_ref(), window * c, c);
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+        // `running` holds the sum of the buckets of digits `d` and above when it is
+        // added for `d`, so bucket `d` ends up added `d` times.
+        let mut running = C::zero();
+        let mut sum = C::zero();
+        for &bucket in buckets[..(1 << c) - 1].iter().rev() {
+            running += bucket;
+            sum += running;
+        }
+        acc += sum;
+    }
+    acc
+}
@@ -0,0 +1,54 @@
+//! Poseidon permutation, as specified in <https://eprint.iacr.org/2019/458>.
+//!
+//! The instance is the one of the `llvm.assigner.poseidon` gadget: width 3, the `x^5`
+//! S-box, 8 full and 57 partial rounds. Its round constants and MDS matrix were generated
+//! by the Grain LFSR of the reference parameter script of the paper, so over the
+//! BLS12-381 scalar field it is the `x5_255_3` instance of the reference implementation.
+//! The Pallas base field has the same size, and its instance is generated the same way.
+
+use crate::ops::PrimeField;
+
+mod bls12_381;
+mod pallas;
+
+const WIDTH: usize = 3;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 57;
+const ROUNDS: usize = FULL_ROUNDS + PARTIAL_ROUNDS;
+
+/// A field with a Poseidon instance.
+pub(super) trait Params: PrimeField {
+    /// Round constants, added to the state at the beginning of each round.
+    const ROUND_CONSTANTS: [[Self; WIDTH]; ROUNDS];
+    /// The MDS matrix, the Cauchy matrix `1 / (x_i + y_j)`.
+    const MDS: [[Self; WIDTH]; WIDTH];
+}
+
+/// The `x^5` S-box.
+fn sbox<F: PrimeField>(x: F) -> F {
+    let x2 = x * x;
+    x2 * x2 * x
+}
+
+/// Applies the Poseidon permutation to `state`.
+pub(super) fn permute<F: Params>(mut state: [F; WIDTH]) -> [F; WIDTH] {
+    for (round, constants) in F::ROUND_CONSTANTS.iter().enumerate() {
+        for (value, constant) in state.iter_mut().zip(constants) {
+            *value += *constant;
+        }
+        let partial = (FULL_ROUNDS / 2..FULL_ROUNDS / 2 + PARTIAL_ROUNDS).contains(&round);
+        if partial {
+            state[0] = sbox(state[0]);
+        } else {
+            state = state.map(sbox);
+        }
+        state = crate::array::from_fn(|i| {
+            let mut acc = F::ZERO;
+            for j in 0..WIDTH {
+                acc += F::MDS[i][j] * state[j];
+            }
+            acc
+        });
+    }
+    state
+}
@@ -0,0 +1,354 @@
+//! Poseidon constants for the Pallas base field.
+
+use super::{Params, ROUNDS, WIDTH};
+
+type F = __zkllvm_field_pallas_base;
+
+#[rustfmt::skip]
+impl Params for F {
+    const ROUND_CONSTANTS: [[F; WIDTH]; ROUNDS] = [
+        [
+            0x2ef5299e2077b2392ca874b015120d7e7530f277e06f78ee0b28f33550c68937g,
+            0x0c0981889405b59c384e7dfa49cd4236e2f45ed024488f67c73f51c7c22d8095g,
+            0x0d88548e6296171b26c61ea458288e5a0d048e2fdf5659de62cfca43f1649c82g,
+        ],
+        [
+            0x3371c00f3715d44abce4140202abaaa44995f6f1df12384222f61123faa6b638g,
+            0x1a3c26d755bf65326b03521c94582d91a3ae2c0d8dfb2a345847aece52070ab0g,
+            0x02dbb4709583838c35a118742bf482d257ed4dfb212014c083a6b059adda82b5g,
+        ],
+        [
+            0x0db98520f9b97cbcdb557872f4b7f81567a1be374f60fc4281a6e04079e00c0cg,
+            0x04e9919eb06ff327152cfed30028c5edc667809ce1512e5963329c7040d29350g,
+            0x102800af87fd92eb1dec942469e076602695a1996a4db968bb7f38ddd455db0bg,
+        ],
+        [
+            0x2102aab97ce5bd94ffd5db908bf28b7f8c36671191d4ee9ac1c5f2fae4780579g,
+            0x14387b24d1c0c712bbe720164c4093185fcb546a2a7d481abc94e5b8fb5178b7g,
+            0x1787986ab56e1b56b5443334562b0bc3657d27323b87e3a8485e68ab96d57188g,
+        ],
+        [
+            0x39ef4b00deefe7e7451adda44428aa22074c496de2c9ed67dcf4861da65f543ag,
+            0x0c1f1b492b27539d754cba5e46edc1f1ac1c5696da8eb19416b07420bb321c65g,
+            0x1c4d41a133b97dc467f1f184cf191f331dfc38e79e7e53516c39848c9bd44692g,
+        ],
+        [
+            0x369ea8e699181b1cf88be9205ab840180c9288e67a359dc0dda4ac74cf9768e2g,
+            0x032b5885586212fb235570996d3a4c40f54ff91598a948ec2722ed865b8438a5g,
+            0x03f3178956cfd3e2e6614fb134597d3b3cff0d8a33f3523d825982990c068940g,
+        ],
+        [
+            0x3126e84dfd67a22bf0ce0d9273d8ad40e6109af5bb2bd78d0ac08a16c6248f74g,
+            0x3527888062f1e2738d7b928e9af244f0a39011390c2dbbcf56d8e087f4087b6fg,
+            0x37832ba2da93de3643243eba3b9765d75359310617f3fc06d74ac12db57b29c5g,
+        ],
+        [
+            0x0e6f127f479ee6113540d69b25420a2682f07b23e799566b091a1c891fa224bag,
+            0x39c815508d2995bb8ae5035472944706e900b2fb16d5a779fdfff82306f37dbbg,
+            0x221807cb4909d549c546a734ad2cd7f60a69e816ace98fad830452a44a343188g,
+        ],
+        [
+            0x2766a1e33038004da58bce78722380b22b13b0aecb87f38659f3035e1336b53fg,
+            0x11b5e993e6a9cdc3b5d2f5336dc9bad5074b661537ff890b1babd7f53cada9e3g,
+            0x29576176f9a5a10e3d0a2c59af26b51f4c5fc86ec59c0f2492deb60ad49eddcdg,
+        ],
+        [
+            0x2fb360d959be4aa871e071764a5e41eb264d04f0289f098723b69bab09f4d1a6g,
+            0x03f46b4c3c77957cb595ed61fe13f9e8739a5009311142b69c1e8c07ae250f47g,
+            0x3bd670c3ce88ea43f254d61c2a9b56d6a4dff19ab5c4d28989d271f3dd6bee25g,
+        ],
+        [
+            0x2fd2ed0ba1135575995d15061ddb487f2c5c6005feed28d8a01b9d7bee361a1bg,
+            0x35b716410b3c9374d42e7d39eaca316b6568f0a14cb14d519967aa3ff9970aacg,
+            0x231c6db056e47a01c192db40e586ededc929b564667377a10bd1465f3852811fg,
+        ],
+        [
+            0x0576cf2418d6bd88f352bb26da1066637575f85688cdb981c7787f8094e5a71ag,
+            0x16672be70221dfa20aa110bdce12e1e66ab171db4eadd9935baa0e3aa49e437ag,
+            0x1e51c73bc2aeb9e877d9c2c18f17b03ea3dfcc04adfc649780ce4bcbc43b0b69g,
+        ],
+        [
+            0x1271c830507a211c8e2ebdfb372f79c8a42a9e84e4fdb0dcb35d55e4d155e169g,
+            0x2e5454b258106b63f0ab01924767b4aecce371202abc28a260adc45f35570b9dg,
+            0x0c0f3630409a2242a39ebb33c5c7cf18965b8932621aab4ca2c315d4441b6987g,
+        ],
+        [
+            0x0d1bd84a786a990adf88b51f253bd9032cb50ce4682bafe103893af36d5e75dcg,
+            0x30ce425059810dd94aae2f255666b0fe8bc52ff701c385c43a998926539dd401g,
+            0x395a1e753153b56d1a9ec2ca73099425e446dfa668dc73da2ea311abe5e3d96dg,
+        ],
+        [
+            0x1065b71b135e4feb8b3cba3c252daa084cb5624b0ba76f48f6a03854bfdbcaccg,
+            0x3d5f53bd162f053f045547952a06bc83bc413e17957977e359d9bd4c8883203dg,
+            0x05f467a5081bd3479d6b49f697b0a75d264b42b95b2bed475cd58ffd05322d85g,
+        ],
+        [
+            0x23c293275e282bf15cdbffae1f00a2712e76aa6d62820542159e9d6f115df3b8g,
+            0x3757e7009ca9bec8bba29308b9922354eeeff3beb4113174bf8cde584722d31bg,
+            0x046dcfa2d6d655c7c551f7440772b056e7d3f2c65ac52e4496c4fc753130ad45g,
+        ],
+        [
+            0x2caa8aae247ef83e63dbe8e5efc89d7d28ffd8bf7a5331e245af8aebc872a759g,
+            0x0df424bdf3b0c60395cd7380029a633692b933250b79371e09122c8c39aa1301g,
+            0x2d012e3e811cf4b88aed6f38d5cc8c3456dbae1741f501574321906efb474930g,
+        ],
+        [
+            0x1bb9b23d6805ed1179a1dad95740513dcea114185a8ed34e17dc8077dc830916g,
+            0x0fab922a838c55af1e2349b1e50b56d0690c200d0f2318aad4b7bd8a38a47f61g,
+            0x03e7812afd6c480faef03c3beadfb882923a743a4e60e58a259e7ed4598cca97g,
+        ],
+        [
+            0x0ae97e00a91a4e761815fde0e9506629373ef7ce765ecb1bc7ba0ca2decd7d01g,
+            0x18d683776871c1918c2b5c632cb1854dff865c4b1b8bd66e46d2fa2a8d515c34g,
+            0x3597acab641c21dc5475eb8b04b0e2ae91700acad1b543e8c7e69d574eb5a15ag,
+        ],
+        [
+            0x009ab455f6b4c7755da22615073e9839cd12a88d1f9b583d7ad61bde4009b873g,
+            0x09e21d43c56b0abfc26d0fb7a3ebfd3a7743bbeea99ac2b8f61cc23d1c673a12g,
+            0x0c6f0ecaf32a3d60aaebeaf3f8ccb00a10ee19def3836b78fc905bfeaf2b80a9g,
+        ],
+        [
+            0x3518d688407ca0e548165b9796a4279d038720408a3c822dc44ce8974ea8ad8dg,
+            0x27ba9d4584a23881e23aa0340dc266b32b56455c30e6da78b37741de7ac5b185g,
+            0x37d6efb2876f3cba63a60821e50853d0997947b96f633607bb36ded243ded838g,
+        ],
+        [
+            0x14f96acdb291ed2bf98a5bed063f6911598bdff1f6c0219bbefa447ab1918163g,
+            0x3b74a3420d1b988408fe8d8fcb51a81f16f8d17d082da9ba61fbc8031d8ff59bg,
+            0x059f3301178a22026798b07a8578611d7c56c16bfbbe6a058f4e44016aaa172dg,
+        ],
+        [
+            0x10000c75e6e03366bba4f59c68f312becb7ae0c30d4aa141940a7531105ef7e0g,
+            0x375487214c07542fa5b6a5736344466a06c2cb4c1838c9966925cd8c5888c3cag,
+            0x2361aaf969f732be06b159772a097f3518ed9485449edcfd367e289f0964c486g,
+        ],
+        [
+            0x2ddba8679308f327c27023a893c0458d1e73dcd64a39b22b130fd9e4f283f906g,
+            0x3525eb41c2db9cf9cd08652d815d7c91f3294defeee702efedb5f777284cd1fdg,
+            0x0079ae4df49f78b97cb0e3c3f4b225538d4a0c4827e333d27a29398c17c26c9eg,
+        ],
+        [
+            0x1c7f5858eabb1e2b8c3104808dc68ae3de05381fc74704a2afbd2fcc42cdd3c8g,
+            0x156a82f8e5aea455d9c8c436f89c6f9ecbce0ecaafdd13b93f255e075c72ebd0g,
+            0x37c7047032df0027d7bc128e9a107582f25ba0b7387230a05864aee420724703g,
+        ],
+        [
+            0x1c4a903be5dff4440b4f38e56f988cddacc57371aeebb06cb64ab5d21d9562f5g,
+            0x2f875bdd6669a8ff920c3d7bedd74c101541d4b184b7e1bc0b90ddb26902319dg,
+            0x24ce56469aeaa4243053bb62c07100002b8f74c4ac74c350beff0c0be47e5a51g,
+        ],
+        [
+            0x3184262ef10e9b0ab57cfc898fb68342cb86ed6e25e536fa94caa605b4a3caf1g,
+            0x1d5a91b930b89934745ba00bd9094b67f95e41e3778fe0420880e80bbf8078e1g,
+            0x0ddebce4b6ca45d69b2f70c8b54e425615c1aadadccda74e0882eb79c445778fg,
+        ],
+        [
+            0x3dcc6a17e074d0350ffc0e5426e1bb6894e6c958f96f3d7d9c4240b948cde438g,
+            0x03b8aba0ee959a4e51cb5cfc458b0f4ad3a9b59797394c3d3c9eb57adeca2308g,
+            0x0f24cc57f3b2fbf25375c71d71bbb97b2d193fc1a203ccc514c074d461001ec4g,
+        ],
+        [
+            0x3ac9c11890e96a2dcda6405a6c52a47e803d6674e65117f1a8adf701d68cd02ag,
+            0x1f98bdb8dc318e3e2e28cc3d8b85e334f74b57e15b02e1637ae035b04bda3b5cg,
+            0x2ec077dbbc7bf2affe7ddd8b8a7f900f3019cddc8ce55cf9782004f65f51257bg,
+        ],
+        [
+            0x32c377fc988f600a2c2ef5d5376e2e31faf1c2d1a618db011fbfec1ff337568dg,
+            0x0a820d131da844383bdfc1a053d8aceec7f2eb345ab6c21d38e829db8d05861eg,
+            0x264cf7b75095fb96b420fb3f31c064299e78e796e8b3735bd0a186cd3817708dg,
+        ],
+        [
+            0x27d3e47b2f11ada6a9a5d329e00a128c9836be92ee92429ab891e71d11dc29f2g,
+            0x0db0f967487ee52e0836fb7135bce37fbd32887e911de52d0b855a5afac1f770g,
+            0x1c9a155911b36c896475995417197faad870737a9ce5d9d3a5000f5396978e9dg,
+        ],
+        [
+            0x2146d3e371040feba8595049a285944bd45a458dccb059c785c2adf032c8b710g,
+            0x16db9ceb3074a795499a37c20ffc9eaca9b07a5a25824aa6adcdb19fabdff0b9g,
+            0x0dba4abc7f188e33e7f309317b7b9f5c22870ca90bcee7b576dd0b52619a39f6g,
+        ],
+        [
+            0x3950231611808399ad3ba5b78cad4c6bed6f364b9346541dfffa4d16366d257eg,
+            0x1a6d8230bb9e8d1af552b9bab8babfe505931dd87e200fc7b3c57160a5bc4ae2g,
+            0x37c3720b132d3a719424e29c37acb7dfbd709ec9497a3162175424bf063c6e18g,
+        ],
+        [
+            0x2b37f70d73366d32d575186d0787fc8ce539b73f83c6e7eaab27be85f4faaaf4g,
+            0x1d8efd6e52d4f936415e5c4814f3366804e2386857a4befa2a53aab21ddb68deg,
+            0x33303b8a8f2d811be65a977907d17d133f3a64c59fe2a9c5c2d4517e3eb390e3g,
+        ],
+        [
+            0x2c1ba860f51e0c2eaf4a9a6bf095c65fab3ee15c145f404fbb0272b5ca14a449g,
+            0x0b0849c7a3adea03a89d101081c9c9f4f66ef917d09c7957584db9a75aec2378g,
+            0x182e66be60686c8c5e6518430845f98924fe8d7d43e628bf75ff52a716371b9cg,
+        ],
+        [
+            0x373b2508c2fca1a288fa4f54a6edf02f2661e664dcf4ff2a74f3d06b1a00ddc4g,
+            0x1735b442b3acaad0bbe630f308e03f1aa6f56bdb029e50c1393533cee1a45c30g,
+            0x22abe8ea470a0372911bcef1367e10aa220491d76caeaa5959feb5d75f4a1f9fg,
+        ],
+        [
+            0x358ac928badd1ef73660bd435a53725fb5339a8478995fee4252db4ab66c359eg,
+            0x1bfdbf64e67ff5975d3e36292443f39e2cf11aec4192113d129a4315f0ab7826g,
+            0x0e5626898ad3536a442d03704f69a11758d2662f328832396f37d5b0e8cf6a13g,
+        ],
+        [
+            0x042e6e8b6a8c7e0d800b6984e8637186b1a1e52c7fe3eaff52001c0e56b7e356g,
+            0x2ba0ed1cd48e7c41cc9babf29615282a8b6a998da7a1c60938577804d118a66dg,
+            0x33986da007912b8ece5449dca561e4a12b814612e13da9b562a9ef0ed4d60c68g,
+        ],
+        [
+            0x09b1eac0bc3f04365e013bd9a69740bfdfd4974eab3fe4434f4fab4d971f52d5g,
+            0x0a83445e64ce72355703b948c071b45032046825ecdbf1fb93fc8fff5a9629c2g,
+            0x3694ddb54723b79e116515c22ee0312be30571677c0e620137660d3901e718d7g,
+        ],
+        [
+            0x35e498a680b14b19dfb3e9ba46b5396ff2e75a6bb72fd00c6e63859bfdb9a7c5g,
+            0x23d048570a85d6e0904af7f804a1c0a5b77c3b00f2fb1b22ceb88ac1b11e84d4g,
+            0x182e5321536a111d15944622ee42ec1afae5127e627644c4d01b94f808273342g,
+        ],
+        [
+            0x09f8b5e1f2ef8db47a51cc455c85349312cd203a7509c9bd680a1fe7dd01aa36g,
+            0x12f4d9fae5564aa287ff5e46b21b895c5dc00dd366d62846a85d9abeb0e21e31g,
+            0x33bb22a0b956e43e91903adae80ec4cbf2a1cf003877af4216e29445ea0edbecg,
+        ],
+        [
+            0x239e1c12d3b9d58820c608230ab543536d7a60ab2a2afc48428510d9b116523ag,
+            0x049d0ecd8f91b28c4cf3ebaa4ec3d9ac944bc7bf8848ef86e3b746a4aa0a62dag,
+            0x2127ae73429b6b5955a76425d2dd968bbf399cfc7c6ea762b66579bd750e2edfg,
+        ],
+        [
+            0x051223494a1015c282257350d58826eda180384d9e54f258754c3c6907e617b2g,
+            0x08ad10dc701c8299accba1f22387c9721898b224b3ad5d305d4a7d92d4439b0bg,
+            0x3c6c91c4f7627bb416e8276690fbb1e55e06a9d6abb3fdbb9c17d0880841aebfg,
+        ],
+        [
+            0x29013810b34c948c1e99e7cbd8117aaa7051ad883042d3ff6027ea938684ea04g,
+            0x248eb6376d6627c14f96e38c7dd04fb26ad8aa8465c85e6daf85dc2fa604492dg,
+            0x10a375976f09aac2d25cbbdc8b3e33ea4504db4ef1fac9e979803ce9216bd79ag,
+        ],
+        [
+            0x36deea71114ce13ba013909eb7f06d4deae2229830b946aeb6974cd538ecd2c1g,
+            0x122a9cfd68ede095f3c08ceaadbbef9347d7770e608845a9a6ecd6e5e10d9337g,
+            0x1c5a00ca485d10e8d58a66b90b4e3b14f26456e41c76b1321349f91804188f53g,
+        ],
+        [
+            0x294db46e56ff68fbc523d6b13f3b8299f77a09b11e1139f98073a4c3bfc7ef6cg,
+            0x3b83a8b53c4cd1b6e308d802c35a498a69269e9f9c402f0a0d3efbf867d7b3edg,
+            0x326a7902178334b193e2330d20ea317c09d660bcc08accc6dd359e581ff3c7dbg,
+        ],
+        [
+            0x009d4cb3a5725da58c5c36af3942b3b4408076da325f186324b630a123ee1428g,
+            0x0aeab21b4c7c20250f0b380cf44550318b717741d27ff9c50135707009c95466g,
+            0x1c4b80640164f3fd57339f5bd83fd4cd7ca92e92663817663567d0a8c143ae42g,
+        ],
+        [
+            0x05f1b25e709b87f81637593f7d44f74113be47c135d41e3cc440053e5e438ea7g,
+            0x32f2e1a6f2f083924678e3c5378456fdb616f3d77220be60fdbf8a0f210f9a6fg,
+            0x3714ba6ea099a2337a94321d231c36830167a554d5fd98d4cadf6d442cbd177fg,
+        ],
+        [
+            0x207ca41f955e840d3350383bc8d0d57dbb389cb56f69248cbd6a23803fe28a28g,
+            0x2a37f87a505ea243a10955155ebd86d735902a2430c96f9900a8fecbe71fc99ag,
+            0x26ee66014b81cc142b899d3d0a95fb474d0a407843ae5adef9b5053338eec75dg,
+        ],
+        [
+            0x2a586725b66abcdb26f8eca128cf7d660b78cc1f25aa7a991b3881f5a6464029g,
+            0x38398515404c4822302a315cbdabcac7ea0402f39b074a51be32c8a63fdae090g,
+            0x0b2e1352a14353e26dc9ea66e4e485a3613da3ab9e4c374737d48ddc18f34f86g,
+        ],
+        [
+            0x18615b205deb9aab4c4f6b8a9833b41c5ad4e7a27dba533d56404dad79e45608g,
+            0x147d3bf7d766b247d0cf562e7844373526629342b2a6a5346b1ce71e3cc8f312g,
+            0x04337bd43fb3f82d7e349a45e6f33d42dd15ec5a6851ed076c13eb2f4358a487g,
+        ],
+        [
+            0x29a007d662fe86239f37aa786890216df0dbdd753d0b393526f3ddb9e5f231ecg,
+            0x37e3d7fa62e4c7eaab379f921e6e5f22b36159c4c85a097464010668dbb38d2eg,
+            0x0443452f33d6d377e907ef66939114543d7bd3663caa08c5798253e46e1826e6g,
+        ],
+        [
+            0x301153a756ae5e3fd3f6d1b4dabc91d32c3bde93c724a8caca97f691b12e2133g,
+            0x1836eeda2c30b4c261ba39d1c70bc66eed2517f3246612eecf528f2e7b942033g,
+            0x203239cfa1059ee06a4642dee8b19781d1ebac3e0979fe715ec4de040dbf2d61g,
+        ],
+        [
+            0x1ea9f757c470483852894031f3fea1300c121a8ffdf872c069fbf4ed6ce295b1g,
+            0x23bddeef9557dc655d01b1d0276faf64f50b3e316644bdce30e02b3f782d5d39g,
+            0x20b25b31c62d6e75d5f88a6c1e8597f41ad439e8fd8178ea0073396971ef1b2cg,
+        ],
+        [
+            0x359731f8806234927475292052e7683bbdd8eb3ffc75ced70a024b7bd317294ag,
+            0x1247df99977623d2f659d25fa4c93ff32ad2804966dda406cf25466ba1f09987g,
+            0x00bc2f92a3a8badd9617fdec8a6e8fd0f093b86fe032eab74b18d4fd3de75f91g,
+        ],
+        [
+            0x346c21ec5ce56c5fde0619209251d27cc3a3e4ac827b1e511b4e2042ddb5655ag,
+            0x213e1d84a69b44e240f5729f798475e5843549f6b614035d1e5bedeaabc1deb2g,
+            0x2fdab8c4d1e6492768765f0a44f2142eae551fbe35c3d9baff2a0c7d378ae503g,
+        ],
+        [
+            0x066a1f67c9579ecad5c5f2527c945df8cc8346f6e413a45c1f0497b878f2ffc8g,
+            0x35f332e376f1b814aa282b4dc8f9d101f9a4814cb4a71ed2fe31d721391e07c7g,
+            0x190b02bcf89041135d04089f4b7117a6282a0add47ca5d6d8dd699f644b50c70g,
+        ],
+        [
+            0x2d5b86adca14d770815cdafa181e00e15611401e94b8ff15edab1f2302569298g,
+            0x06350280fb3645db3e88aec20c9fbae2df7b8ce9a7305c3424f86ad1e81f4434g,
+            0x3a48b68a66a2bbc24bcfd39723b7154f6eb91ce60ac260af2fbe59cfc946224dg,
+        ],
+        [
+            0x1b30e394c9f6417e450e9564aee203b24d62b49b4fdf3110dd92cbcaf58a122ag,
+            0x2811c41c90cc79b3f78f406fd2b0c7ee897da08dfca021f6b5510a2675f90e26g,
+            0x06f3b054477bc80daded7b647e333f6baa381746856a226772e056e3b91380ceg,
+        ],
+        [
+            0x3a73c7b0ac1b125cae2f4edd426b1254a48594a946d286c587168cca35b23c6dg,
+            0x3a1ff76dc309173df7639313d3736fb0bcf65289e0968fdfca12e4bec7122479g,
+            0x16b85dcf6b0a32af386f9974458cdc524934cb3c0b2c59dbe5179dc1c9bd78aag,
+        ],
+        [
+            0x14254889e96e86b9e612fe3ab5cfb29802d12e55363ad2217ab74aeeaffa1f2eg,
+            0x3404fc5234ab98232d571da75ae243517d6b1cc62720be211a0ad176eda69b24g,
+            0x0ee418649f6916231be92542ae109bae72efc8b3240e255209e1e09851abe136g,
+        ],
+        [
+            0x01eb0907972bf6a6b697e4a7ec1ba1310cb9592a81da40fc48ef493149533db8g,
+            0x1a5e2bf0471becc2ee06b282c68a455bfc14bb73a84e0f944de4018268c373aeg,
+            0x1003cb9194b1a2c6b3ada537e5476286a0e6cb390f2d02cdbf278a244a9740d7g,
+        ],
+        [
+            0x0316d937f53717d1da52c550490729a900dc114c514ee4c95961b2de5b0477a1g,
+            0x11bbcb247220974678f38b397f32e9f9ee1567c48989cf20b8cb37d030ab9a4dg,
+            0x11c807ec0a1aadba4849b72db9aaeea70a057c20cb2b6d9422241fbfb3c0c218g,
+        ],
+        [
+            0x28c8e9aa08a5bd37273b79ed5c092b70372439880cbdb3c745b647c04f64421bg,
+            0x0c7db18ce9e6f9ff927156277d24acba6682aa9ae85e9c74f2c7dd1e3728e6f2g,
+            0x2c3b1af15d6446c0e4102af4518335317883d15569f4f3e1e1ad5c0a385714c7g,
+        ],
+        [
+            0x3ca50679c50ff7f3f561e76b456b3efd099acf35b4f9bfc78c0e5be8589ee607g,
+            0x0fa14b4f334eb3539237b5839483083a00558edaebfa21d8e14d35f740ac1d06g,
+            0x10a5989861607b7e0a79170b3c46910c48604f166d81d4cffe6d61e2a19cde8bg,
+        ],
+    ];
+
+    const MDS: [[F; WIDTH]; WIDTH] = [
+        [
+            0x3aeb41a80df6213d44b4809e050c7740f4c622e45462b8d9c1f0e31a5540a835g,
+            0x2f573f555738cf0978796faa5ed1791568fce8e35567cb038491c6ea6637d391g,
+            0x0cc7d0080b0c840607698e29e3cb03b12c9e51a33f2ca58be972ef6bc62a2817g,
+        ],
+        [
+            0x2881d08831c029cd03fa315abd0ab3d4dad5d822cc1ba5320ee8f6d3cc63b5e9g,
+            0x378a82e6980e1f7f29416d0a201ca8e7e82b366ae9965e308b023a015a8635a6g,
+            0x3e7823cfe511b8405e826b858613d1811f73f0f5f8acf7d8e0215bcc7d268852g,
+        ],
+        [
+            0x221073e7edbc7a441eb7cc5c2da4e02e5afbcc7a29fad61fbc3d702ca87cc310g,
+            0x0dade6798b68bca2a8a5f73ecb04de03f501a9a0afdfc199d07ad7219b2e6e22g,
+            0x3ab3feaa95bb1baa7a1939151b600817afe419b0f0b41ebb64c05fd50fccacc3g,
+        ],
+    ];
+}
@@ -0,0 +1,114 @@
+//! Canonical values of field elements and coordinates of curve points.
+//!
+//! Outside the assigner a field element is stored as a native-endian integer of the
+//! field size holding its canonical value, and a curve point as its coordinates `x`
+//! and `y` (see `num::zk_soft`), so both can be converted to and from bytes directly.
+
+use crate::mem;
+
+/// Largest field size in bytes.
+const MAX_SIZE: usize = 48;
+
+pub(crate) trait FieldRepr: Copy {
+    /// Size of the field element in bytes.
+    const SIZE: usize;
+
+    /// Writes the canonical value as `Self::SIZE` big-endian bytes.
+    fn write_be_bytes(self, out: &mut [u8]);
+
+    /// Reads the canonical value from `Self::SIZE` big-endian bytes.
+    ///
+    /// The value must be less than the field modulus.
+    fn read_be_bytes(bytes: &[u8]) -> Self;
+
+    /// Returns the canonical value if it fits into `u128`.
+    fn to_u128(self) -> Option<u128> {
+        let mut bytes = [0; MAX_SIZE];
+        self.write_be_bytes(&mut bytes[..Self::SIZE]);
+        let (high, low) = bytes[..Self::SIZE].split_at(Self::SIZE - 16);
+        if high.iter().any(|&b| b != 0) {
+            return None;
+        }
+        Some(u128::from_be_bytes(low.try_into().unwrap()))
+    }
+
+    /// Returns the field element with the given value, which must be less than the modulus.
+    fn from_u128(value: u128) -> Self {
+        let mut bytes = [0; MAX_SIZE];
+        bytes[Self::SIZE - 16..Self::SIZE].copy_from_slice(&value.to_be_bytes());
+        Self::read_be_bytes(&bytes[..Self::SIZE])
+    }
+}
+
+macro_rules! field_repr_impl {
+    ($($t:ty, $size:literal;)*) => {$(
+        impl FieldRepr for $t {
+            const SIZE: usize = $size;
+
+            fn write_be_bytes(self, out: &mut [u8]) {
+                // SAFETY: outside the assigner field elements are plain integers of the field size.
+                let mut bytes: [u8; $size] = unsafe { mem::transmute(self) };
+                if cfg!(target_endian = "little") {
+                    bytes.reverse();
+                }
+                out.copy_from_slice(&bytes);
+            }
+
+            fn read_be_bytes(bytes: &[u8]) -> Self {
+                let mut bytes: [u8; $size] = bytes.try_into().unwrap();
+                if cfg!(target_endian = "little") {
+                    bytes.reverse();
+                }
+                // SAFETY: outside the assigner field elements are plain integers of the field size,
+                // the caller guarantees the value is reduced.
+                unsafe { mem::transmute(bytes) }
+            }
+        }
+    )*}
+}
+
+field_repr_impl! {
+    __zkllvm_field_bls12381_base, 48;
+    __zkllvm_field_bls12381_scalar, 32;
+    __zkllvm_field_bn254_base, 32;
+    __zkllvm_field_bn254_scalar, 32;
+    __zkllvm_field_secp256k1_base, 32;
+    __zkllvm_field_secp256k1_scalar, 32;
+    __zkllvm_field_curve25519_base, 32;
+    __zkllvm_field_curve25519_scalar, 32;
+    __zkllvm_field_pallas_base, 32;
+    __zkllvm_field_pallas_scalar, 32;
+}
+
+pub(crate) trait CurveRepr: Copy {
+    /// Field of point coordinates.
+    type Base: FieldRepr;
+
+    /// Returns the coordinates `(x, y)` of the point.
+    ///
+    /// The neutral element is represented as `(0, 1)`.
+    fn coordinates(self) -> (Self::Base, Self::Base);
+}
+
+macro_rules! curve_repr_impl {
+    ($($t:ty, $base:ty;)*) => {$(
+        impl CurveRepr for $t {
+            type Base = $base;
+
+            fn coordinates(self) -> ($base, $base) {
+                // SAFETY: outside the assigner curve points are stored as two coordinates.
+                let [x, y]: [$base; 2] = unsafe { mem::transmute(self) };
+                (x, y)
+            }
+        }
+    )*}
+}
+
+curve_repr_impl! {
+    __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base;
+    __zkllvm_curve_bn254, __zkllvm_field_bn254_base;
+    __zkllvm_curve_secp256k1, __zkllvm_field_secp256k1_base;
+    __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base;
+    __zkllvm_curve_pallas, __zkllvm_field_pallas_base;
+    __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar;
+}
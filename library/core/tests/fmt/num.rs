@@ -223,3 +223,80 @@ fn test_format_debug_hex() {
     assert_eq!(format!("{:02x?}", b"Foo\0"), "[46, 6f, 6f, 00]");
     assert_eq!(format!("{:02X?}", b"Foo\0"), "[46, 6F, 6F, 00]");
 }
+
+#[test]
+fn test_format_field() {
+    type F = __zkllvm_field_pallas_base;
+    let x: F = 255g;
+    assert_eq!(format!("{}", x), "255");
+    assert_eq!(format!("{:?}", x), "255");
+    assert_eq!(format!("{:x}", x), "ff");
+    assert_eq!(format!("{:X}", x), "FF");
+    assert_eq!(format!("{:#x}", x), "0xff");
+    assert_eq!(format!("{:#X}", x), "0xFF");
+    assert_eq!(format!("{:x?}", x), "ff");
+    assert_eq!(format!("{:X?}", x), "FF");
+    assert_eq!(format!("{}", F::ZERO), "0");
+    assert_eq!(format!("{:x}", F::ZERO), "0");
+    assert_eq!(format!("{:#x}", F::ZERO), "0x0");
+
+    // Decimal digits are produced 19 at a time.
+    let x: F = 10000000000000000000g;
+    assert_eq!(format!("{}", x), "10000000000000000000");
+    let x: F = 9999999999999999999g;
+    assert_eq!(format!("{}", x), "9999999999999999999");
+
+    let max = -F::ONE;
+    assert_eq!(
+        format!("{}", max),
+        "28948022309329048855892746252171976963363056481941560715954676764349967630336"
+    );
+    assert_eq!(
+        format!("{:#x}", max),
+        "0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000"
+    );
+
+    // The largest field is 48 bytes wide.
+    let max = -__zkllvm_field_bls12381_base::ONE;
+    assert_eq!(
+        format!("{}", max),
+        "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786"
+    );
+    assert_eq!(
+        format!("{:X}", max),
+        "1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAA"
+    );
+}
+
+#[test]
+fn test_format_field_padding() {
+    let x: __zkllvm_field_pallas_base = 255g;
+    assert_eq!(format!("{:6}", x), "   255");
+    assert_eq!(format!("{:<6}|", x), "255   |");
+    assert_eq!(format!("{:^7}", x), "  255  ");
+    assert_eq!(format!("{:*>6}", x), "***255");
+    assert_eq!(format!("{:06}", x), "000255");
+    assert_eq!(format!("{:+}", x), "+255");
+    assert_eq!(format!("{:6x}", x), "    ff");
+    assert_eq!(format!("{:#08x}", x), "0x0000ff");
+    assert_eq!(format!("{:2}", x), "255");
+}
+
+#[test]
+fn test_format_curve() {
+    let g = __zkllvm_curve_pallas::one();
+    assert_eq!(
+        format!("{}", g),
+        "(28948022309329048855892746252171976963363056481941560715954676764349967630336, 2)"
+    );
+    assert_eq!(
+        format!("{:x?}", g),
+        "(40000000000000000000000000000000224698fc094cf91b992d30ed00000000, 2)"
+    );
+
+    // The point at infinity is printed as its `(0, 1)` representation.
+    let zero = __zkllvm_curve_pallas::zero();
+    assert_eq!(format!("{}", zero), "(0, 1)");
+    assert_eq!(format!("{:?}", zero), "(0, 1)");
+    assert_eq!(format!("{:#x?}", zero), "(0x0, 0x1)");
+}
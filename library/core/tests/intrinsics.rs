@@ -99,3 +99,74 @@ fn test_const_deallocate_at_runtime() {
         const_deallocate(core::ptr::null_mut(), 1, 1); // nop
     }
 }
+
+#[test]
+fn test_assigner_hash_to_curve() {
+    use core::intrinsics::{assigner_hash_to_curve, assigner_is_in_g1_check};
+    type Fp = __zkllvm_field_bls12381_base;
+
+    // Computed with a Python implementation of RFC 9380, `map_to_curve_svdw` with `Z = -3`
+    // and `clear_cofactor`.
+    #[rustfmt::skip]
+    let vectors: [(Fp, Fp, Fp); 4] = [
+        (
+            0x0g,
+            0x11396aa4cd02fe3d659feb10bc4076a63edf91d1d95b9fec42d937f5070a3783a993d1614f10b365597eb007186de9a8g,
+            0x944e24e7c17c03c4b27b20c21829d305c6090094384273245672427f299a582f3b1ce694f33aedf8ba721960c54f9edg,
+        ),
+        (
+            0x1g,
+            0x11f1170fae4123f9125d7a5e16602588c7cacd1a8d2638e25839d3902ec9f3dd829b9d879e2dedf5a7eabf554e73ef77g,
+            0x119d30444e6d35f729214b17dcf5a31a827940c237eb8823803b5d298a7e8d35c96717a5442a4205f3bedc72bb226ad2g,
+        ),
+        (
+            0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaag,
+            0x14c9fc2e1b7a58a39e113c8540780531cf35d8c0f8f5ceeed3bca142270b95000c025e0e895aefb7d764232ea0d1a7fag,
+            0xf21c82f18c76ccd4a92fb8a4e629e9236db8dee2514f4dbfabd38ad43c18626cde9e91fa3718c1e9f87f4f3fa03a896g,
+        ),
+        (
+            0xe6b2a1c9d3ef54a0f3b5c2d1e7a8b9c0d1e2f3a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f5g,
+            0xa61b89225b87a87b0ccced06544490a4e44acef8de493d21c56eae1a09d9168c0b90cce916a2ae3db7359ea7190ec60g,
+            0x2a775aaa5ed6223531bdc59ff2de6f70317e566f230317318e50bfb6db0372fb3a7ab5115eccf292c9961bf105e653g,
+        ),
+    ];
+    for (u, x, y) in vectors {
+        let point = assigner_hash_to_curve(core::hint::black_box(u));
+        assert_eq!(point, unsafe { __zkllvm_curve_bls12381::from_coordinates(x, y) });
+        assert!(assigner_is_in_g1_check(point));
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_assigner_bls12381_array_adapters() {
+    use core::intrinsics::{
+        assigner_bls12_optimal_ate_pairing, assigner_bls12_pairing, assigner_g2_coordinates,
+        assigner_gt_coefficients, assigner_gt_mul, assigner_gt_multiplication,
+        assigner_is_in_g2, assigner_is_in_g2_check,
+    };
+    type Fp = __zkllvm_field_bls12381_base;
+    type G1 = __zkllvm_curve_bls12381;
+    type G2 = __zkllvm_curve_bls12381_g2;
+
+    #[rustfmt::skip]
+    let q: [Fp; 4] = [
+        0x24aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8g,
+        0x13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7eg,
+        0xce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801g,
+        0x606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79beg,
+    ];
+    let g2 = unsafe { G2::from_coordinates([q[0], q[1]], [q[2], q[3]]) };
+    assert_eq!(assigner_g2_coordinates(g2), q);
+    assert!(assigner_is_in_g2_check(core::hint::black_box(q)));
+    assert!(assigner_is_in_g2(g2));
+
+    let g1 = G1::one();
+    let e = assigner_bls12_pairing(g1, g2);
+    let coefficients = assigner_bls12_optimal_ate_pairing(g1, core::hint::black_box(q));
+    assert_eq!(coefficients, assigner_gt_coefficients(e));
+    assert_eq!(
+        assigner_gt_multiplication(coefficients, coefficients),
+        assigner_gt_coefficients(assigner_gt_mul(e, e)),
+    );
+}
@@ -160,6 +160,8 @@
 mod tuple;
 mod unicode;
 mod waker;
+#[cfg(not(bootstrap))]
+mod zk;
 
 /// Copied from `std::test_helpers::test_rng`, see that function for rationale.
 #[track_caller]
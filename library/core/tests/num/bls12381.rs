@@ -0,0 +1,161 @@
+use core::fields::bls12381::{Fp2, Fp6, Fp12};
+use core::ops::Field;
+
+type Fp = __zkllvm_field_bls12381_base;
+
+/// The base field modulus `p`, little-endian 128-bit limbs.
+const P: [u128; 3] = [
+    0x1eabfffeb153ffffb9feffffffffaaab,
+    0x64774b84f38512bf6730d2a0f6b0f624,
+    0x1a0111ea397fe69a4b1ba7b6434bacd7,
+];
+
+/// Raises to the power of `p` with square-and-multiply, to check `frobenius_map` against.
+fn pow_p<F: Field>(x: F) -> F {
+    let mut acc = F::ONE;
+    for &limb in P.iter().rev() {
+        for _ in 0..128 {
+            acc = acc * acc;
+        }
+        acc = acc * x.pow(limb);
+    }
+    acc
+}
+
+fn fp(seed: u64) -> Fp {
+    // Spread the values over the whole field.
+    Fp::from(seed) * 0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bbg
+        + Fp::from(seed * seed)
+}
+
+fn fp2(seed: u64) -> Fp2 {
+    Fp2 { c0: fp(seed), c1: fp(seed + 1) }
+}
+
+fn fp6(seed: u64) -> Fp6 {
+    Fp6 { c0: fp2(seed), c1: fp2(seed + 2), c2: fp2(seed + 4) }
+}
+
+fn fp12(seed: u64) -> Fp12 {
+    Fp12 { c0: fp6(seed), c1: fp6(seed + 6) }
+}
+
+macro_rules! extension_field_tests {
+    ($($name:ident: $F:ty, $element:ident, $degree:literal;)*) => {$(
+        mod $name {
+            use super::*;
+
+            type F = $F;
+
+            fn elements() -> [F; 3] {
+                [$element(1), $element(20), $element(300)]
+            }
+
+            #[test]
+            fn axioms() {
+                let [a, b, c] = elements();
+                assert_eq!(a + b, b + a);
+                assert_eq!(a * b, b * a);
+                assert_eq!((a + b) + c, a + (b + c));
+                assert_eq!((a * b) * c, a * (b * c));
+                assert_eq!(a * (b + c), a * b + a * c);
+                assert_eq!(a + F::ZERO, a);
+                assert_eq!(a * F::ONE, a);
+                assert_eq!(a * F::ZERO, F::ZERO);
+                assert_eq!(a + -a, F::ZERO);
+                assert_eq!(a - b, a + -b);
+
+                let mut x = a;
+                x += b;
+                x *= c;
+                x -= b;
+                assert_eq!(x, (a + b) * c - b);
+            }
+
+            #[test]
+            fn inverse() {
+                assert_eq!(F::ZERO.inverse(), None);
+                assert_eq!(F::ONE.inverse(), Some(F::ONE));
+                for a in elements() {
+                    let inv = a.inverse().unwrap();
+                    assert_eq!(a * inv, F::ONE);
+                    assert_eq!(F::ONE / a, inv);
+                    assert_eq!(inv.inverse(), Some(a));
+                }
+                let [a, b, _] = elements();
+                assert_eq!(a / b * b, a);
+            }
+
+            #[test]
+            fn pow() {
+                let [a, _, _] = elements();
+                assert_eq!(a.pow(0), F::ONE);
+                assert_eq!(a.pow(1), a);
+                assert_eq!(a.pow(5), a * a * a * a * a);
+            }
+
+            #[test]
+            fn frobenius_map() {
+                for a in elements() {
+                    assert_eq!(a.frobenius_map(), pow_p(a));
+                    // The Frobenius map has the extension degree as its order.
+                    let mut x = a;
+                    for _ in 0..$degree {
+                        x = x.frobenius_map();
+                    }
+                    assert_eq!(x, a);
+                    assert_ne!(a.frobenius_map(), a);
+                }
+            }
+        }
+    )*}
+}
+
+extension_field_tests! {
+    fp2_tests: Fp2, fp2, 2;
+    fp6_tests: Fp6, fp6, 6;
+    fp12_tests: Fp12, fp12, 12;
+}
+
+#[test]
+fn fp12_conjugate() {
+    let a = fp12(7);
+    // Conjugation is raising to the power of `p^6`.
+    let mut x = a;
+    for _ in 0..6 {
+        x = x.frobenius_map();
+    }
+    assert_eq!(a.conjugate(), x);
+}
+
+#[test]
+fn fp12_coefficients_round_trip() {
+    let a = fp12(7);
+    assert_eq!(Fp12::from_coefficients(a.coefficients()), a);
+    assert_eq!(Fp12::ONE.coefficients()[0], Fp::ONE);
+    assert!(Fp12::ONE.coefficients()[1..].iter().all(|&c| c == Fp::ZERO));
+}
+
+#[test]
+fn gt_mul_matches_fp12_mul() {
+    type G2 = __zkllvm_curve_bls12381_g2;
+    #[rustfmt::skip]
+    let q = unsafe {
+        G2::from_coordinates(
+            [
+                0x24aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8g,
+                0x13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7eg,
+            ],
+            [
+                0xce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801g,
+                0x606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79beg,
+            ],
+        )
+    };
+    let g = __zkllvm_curve_bls12381::one();
+    let a = g.pairing(q);
+    let b = (g + g).pairing(q);
+    // The gadget and the software multiplication agree on the target group.
+    assert_eq!(Fp12::from(a * b), Fp12::from(a) * Fp12::from(b));
+    assert_eq!(Fp12::from(a * b), Fp12::from((g + g + g).pairing(q)));
+}
@@ -0,0 +1,99 @@
+//! BN254 fields and curve, checked against values computed with arbitrary-precision
+//! integers.
+
+use core::hint::black_box;
+
+type Fq = __zkllvm_field_bn254_base;
+type Fr = __zkllvm_field_bn254_scalar;
+type G1 = __zkllvm_curve_bn254;
+
+#[test]
+fn base_field_arith() {
+    let (a, b): (Fq, Fq) = black_box((
+        0x3a133837a3d9895706bfa5a978c7a868fad047403e9a998040c0a15ff32385g,
+        0x2b4107667beafe22bb8a0b59530f632cf9e0b742b53f787a80135771b7d72365g,
+    ));
+    assert_eq!(a + b, 0x2b7b1a9eb38ed7ac1290cafefc882ad562db8789f57e13140054181317ca46eag);
+    assert_eq!(a - b, 0x55d5a449cea7b9053ccfa02d7eabcd9069b8395f370ecac3c4df5468098fd67g);
+    assert_eq!(a * b, 0x21bb87e3ee7a69cc30d6478780b82037913245a5a2e96a2c6026d07c6975bd1ag);
+    assert_eq!(a / b, 0x1d439cf58b23f30003daa2db06c264df07e2219509ba4bd9f575d1f87cec53f9g);
+    assert_eq!(
+        a.inverse(),
+        Some(0xb6ecef6bfba8dd7c93a3f89f9722f8f4cc9cfbc5f084d584d8071e6ad0b1012g),
+    );
+    assert_eq!(-a + a, 0g);
+}
+
+#[test]
+fn scalar_field_arith() {
+    let (a, b): (Fr, Fr) = black_box((
+        0xd99f756107fdca5d6192a98054aadfa73a7d1a92c5c214de3f3b23094789f59g,
+        0x12962fbcb90ae5119d2996b0a2fbd342eef773dfd8db21053e25cc2cdcb7f4f0g,
+    ));
+    assert_eq!(a + b, 0x20302712c98ac1b77342c148a846813d629f45890537425322197e5d71309449g);
+    assert_eq!(a - b, 0x2b68160c38a697bdf13fd99de3d03314ace44611cd3a70d9e9afdb97a7c0aa6ag);
+    assert_eq!(a * b, 0x1abd16fef528b21a8f30ae174a89a36b2a1406502d8436bd0210e34862b79aecg);
+    assert_eq!(a / b, 0x1e89323a5fe6ff2ab4064f0126c3fe37069b5647ec0ba2a2cb3241338ee84481g);
+    assert_eq!(
+        a.inverse(),
+        Some(0x265752020cdded343e2a6ea27110a1647f8575395f581fceb95ef354369e11deg),
+    );
+}
+
+#[test]
+fn moduli() {
+    // `p - 1` and `r - 1` wrap around to zero.
+    let p_minus_1: Fq =
+        black_box(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46g);
+    let r_minus_1: Fr =
+        black_box(0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000g);
+    assert_eq!(p_minus_1 + 1g, 0g);
+    assert_eq!(r_minus_1 + 1g, 0g);
+    assert_eq!(p_minus_1, -1g);
+    assert_eq!(r_minus_1, -1g);
+}
+
+#[test]
+fn curve_generator() {
+    let g = black_box(G1::one());
+    assert_eq!(g.to_affine(), (1g, 2g));
+    assert_eq!(G1::try_from_coordinates(1g, 2g), Some(g));
+    assert_eq!(G1::try_from_coordinates(1g, 3g), None);
+}
+
+#[test]
+fn curve_arith() {
+    let g = black_box(G1::one());
+    let two = unsafe {
+        G1::from_coordinates(
+            0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3g,
+            0x15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4g,
+        )
+    };
+    let five = unsafe {
+        G1::from_coordinates(
+            0x17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9g,
+            0x1e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7cg,
+        )
+    };
+    assert_eq!(g + g, two);
+    assert_eq!(g.double(), two);
+    assert_eq!(g * black_box::<Fr>(5g), five);
+    assert_eq!(five - two - two, g);
+    // The generator has order `r`.
+    assert_eq!(g * black_box::<Fr>(-1g), -g);
+    assert!((g * black_box::<Fr>(-1g) + g).is_zero());
+}
+
+#[test]
+fn curve_mul_random_scalar() {
+    let g = black_box(G1::one());
+    let k: Fr = black_box(0x4316a408308ab1fabf0c0087d263d8ce27901e579bde6ef95c15f41eb6e8a7g);
+    let expected = unsafe {
+        G1::from_coordinates(
+            0x8618d2ea16386628284446073163fe652382204269c8f342f32340d3b551bdeg,
+            0x1ed50e0017448478a0f23d7629d91ded55c455444c004f943014c3d39eef012bg,
+        )
+    };
+    assert_eq!(g * k, expected);
+}
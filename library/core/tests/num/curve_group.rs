@@ -0,0 +1,67 @@
+//! Coordinates and the group API of the curve types.
+
+use core::hint::black_box;
+
+macro_rules! curve_group_tests {
+    ($($name:ident: $C:ty, $Base:ty {
+        double: ($double_x:literal, $double_y:literal),
+    })*) => {$(
+        mod $name {
+            use super::black_box;
+
+            type C = $C;
+            type Base = $Base;
+
+            #[test]
+            fn coordinates() {
+                let zero = black_box(C::zero());
+                assert_eq!(zero.to_affine(), (0g, 1g));
+                let g = black_box(C::one());
+                assert_eq!((g.x(), g.y()), g.to_affine());
+                // SAFETY: the generator is a point of the curve.
+                assert_eq!(unsafe { C::from_coordinates(g.x(), g.y()) }, g);
+            }
+
+            #[test]
+            fn double() {
+                let g = black_box(C::one());
+                let double: (Base, Base) = ($double_x, $double_y);
+                assert_eq!(g.double().to_affine(), double);
+                assert_eq!(g.double(), g + g);
+                assert_eq!(C::zero().double(), C::zero());
+            }
+
+            #[test]
+            fn neg() {
+                let g = black_box(C::one());
+                assert_eq!(-g + g, C::zero());
+                assert_eq!(-(-g), g);
+                assert_eq!((-g).double(), -g.double());
+                assert_eq!(-C::zero(), C::zero());
+            }
+
+            #[test]
+            fn is_zero() {
+                assert!(black_box(C::zero()).is_zero());
+                assert!(!black_box(C::one()).is_zero());
+                let g = black_box(C::one());
+                assert!((g - g).is_zero());
+            }
+        }
+    )*};
+}
+
+curve_group_tests! {
+    bls12381: __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base {
+        double: (0x572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4eg, 0x166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28g),
+    }
+    curve25519: __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base {
+        double: (0x36ab384c9f5a046c3d043b7d1833e7ac080d8e4515d7a45f83c5a14e2843ce0eg, 0x2260cdf3092329c21da25ee8c9a21f5697390f51643851560e5f46ae6af8a3c9g),
+    }
+    pallas: __zkllvm_curve_pallas, __zkllvm_field_pallas_base {
+        double: (0x1c0000000000000000000000000000000efee2ee4411acfc1303c567b0000003g, 0x2b00000000000000000000000000000017076ec9563fb75e8aea5cdf3bfffffcg),
+    }
+    vesta: __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar {
+        double: (0x1c0000000000000000000000000000000efee2ee443109e0ed5f06de70000003g, 0x2b00000000000000000000000000000017076ec9566fe174da3fa5fa2bfffffcg),
+    }
+}
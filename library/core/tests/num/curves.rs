@@ -0,0 +1,88 @@
+type G2 = __zkllvm_curve_bls12381_g2;
+type Fr = __zkllvm_field_bls12381_scalar;
+
+fn g2_generator() -> G2 {
+    unsafe {
+        G2::from_coordinates(
+            [
+                0x24aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8g,
+                0x13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7eg,
+            ],
+            [
+                0xce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801g,
+                0x606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79beg,
+            ],
+        )
+    }
+}
+
+#[test]
+fn g2_double() {
+    let g = g2_generator();
+    assert_eq!(
+        (g + g).to_affine(),
+        (
+            [
+                0x1638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053g,
+                0xa4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577g,
+            ],
+            [
+                0x468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899g,
+                0xf6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3g,
+            ],
+        )
+    );
+    assert_eq!(g * Fr::from(2u64), g + g);
+}
+
+#[test]
+fn g2_scalar_mul() {
+    let g = g2_generator();
+    let five = g + g + g + g + g;
+    assert_eq!(
+        five.to_affine(),
+        (
+            [
+                0x411a5de6730ffece671a9f21d65028cc0f1102378de124562cb1ff49db6f004fcd14d683024b0548eff3d1468df2688g,
+                0xfb837804dba8213329db46608b6c121d973363c1234a86dd183baff112709cf97096c5e9a1a770ee9d7dc641a894d6g,
+            ],
+            [
+                0x19b5e8f5d4a72f2b75811ac084a7f814317360bac52f6aab15eed416b4ef9938e0bdc4865cc2c4d0fd947e7c6925fd14g,
+                0x93567b4228be17ee62d11a254edd041ee4b953bffb8b8c7f925bd6662b4298bac2822b446f5b5de3b893e1be5aa4986g,
+            ],
+        )
+    );
+    assert_eq!(g * Fr::from(5u64), five);
+    assert_eq!(Fr::from(5u64) * g, five);
+
+    let mut acc = g;
+    acc *= Fr::from(5u64);
+    assert_eq!(acc, five);
+
+    // The generator has order `r`, which is zero in the scalar field.
+    assert!((g * -Fr::ONE + g).is_zero());
+    assert!((g * Fr::ZERO).is_zero());
+}
+
+#[test]
+fn g2_group_laws() {
+    let g = g2_generator();
+    let zero = G2::zero();
+    assert_eq!(g + zero, g);
+    assert_eq!(zero + g, g);
+    assert_eq!(-zero, zero);
+    assert!((g - g).is_zero());
+    assert!((g + -g).is_zero());
+    assert_eq!(-(-g), g);
+
+    let two = g + g;
+    assert_eq!(two - g, g);
+    assert_eq!(two + g, g + two);
+
+    let mut acc = g;
+    acc += g;
+    assert_eq!(acc, two);
+    acc -= g;
+    assert_eq!(acc, g);
+    assert!(acc.is_in_g2());
+}
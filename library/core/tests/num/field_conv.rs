@@ -0,0 +1,53 @@
+use core::hint::black_box;
+
+macro_rules! field_conv_tests {
+    ($($name:ident: $F:ty;)*) => {$(
+        mod $name {
+            use super::black_box;
+
+            type F = $F;
+
+            #[test]
+            fn from_unsigned() {
+                assert_eq!(F::from(black_box(0u64)), 0g);
+                assert_eq!(F::from(black_box(u64::MAX)), 0xffffffffffffffffg);
+                assert_eq!(F::from(black_box(1u128 << 64)), 0x10000000000000000g);
+                assert_eq!(F::from(black_box(u128::MAX)), 0xffffffffffffffffffffffffffffffffg);
+            }
+
+            #[test]
+            fn cast_unsigned() {
+                assert_eq!(black_box(7u8) as F, 7g);
+                assert_eq!(black_box(u32::MAX) as F, 0xffffffffg);
+                assert_eq!(black_box(u128::MAX) as F, 0xffffffffffffffffffffffffffffffffg);
+                // Unsuffixed literals are unsigned.
+                assert_eq!(1 as F, 1g);
+                assert_eq!(0xffffffffffffffffffffffffffffffff as F, F::from(u128::MAX));
+            }
+
+            #[test]
+            fn try_into_u128() {
+                assert_eq!(u128::try_from(black_box::<F>(0g)), Ok(0));
+                assert_eq!(u128::try_from(black_box(F::from(u128::MAX))), Ok(u128::MAX));
+                assert!(u128::try_from(black_box::<F>(0x100000000000000000000000000000000g)).is_err());
+                assert!(u128::try_from(black_box::<F>(0g) - 1g).is_err());
+            }
+
+            #[test]
+            fn truncate_to_u128() {
+                assert_eq!(black_box::<F>(0x2ag).truncate_to_u128(), 0x2a);
+                assert_eq!(black_box(F::from(u128::MAX)).truncate_to_u128(), u128::MAX);
+                assert_eq!(black_box::<F>(0x100000000000000000000000000000005g).truncate_to_u128(), 5);
+            }
+        }
+    )*};
+}
+
+field_conv_tests! {
+    bls12381_base: __zkllvm_field_bls12381_base;
+    bls12381_scalar: __zkllvm_field_bls12381_scalar;
+    curve25519_base: __zkllvm_field_curve25519_base;
+    curve25519_scalar: __zkllvm_field_curve25519_scalar;
+    pallas_base: __zkllvm_field_pallas_base;
+    pallas_scalar: __zkllvm_field_pallas_scalar;
+}
@@ -0,0 +1,101 @@
+//! Field division, inversion and exponentiation, checked against values computed with
+//! arbitrary-precision integers.
+
+use core::hint::black_box;
+
+macro_rules! field_inverse_tests {
+    ($($name:ident: $F:ty {
+        a: $a:literal,
+        b: $b:literal,
+        inverse: $inverse:literal,
+        quotient: $quotient:literal,
+        exp: $exp:literal,
+        power: $power:literal,
+    })*) => {$(
+        mod $name {
+            use super::black_box;
+
+            type F = $F;
+
+            #[test]
+            fn inverse() {
+                let a: F = black_box($a);
+                assert_eq!(a.inverse(), Some($inverse));
+                assert_eq!(a * a.inverse().unwrap(), 1g);
+                assert_eq!(black_box::<F>(1g).inverse(), Some(1g));
+                assert_eq!(black_box::<F>(0g).inverse(), None);
+            }
+
+            #[test]
+            fn div() {
+                let (a, b): (F, F) = black_box(($a, $b));
+                assert_eq!(a / b, $quotient);
+                assert_eq!(a / b * b, a);
+                assert_eq!(a / a, 1g);
+                assert_eq!(black_box::<F>(1g) / a, $inverse);
+            }
+
+            #[test]
+            fn pow() {
+                let a: F = black_box($a);
+                assert_eq!(a.pow(black_box($exp)), $power);
+                assert_eq!(a.pow(0), 1g);
+                assert_eq!(a.pow(1), a);
+                assert_eq!(a.pow(3), a * a * a);
+                assert_eq!(black_box::<F>(0g).pow(0), 1g);
+                assert_eq!(black_box::<F>(0g).pow(5), 0g);
+            }
+        }
+    )*};
+}
+
+field_inverse_tests! {
+    bls12381_base: __zkllvm_field_bls12381_base {
+        a: 0x123698ab59aa6c595d638cb792a026868e33aa0c3e1f612f704bf91e4737a332d8780a7f6b8e927a0d834ecb9c7865a2g,
+        b: 0x1e2b052a5c2a8be1606c09e2db72d5225f8d8517dd13d4e9955306a1fe8d1cceaec575e12db50d32f7f69bbc5105082g,
+        inverse: 0xe809abf050e2de543849625eef1164b32681c1fb0170f528072fb9a8f3e6232f2bdb86687857eaff93af3e90840d63dg,
+        quotient: 0x19dee8908c1551f60f036605318a1d7a3c2d55acc1ef954665853ccb0f32ebc1bfac9be1181fa1e6d61ec56444659b48g,
+        exp: 0x6018366cf658f7a75ed34fe53a096533,
+        power: 0x1615c029bd6c9fe9aa20dd6fc80b9c3db87451f4eb11e213afb4aca1f8a4ba4a8369d3c832a3677dfbd7425669583476g,
+    }
+    bls12381_scalar: __zkllvm_field_bls12381_scalar {
+        a: 0x1eaa289c4857451f694931b2e610d35163737ba5bba454147894d56364a10344g,
+        b: 0x7661ffc792c29c4b9a053a5d048ad29d87ba5756173e8a0009bfce0a9d96a97g,
+        inverse: 0x2bdbeaa3f09f5f9acde628418bc305c5bd195d50df2a0be7bc1f2b4b373ae3g,
+        quotient: 0x43516fe58a3f1539344025f8231e004b3cd6866c061fdbe70898a6e9387200cdg,
+        exp: 0xb3510b0b46ee1da317017a6205738d1,
+        power: 0x4e4ef75f4aea1d00f1eb2ec61e1dbc1c292de85ebb73aec301e3def9d49bddb9g,
+    }
+    curve25519_base: __zkllvm_field_curve25519_base {
+        a: 0x779f2be628b79344dd89614c7d9a6888cfddbae04cf91265d1ba5813318f5013g,
+        b: 0x66db83313d7d08b23796912357caca359996b1e64a25336548769b691094629ag,
+        inverse: 0x2a1b57411d4b5ed6cb9b1542fee93866338cf3dd9a0ed925f70a17de56d0e647g,
+        quotient: 0xa8885d2fb45c0b130860dc9af362b2967151cc68f32f0ca392b43e285131739g,
+        exp: 0xcfaf00103f584ad4230824d215ceb3a1,
+        power: 0x997727c40e4bb78fffa8657d1f83160f27135523c93366995423a3439692da4g,
+    }
+    curve25519_scalar: __zkllvm_field_curve25519_scalar {
+        a: 0xfcd1bb28af285edb07b77448de6479b4357f533fd901819f83b59d0bc52dc86g,
+        b: 0xcd0d5335b29cfdca518af336b838a55aeb4a05dbae8a6885ad0ec6fddc1c2a1g,
+        inverse: 0x385ab86822dfd5bea7d00b78c91e3a573f066b03251605436306cb27280c952g,
+        quotient: 0x86b66a551435f7055775d34f6ce339a8df29551e0d0d4c61ca384d46dbbe121g,
+        exp: 0x6694f229359b154881a0d5b3ffc6e35c,
+        power: 0x8448c4ac96b3ad101a980b5e71718d87c8cac60e175f51ff1d0e2cd7a6eadd0g,
+    }
+    pallas_base: __zkllvm_field_pallas_base {
+        a: 0x1b6f35979292d0090ef2d1f15552905513064033b17678ea3190081ef355d003g,
+        b: 0xc89c8c87a82a7706cbb35ce40bc2a7e5d802c39634b52ca6438bc7e2f6fd4cdg,
+        inverse: 0x3b4fb24973db7af85fca78456049fbe70f6425e187e17ab17d929dd2f55a66bbg,
+        quotient: 0x8152a1d6a4e0cd0621b0fea7138b6dfa9bb0ade90d5be1164855a07b0fb3860g,
+        exp: 0x7cc661e97589ca4a07c15471a4517d6c,
+        power: 0x1060c7e7bb9c08ce10f72a2d11e609cff1b87d8a099e1f303288f17ff1d3006dg,
+    }
+    pallas_scalar: __zkllvm_field_pallas_scalar {
+        a: 0x21ec14b05b181bfc881070b1e3096a930c97d9751dcc7333c9eb8b10a4503d73g,
+        b: 0x10fbb126439ac09b1085f21e8be42834b7cece1a466d3300b8db16ad5911584eg,
+        inverse: 0x247b1702595aa43d7d6ca1f2fd31b98b18b179c24b5ffaa124b40f5bb3e99208g,
+        quotient: 0x2eb38d59234a63728e7f141196ea1c096db3ec0d4661272bdb3446b5c9d7e151g,
+        exp: 0x92b850ad7eb72f8263f65da874007cb4,
+        power: 0x2f59935d9a6b6c795c6a4afdec2195273ff18de50fba4fd35629fa195382b041g,
+    }
+}
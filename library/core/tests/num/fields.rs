@@ -0,0 +1,77 @@
+macro_rules! field_bytes_tests {
+    ($($name:ident: $F:ty, $size:literal;)*) => {$(
+        mod $name {
+            type F = $F;
+
+            fn modulus_minus_one() -> [u8; $size] {
+                // Every modulus is odd, so this never borrows.
+                let mut bytes = F::MODULUS;
+                bytes[0] -= 1;
+                bytes
+            }
+
+            #[test]
+            fn to_le_bytes() {
+                let mut one = [0; $size];
+                one[0] = 1;
+                assert_eq!(F::ZERO.to_le_bytes(), [0; $size]);
+                assert_eq!(F::ONE.to_le_bytes(), one);
+                assert_eq!((-F::ONE).to_le_bytes(), modulus_minus_one());
+
+                let x: F = 0x0102030405060708090a0b0c0d0e0f101112g;
+                let bytes = x.to_le_bytes();
+                assert_eq!(bytes[..18], [
+                    0x12, 0x11, 0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06,
+                    0x05, 0x04, 0x03, 0x02, 0x01,
+                ]);
+                assert!(bytes[18..].iter().all(|&byte| byte == 0));
+            }
+
+            #[test]
+            fn round_trip() {
+                let x: F = 0x0102030405060708090a0b0c0d0e0f101112g;
+                for value in [F::ZERO, F::ONE, -F::ONE, x, -x, x * x * x * x] {
+                    assert_eq!(F::from_le_bytes(value.to_le_bytes()), Some(value));
+                    assert_eq!(F::from_be_bytes(value.to_be_bytes()), Some(value));
+
+                    let mut be = value.to_le_bytes();
+                    be.reverse();
+                    assert_eq!(value.to_be_bytes(), be);
+                }
+                assert_eq!(F::from_le_bytes(modulus_minus_one()), Some(-F::ONE));
+            }
+
+            #[test]
+            fn rejects_non_canonical() {
+                assert_eq!(F::from_le_bytes(F::MODULUS), None);
+                let mut be = F::MODULUS;
+                be.reverse();
+                assert_eq!(F::from_be_bytes(be), None);
+
+                // Values just above the modulus, and in its top byte.
+                let mut above = F::MODULUS;
+                above[0] += 1;
+                assert_eq!(F::from_le_bytes(above), None);
+                if let Some(byte) = F::MODULUS[$size - 1].checked_add(1) {
+                    let mut top = [0; $size];
+                    top[$size - 1] = byte;
+                    assert_eq!(F::from_le_bytes(top), None);
+                }
+                assert_eq!(F::from_le_bytes([0xff; $size]), None);
+            }
+        }
+    )*};
+}
+
+field_bytes_tests! {
+    bls12381_base: __zkllvm_field_bls12381_base, 48;
+    bls12381_scalar: __zkllvm_field_bls12381_scalar, 32;
+    bn254_base: __zkllvm_field_bn254_base, 32;
+    bn254_scalar: __zkllvm_field_bn254_scalar, 32;
+    secp256k1_base: __zkllvm_field_secp256k1_base, 32;
+    secp256k1_scalar: __zkllvm_field_secp256k1_scalar, 32;
+    curve25519_base: __zkllvm_field_curve25519_base, 32;
+    curve25519_scalar: __zkllvm_field_curve25519_scalar, 32;
+    pallas_base: __zkllvm_field_pallas_base, 32;
+    pallas_scalar: __zkllvm_field_pallas_scalar, 32;
+}
@@ -27,13 +27,22 @@
 mod u8;
 
 mod bignum;
+mod bls12381;
+mod bn254;
 
 mod const_from;
+mod curve_group;
+mod curves;
 mod dec2flt;
+mod field_conv;
+mod field_inverse;
+mod fields;
 mod flt2dec;
 mod int_log;
 mod ops;
+mod secp256k1;
 mod wrapping;
+mod zk_soft;
 
 mod ieee754;
 mod nan;
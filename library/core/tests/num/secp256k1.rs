@@ -0,0 +1,109 @@
+//! secp256k1 fields and curve, checked against values computed with arbitrary-precision
+//! integers.
+
+use core::hint::black_box;
+
+type Fq = __zkllvm_field_secp256k1_base;
+type Fr = __zkllvm_field_secp256k1_scalar;
+type G = __zkllvm_curve_secp256k1;
+
+#[test]
+fn base_field_arith() {
+    let (a, b): (Fq, Fq) = black_box((
+        0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8g,
+        0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827g,
+    ));
+    assert_eq!(a + b, 0x1fd9253f53ffae8ffafd085de306b9845b9b1ff3acbdd3a4be2ee98d42be39b0g);
+    assert_eq!(a - b, 0xca3dcdc8c06c2281a253c838dcffc0285fc9473730934af1e0b710f1b78c61c0g);
+    assert_eq!(a * b, 0xe6c1d071a4ec4980533e02bf09fc76f7a9ba06881e5843ded23583bb74d1142ag);
+    assert_eq!(a / b, 0xa8af7b33d296e2c6be871f88275cf7dffd1e79c9b01e77e6ff9598e255d70827g);
+    assert_eq!(
+        a.inverse(),
+        Some(0x9121dcbeaf9b56f023cfeed2f9d4e4d04938e9214b522d5c175d66e15b4d2593g),
+    );
+    assert_eq!(-a + a, 0g);
+}
+
+#[test]
+fn scalar_field_arith() {
+    let (a, b): (Fr, Fr) = black_box((
+        0xd2aeeaf914c7d3fd9a1ac067541b8ee6f0969fe15284b2bf8e56916a518a4444g,
+        0xf09b30460cce5b3445fff12fb4d7a20d294b97d08e7981664997082c8b7e20bfg,
+    ));
+    assert_eq!(a + b, 0xc34a1b3f21962f31e01ab19708f330f55f335acb31b593ea181b3b0a0cd223c2g);
+    assert_eq!(a - b, 0xe213bab307f978c9541acf379f43ecd881f9e4f77353d1950491e7ca964264c6g);
+    assert_eq!(a * b, 0xfff8784ae706bd57ea12cfcb67f5c279d0f572cd4296b34d337e24c98299ef1ag);
+    assert_eq!(a / b, 0x888e212102584d7337f9bf3f048305b5a3fa3b8fb7b604fa05d46159d09dfd3cg);
+    assert_eq!(
+        a.inverse(),
+        Some(0x374fa804f699fd31dca78ad857770335e6aa3a267d055841505abbd99a71f8c1g),
+    );
+}
+
+#[test]
+fn moduli() {
+    // Both moduli use all 256 bits.
+    let p_minus_1: Fq =
+        black_box(0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2eg);
+    let n_minus_1: Fr =
+        black_box(0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140g);
+    assert_eq!(p_minus_1 + 1g, 0g);
+    assert_eq!(n_minus_1 + 1g, 0g);
+    assert_eq!(Fq::BITS, 256);
+    assert_eq!(Fr::BITS, 256);
+
+    let mut bytes = Fq::MODULUS;
+    assert_eq!(Fq::from_le_bytes(bytes), None);
+    bytes[0] -= 1;
+    assert_eq!(Fq::from_le_bytes(bytes), Some(p_minus_1));
+    assert_eq!(p_minus_1.to_le_bytes(), bytes);
+    assert_eq!(Fr::from_le_bytes(Fr::MODULUS), None);
+    assert_eq!(n_minus_1.to_be_bytes()[0], 0xff);
+}
+
+#[test]
+fn curve_generator() {
+    let g = black_box(G::one());
+    let (x, y) = g.to_affine();
+    assert_eq!(x, 0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798g);
+    assert_eq!(y, 0x483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8g);
+    assert_eq!(G::try_from_coordinates(x, y), Some(g));
+    assert_eq!(G::try_from_coordinates(x, y + 1g), None);
+}
+
+#[test]
+fn curve_arith() {
+    let g = black_box(G::one());
+    let two = unsafe {
+        G::from_coordinates(
+            0xc6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5g,
+            0x1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52ag,
+        )
+    };
+    let five = unsafe {
+        G::from_coordinates(
+            0x2f8bde4d1a07209355b4a7250a5c5128e88b84bddc619ab7cba8d569b240efe4g,
+            0xd8ac222636e5e3d6d4dba9dda6c9c426f788271bab0d6840dca87d3aa6ac62d6g,
+        )
+    };
+    assert_eq!(g + g, two);
+    assert_eq!(g.double(), two);
+    assert_eq!(g * black_box::<Fr>(5g), five);
+    assert_eq!(five - two - two, g);
+    // The generator has order `n`.
+    assert_eq!(g * black_box::<Fr>(-1g), -g);
+    assert!((g * black_box::<Fr>(-1g) + g).is_zero());
+}
+
+#[test]
+fn curve_mul_random_scalar() {
+    let g = black_box(G::one());
+    let k: Fr = black_box(0xfde9c7e9675be2b6da6f2974beeb65d108c25300fecf0c9277eeb71d894a472bg);
+    let expected = unsafe {
+        G::from_coordinates(
+            0xfcd281afd7a424b04d51cc39b6f4891835c4ee416563a1da3d39a05624244da3g,
+            0x923e945f4c7da8b8e40abfbbe2000614488cc8120b7cd75a6b353e8db1c5bde8g,
+        )
+    };
+    assert_eq!(g * k, expected);
+}
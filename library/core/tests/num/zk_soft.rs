@@ -0,0 +1,199 @@
+//! Field and curve arithmetic outside the assigner, which runs the software runtime of
+//! `core::num::zk_soft`. The expected values are computed with arbitrary-precision integers
+//! and affine curve formulas.
+//!
+//! Operands go through `black_box`, so that the operations are not folded at compile time.
+
+use core::hint::black_box;
+
+macro_rules! field_ops_tests {
+    ($($name:ident: $F:ty {
+        a: $a:literal,
+        b: $b:literal,
+        sum: $sum:literal,
+        difference: $difference:literal,
+        product: $product:literal,
+        neg: $neg:literal,
+    })*) => {$(
+        mod $name {
+            use super::black_box;
+
+            type F = $F;
+
+            fn operands() -> (F, F) {
+                black_box(($a, $b))
+            }
+
+            #[test]
+            fn add() {
+                let (a, b) = operands();
+                assert_eq!(a + b, $sum);
+                assert_eq!(b + a, $sum);
+                assert_eq!(a + black_box(0g), a);
+            }
+
+            #[test]
+            fn sub() {
+                let (a, b) = operands();
+                assert_eq!(a - b, $difference);
+                assert_eq!(a - b + b, a);
+                assert_eq!(a - a, 0g);
+            }
+
+            #[test]
+            fn mul() {
+                let (a, b) = operands();
+                assert_eq!(a * b, $product);
+                assert_eq!(b * a, $product);
+                assert_eq!(a * black_box(1g), a);
+                assert_eq!(a * black_box(0g), 0g);
+            }
+
+            #[test]
+            fn neg() {
+                let (a, _) = operands();
+                assert_eq!(-a, $neg);
+                assert_eq!(-a + a, 0g);
+                assert_eq!(-black_box::<F>(0g), 0g);
+            }
+        }
+    )*};
+}
+
+macro_rules! curve_ops_tests {
+    ($($name:ident: $C:ty, $Base:ty, $Scalar:ty {
+        scalar: $k:literal,
+        double: ($double_x:literal, $double_y:literal),
+        triple: ($triple_x:literal, $triple_y:literal),
+        mul: ($mul_x:literal, $mul_y:literal),
+        div: ($div_x:literal, $div_y:literal),
+    })*) => {$(
+        mod $name {
+            use super::black_box;
+
+            type C = $C;
+
+            fn point(x: $Base, y: $Base) -> C {
+                // SAFETY: the test vectors are points of the curve.
+                unsafe { C::from_coordinates(x, y) }
+            }
+
+            #[test]
+            fn add() {
+                let g = black_box(C::one());
+                assert_eq!(g + g + g, point($triple_x, $triple_y));
+                assert_eq!(g + black_box(C::zero()), g);
+                assert_eq!(g - g, C::zero());
+                assert_eq!(g + -g, C::zero());
+                assert_eq!(g + g + g - g, point($double_x, $double_y));
+            }
+
+            #[test]
+            fn double() {
+                let g = black_box(C::one());
+                assert_eq!(g + g, point($double_x, $double_y));
+                assert_eq!(C::zero() + black_box(C::zero()), C::zero());
+            }
+
+            #[test]
+            fn mul() {
+                let g = black_box(C::one());
+                let k: $Scalar = black_box($k);
+                assert_eq!(g * k, point($mul_x, $mul_y));
+                assert_eq!(k * g, point($mul_x, $mul_y));
+                assert_eq!(g * black_box::<$Scalar>(0g), C::zero());
+                assert_eq!(g * black_box::<$Scalar>(1g), g);
+            }
+
+            #[test]
+            fn div() {
+                let g = black_box(C::one());
+                let k: $Scalar = black_box($k);
+                assert_eq!(g / k, point($div_x, $div_y));
+                assert_eq!(g / k * k, g);
+            }
+        }
+    )*};
+}
+
+field_ops_tests! {
+    bls12381_base: __zkllvm_field_bls12381_base {
+        a: 0xf528630a00f0453f093da2f3601ce1a4f8530fbed70d98afd77e48e4b291b4c2b93b1afa4035b79444cb4199b3d478g,
+        b: 0x117913c5f79db49691e6df5692bdca65e63cddceb527078450832645c9a72b63742b0eff99273413bfcb57248883ad93g,
+        sum: 0x126e3c29019ea4dbd0f01cf9861de7478b3530de73fe151d005aa48eae59bd1836e44a1a936769cb541022662237820bg,
+        difference: 0x97d26874be32248f83e0602a3edff532332c0c5fd3518d3c6852aa411bc5c756d3a2c1a126d01a38e78741d112fd190g,
+        product: 0x15c9cbbd0b71e6859209ea7a3b5d3a6056c2a5a05bcb40a370571752bca25994f6fa01414315125b6c666119603f88cag,
+        neg: 0x190be9872f7ef6550c126a134feb8ff5bf7ef87534ae0526b759545811fe646f5bf2c4e3b713ca4825ba34be664bd633g,
+    }
+    bls12381_scalar: __zkllvm_field_bls12381_scalar {
+        a: 0x71714cdf93816dadc04eceb89ff1d7388f4b7a7a9729210a3e00f9b0d6ce7e5bg,
+        b: 0x710ba39447265b6ec11651db71275a97c2106019cff998f3acbc19f951c53694g,
+        sum: 0x6e8f4920b10a4bd44e2b488c077759cafd9e369167245dfeeabd13ab2893b4eeg,
+        difference: 0x65a94b4c5b123eff387cdd2eca7ca0cd3b1a60c72f88169144dfb7850947c7g,
+        product: 0x3d2737d47ada197f4240a17640f26c58cecc4d3c89e485e02d62ac6b37a72c9g,
+        neg: 0x27c5a73961c0f9a72eb094f69b000ccc472298868d53af4c1ff064e293181a6g,
+    }
+    curve25519_base: __zkllvm_field_curve25519_base {
+        a: 0x23345217ec4620434dc5f5baf0135597cb0ee7989da92a3aca151fcf9a1edfa8g,
+        b: 0x6031a97674f522bba332ea6eb003224012c82734285a11e40d3cf0b5e93f8e1bg,
+        sum: 0x365fb8e613b42fef0f8e029a01677d7ddd70eccc6033c1ed7521085835e6dd6g,
+        difference: 0x4302a8a17750fd87aa930b4c40103357b846c064754f1856bcd82f19b0df517ag,
+        product: 0x503879b97be691a73a057b73d2e763130a6f10838744a8473b1fe26625007362g,
+        neg: 0x5ccbade813b9dfbcb23a0a450fecaa6834f118676256d5c535eae03065e12045g,
+    }
+    curve25519_scalar: __zkllvm_field_curve25519_scalar {
+        a: 0x942ce4fed8667fea0b62cdd8ec07f317753ab601ca47214c06aca3a0f9239ebg,
+        b: 0x35da6c8b682c2cb1a478074522aae351f9bda77e08d742469b30af43ae6b798g,
+        sum: 0xca07518a4092ac9bafdad51e0eb2d6696ef85d7fd31e6392a1dd52e4a78f183g,
+        difference: 0x5e527873703a533866eac693c95d0fc57b7d0e83c16fdf056b7bf45d4ab8253g,
+        product: 0xb0ef6137c7bf6980a9c2048e7a4307e0d9436bc00a14e8b9560b7c483615c62g,
+        neg: 0x6bd31b0127998015f49d322713f80ce9d8b4e7e86532ac197a798e04d639a02g,
+    }
+    pallas_base: __zkllvm_field_pallas_base {
+        a: 0x1e1647d737eab8cb1182e6dbd48a2b65b36f7b5193c314389915de4cc9515161g,
+        b: 0x26a2007bbdbcaa6992951c459b6bede9a19d79c9efbd542d53c1a66e0f3fb549g,
+        sum: 0x4b84852f5a76334a41803216ff6194f32c65c1f7a336f4a53aa53cdd89106a9g,
+        difference: 0x3774475b7a2e0e617eedca96391e3d7c34189a83ad52b926de8168cbba119c19g,
+        product: 0xbebf5f5d4436b150d90a23ba15f6def543172535b1159867b4d902e285153dag,
+        neg: 0x21e9b828c8154734ee7d19242b75d49a6ed71daa7589e4e3001752a036aeaea0g,
+    }
+    pallas_scalar: __zkllvm_field_pallas_scalar {
+        a: 0x1eca457144545055d406a3b367f18eda7b67294efb746928e2bb19591ca02016g,
+        b: 0xb23d0058c7bf6a497efae996bb6cffe1ebd84e7da8d63e0e2046f6c42a851cfg,
+        sum: 0x29ee1576d0d046fa6bf6524cd3a85ed89a24ae36d601cd09c4bf88c55f4871e5g,
+        difference: 0x13a6756bb7d859b13c16f519fc3abedc5ca9a46720e7054800b6a9ecd9f7ce47g,
+        product: 0x3ed367ddba1549fa2fdda91ae0d0b3de890340dae7583d24e2b60235faf1deb8g,
+        neg: 0x2135ba8ebbabafaa2bf95c4c980e7125a6df6fad0e203fb4a98bd1c7e35fdfebg,
+    }
+}
+
+curve_ops_tests! {
+    bls12381: __zkllvm_curve_bls12381, __zkllvm_field_bls12381_base, __zkllvm_field_bls12381_scalar {
+        scalar: 0x190cf62a5d808ce41b694b3fe95beae91da7d707ab5df0ef8f133fad6ef1d8ecg,
+        double: (0x572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4eg, 0x166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28g),
+        triple: (0x9ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224g, 0x32b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1g),
+        mul: (0xc80359ce9c19642f6eba98703ece617b30aa6b7b44e3cfc1942ab3c29b1abc5f2c5470ed5f43159dcc9c109c0c3171ag, 0x1074b1e5e62e173c2835fd1bfe17bd6c85061628f306903872fc9467513fb5a1c9eef73129981fa4707aa44593facc1dg),
+        div: (0x997a6c1d3273ca43874a465416057e9f1d15dbef84e0a81f0d7cfee4f97f1dcd2df4d74f0f5661448b7a55da37901e7g, 0xc0e843b4c9999154fc12ef2669fe8ef7575fadbc71f5be7a3b5a01bc50f4f7feda4a5b2a81d199e39f7750cd7863c16g),
+    }
+    curve25519: __zkllvm_curve_curve25519, __zkllvm_field_curve25519_base, __zkllvm_field_curve25519_scalar {
+        scalar: 0x5e891d09c28e67a6fb1bdf521b72949705af04532355afe822ec99ba22c2963g,
+        double: (0x36ab384c9f5a046c3d043b7d1833e7ac080d8e4515d7a45f83c5a14e2843ce0eg, 0x2260cdf3092329c21da25ee8c9a21f5697390f51643851560e5f46ae6af8a3c9g),
+        triple: (0x67ae9c4a22928f491ff4ae743edac83a6343981981624886ac62485fd3f8e25cg, 0x1267b1d177ee69aba126a18e60269ef79f16ec176724030402c3684878f5b4d4g),
+        mul: (0x770b683296232813992b9b70649f87deedde2505fd06700d785494e27aa4d3a0g, 0x48af691eaacdbd86658c2778091fadfd872b561b9186d4245040c196ad34533cg),
+        div: (0x10b9255594b6b2a91b86c7573cebca1bfe7d10d1dd42ed3b5e2bb799c73d25a3g, 0x30eb132a3071cf1ffd2a26b023f7d8f6951db2a47998f66fdf89894a0151c96eg),
+    }
+    pallas: __zkllvm_curve_pallas, __zkllvm_field_pallas_base, __zkllvm_field_pallas_scalar {
+        scalar: 0x4e9a1fc8de348a5b3744b43a47fc087446c8c53439baeffea89e39125e1f17cg,
+        double: (0x1c0000000000000000000000000000000efee2ee4411acfc1303c567b0000003g, 0x2b00000000000000000000000000000017076ec9563fb75e8aea5cdf3bfffffcg),
+        triple: (0x8e7566fbaa967edb84c45a7474edf4cfff647de5af5fc5cb7f08a3beb32d263g, 0x301d0a4cc182e0f43897d34a1f5ef0cbc7c89e18de142df1187ffb7b17eb87c5g),
+        mul: (0x274905937d101bf01e892eadad1682b9e628e49bbb6a9fe57274fc42ff5d68a5g, 0xec7fff2c2d836c7881c7901d5bf7e08f4820227a8b427c3f8482df5e38a5863g),
+        div: (0x6f2c65cd564c6a6d966ddc12580c17cd82a10cac490da3f56783fb75745b850g, 0xf90df678252a9df75f9f9968cdc55eb6a3f9c6d002db059c0968a8a4407f83fg),
+    }
+    vesta: __zkllvm_curve_vesta, __zkllvm_field_pallas_scalar, __zkllvm_field_pallas_base {
+        scalar: 0x3bd3a2049bea3c000c7e99df43c7c9adf295ff7f55295bff7a0fe46fb54dd62fg,
+        double: (0x1c0000000000000000000000000000000efee2ee443109e0ed5f06de70000003g, 0x2b00000000000000000000000000000017076ec9566fe174da3fa5fa2bfffffcg),
+        triple: (0x377879a8395c9513c6f41a28d0a526b02402e1bada0d56155aee6feb6f55ce5fg, 0x2006adf2119a4d16713baebef70862477b45d94acebc2a59e7bd83fc4ae53086g),
+        mul: (0x76040845147fd086938a6a098a133c65690c99bd86a928b96d1147982cf3e81g, 0x2b673ecb1cc395480d32f654fc94be95314d0a2d33cb8779a0d17930347fd46dg),
+        div: (0x1d0f5f97c5a6510e7d2679a3ca36cb8ac23bdfea0f721f975e86f699b6166f90g, 0x15b9e129be1fcbd78a2ddedb03c520c5f84507409bfe9a852b291e381daca099g),
+    }
+}
@@ -1,4 +1,5 @@
 mod control_flow;
+mod zk;
 
 use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use core::ops::{Deref, DerefMut};
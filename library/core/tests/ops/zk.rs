@@ -0,0 +1,88 @@
+//! The zk traits, used through generic code only.
+
+use core::hint::black_box;
+use core::ops::{CurveGroup, Field, PrimeField};
+
+/// Evaluates `1 + x + ... + x^(n - 1)` with Horner's method.
+fn geometric_sum<F: Field>(x: F, n: usize) -> F {
+    let mut acc = F::ZERO;
+    for _ in 0..n {
+        acc = acc * x + F::ONE;
+    }
+    acc
+}
+
+fn check_field<F: Field>(a: F, b: F) {
+    assert!(F::ZERO.is_zero());
+    assert!(!F::ONE.is_zero());
+    assert_eq!(a + F::ZERO, a);
+    assert_eq!(a * F::ONE, a);
+    assert_eq!(a - a, F::ZERO);
+    assert_eq!(-a + a, F::ZERO);
+    assert_eq!(a.square(), a * a);
+    assert_eq!(a.double(), a + a);
+    assert_eq!(a.pow(3), a * a * a);
+    assert_eq!(a.inverse().map(|inv| inv * a), Some(F::ONE));
+    assert_eq!(F::ZERO.inverse(), None);
+    assert_eq!(a * b / b, a);
+
+    let mut c = a;
+    c += b;
+    c *= b;
+    c -= a;
+    c /= b;
+    assert_eq!(c, (a + b) * b / b - a / b);
+
+    // `(x^n - 1) / (x - 1)` is the geometric sum, for any `x` other than one.
+    assert_eq!(geometric_sum(a, 5) * (a - F::ONE), a.pow(5) - F::ONE);
+}
+
+fn check_prime_field<F: PrimeField>(a: F) {
+    check_field(a, F::from(3u64));
+    assert_eq!(F::from_le_bytes(a.to_le_bytes()), Some(a));
+    assert_eq!(F::from_be_bytes(a.to_be_bytes()), Some(a));
+    assert_eq!(F::from_le_bytes(F::MODULUS), None);
+    assert_eq!(F::from(u64::MAX), F::from(u128::from(u64::MAX)));
+
+    // The modulus has exactly `BITS` significant bits.
+    let modulus = F::MODULUS;
+    let modulus = modulus.as_ref();
+    let top = modulus.iter().rposition(|&byte| byte != 0).unwrap();
+    let bits = 8 * top as u32 + (8 - modulus[top].leading_zeros());
+    assert_eq!(bits, F::BITS);
+}
+
+fn check_curve_group<C: CurveGroup>(k: C::Scalar) {
+    let g = black_box(C::generator());
+    let zero = C::zero();
+    assert_ne!(g, zero);
+    assert_eq!(g + zero, g);
+    assert_eq!(g - g, zero);
+    assert_eq!(-g + g, zero);
+    assert_eq!(g * C::Scalar::from(2u64), g + g);
+    assert_eq!(g * (k + C::Scalar::ONE), g * k + g);
+
+    let mut p = g;
+    p += g;
+    p *= k;
+    p -= g * k;
+    assert_eq!(p, g * k);
+}
+
+#[test]
+fn fields() {
+    check_prime_field(black_box::<__zkllvm_field_bls12381_base>(0x1234567890abcdefg));
+    check_prime_field(black_box::<__zkllvm_field_bls12381_scalar>(0x1234567890abcdefg));
+    check_prime_field(black_box::<__zkllvm_field_curve25519_base>(0x1234567890abcdefg));
+    check_prime_field(black_box::<__zkllvm_field_curve25519_scalar>(0x1234567890abcdefg));
+    check_prime_field(black_box::<__zkllvm_field_pallas_base>(0x1234567890abcdefg));
+    check_prime_field(black_box::<__zkllvm_field_pallas_scalar>(0x1234567890abcdefg));
+}
+
+#[test]
+fn curve_groups() {
+    check_curve_group::<__zkllvm_curve_bls12381>(black_box(0x1234567890abcdefg));
+    check_curve_group::<__zkllvm_curve_curve25519>(black_box(0x1234567890abcdefg));
+    check_curve_group::<__zkllvm_curve_pallas>(black_box(0x1234567890abcdefg));
+    check_curve_group::<__zkllvm_curve_vesta>(black_box(0x1234567890abcdefg));
+}
@@ -0,0 +1,244 @@
+use core::intrinsics::{
+    assigner_ecdsa_verify, assigner_poseidon_bls12381_scalar, assigner_poseidon_pallas_base,
+    assigner_sha2_256_compress,
+};
+use core::zk::{ed25519_verify, sha256};
+
+fn hex<const N: usize>(s: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+    assert_eq!(s.len(), 2 * N);
+    for (byte, i) in bytes.iter_mut().zip((0..s.len()).step_by(2)) {
+        *byte = u8::from_str_radix(&s[i..i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+#[test]
+fn sha256_fips180_4() {
+    // The lengths around the padding boundaries: 55 bytes still fit the length field into
+    // the last block, 56 bytes do not, and 64 bytes are hashed by `assigner_sha2_256`.
+    let counting: [u8; 65] = core::array::from_fn(|i| i as u8);
+    let vectors: [(&[u8], &str); 6] = [
+        (b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        (b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        (&[b'a'; 55], "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+        (&counting[..64], "fdeab9acf3710362bd2658cdc9a29e8f9c757fcf9811603a8c447cd1d9151108"),
+        (&counting, "4bfd2c8b6f1eec7a2afeb48b934ee4b2694182027e6d0fc075074f2fabb31781"),
+    ];
+    for (message, digest) in vectors {
+        assert_eq!(sha256(message), hex(digest), "{} bytes", message.len());
+    }
+}
+
+#[test]
+fn sha256_64_bytes_matches_chained_compression() {
+    type PallasBase = __zkllvm_field_pallas_base;
+    let pack = |bytes: &[u8]| -> [PallasBase; 4] {
+        core::array::from_fn(|i| {
+            u128::from_be_bytes(bytes[16 * i..16 * (i + 1)].try_into().unwrap()) as PallasBase
+        })
+    };
+    let message: [u8; 64] = core::array::from_fn(|i| (i * 7) as u8);
+    let mut padding = [0; 64];
+    padding[0] = 0x80;
+    padding[56..].copy_from_slice(&512u64.to_be_bytes());
+
+    let mut state = [0x6a09e667bb67ae853c6ef372a54ff53ag, 0x510e527f9b05688c1f83d9ab5be0cd19g];
+    for block in [message, padding] {
+        state = assigner_sha2_256_compress(state, pack(&block));
+    }
+    let mut chained = [0; 32];
+    for (bytes, value) in chained.chunks_exact_mut(16).zip(state) {
+        bytes.copy_from_slice(&value.truncate_to_u128().to_be_bytes());
+    }
+    assert_eq!(sha256(&message), chained);
+}
+
+// Test vectors from RFC 8032, section 7.1.
+const RFC8032: [(&str, &[u8], &str); 3] = [
+    (
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        b"",
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+         5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    ),
+    (
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        b"\x72",
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+         085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+    ),
+    (
+        "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        b"\xaf\x82",
+        "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac\
+         18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+    ),
+];
+
+#[test]
+fn ed25519_rfc8032() {
+    for (public_key, message, signature) in RFC8032 {
+        assert!(ed25519_verify(&hex(public_key), message, &hex(signature)));
+    }
+}
+
+#[test]
+fn ed25519_64_byte_message() {
+    // Signed with the secret key of RFC 8032, test 3, and hashed by `assigner_sha2_512`.
+    let public_key = hex("fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025");
+    let mut message = [0; 64];
+    for (i, byte) in message.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let signature = hex(
+        "c7e0ff26d1d24bb61a5ce86aeb8a12438f2332e388842db63cccf86d6e334114\
+         d2ad120d14f47f7afe3110e8c9ba00d79ba40cfcf6b4741c212ecdb387444006",
+    );
+    assert!(ed25519_verify(&public_key, &message, &signature));
+    message[0] ^= 1;
+    assert!(!ed25519_verify(&public_key, &message, &signature));
+}
+
+#[test]
+fn ed25519_rejects_tampered() {
+    let (public_key, message, signature) = RFC8032[2];
+    let (public_key, signature) = (hex(public_key), hex::<64>(signature));
+    assert!(!ed25519_verify(&public_key, b"\xaf\x83", &signature));
+
+    let mut bad_r = signature;
+    bad_r[0] ^= 1;
+    assert!(!ed25519_verify(&public_key, message, &bad_r));
+
+    // `S` must be less than the group order.
+    let mut bad_s = signature;
+    bad_s[63] |= 0xf0;
+    assert!(!ed25519_verify(&public_key, message, &bad_s));
+
+    let mut bad_key = public_key;
+    bad_key[0] ^= 1;
+    assert!(!ed25519_verify(&bad_key, message, &signature));
+}
+
+type Secp256k1 = __zkllvm_curve_secp256k1;
+type Secp256k1Base = __zkllvm_field_secp256k1_base;
+type Secp256k1Scalar = __zkllvm_field_secp256k1_scalar;
+
+// Signatures of SHA-256 digests, reduced to the scalar field, made with OpenSSL. The first
+// secret key is the one of RFC 6979, appendix A.2.5, the second is 1.
+const SECP256K1_ECDSA: [(
+    Secp256k1Base,
+    Secp256k1Base,
+    Secp256k1Scalar,
+    Secp256k1Scalar,
+    Secp256k1Scalar,
+); 2] = [
+    (
+        0x2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645g,
+        0x64b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085g,
+        // SHA-256 of `sample`.
+        0xaf2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bfg,
+        0x71541b6fa151bbbe400bdab108fc5d5aa21f05368a523d7c35294eeed71e5378g,
+        0x6ecd4f70cd3661dea9b795e4f2684577b2a6ced010ed4d7b0db325349977c535g,
+    ),
+    (
+        0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798g,
+        0x483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8g,
+        // SHA-256 of `test`.
+        0x9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08g,
+        0x5c4dd652157754a7e13a4459e2f228599d404c159fc5fcbc6c83975c04954e03g,
+        0x7e32c2a8c102c4a47ed3961543f38494ca462ffa4fc4fbd6e3b03334a288674eg,
+    ),
+];
+
+#[test]
+fn ecdsa_secp256k1() {
+    for (x, y, hash, r, s) in SECP256K1_ECDSA {
+        let key = Secp256k1::try_from_coordinates(x, y).unwrap();
+        assert!(assigner_ecdsa_verify(key, hash, r, s));
+    }
+}
+
+#[test]
+fn ecdsa_secp256k1_rejects_tampered() {
+    let (x, y, hash, r, s) = SECP256K1_ECDSA[0];
+    let key = Secp256k1::try_from_coordinates(x, y).unwrap();
+    let one = Secp256k1Scalar::ONE;
+    assert!(!assigner_ecdsa_verify(key, hash + one, r, s));
+    assert!(!assigner_ecdsa_verify(key, hash, r + one, s));
+    assert!(!assigner_ecdsa_verify(key, hash, r, s + one));
+    assert!(!assigner_ecdsa_verify(key, hash, Secp256k1Scalar::ZERO, s));
+    assert!(!assigner_ecdsa_verify(key, hash, r, Secp256k1Scalar::ZERO));
+    assert!(!assigner_ecdsa_verify(Secp256k1::one(), hash, r, s));
+    assert!(!assigner_ecdsa_verify(Secp256k1::zero(), hash, r, s));
+
+    // A valid signature of another message.
+    let (_, _, other_hash, other_r, other_s) = SECP256K1_ECDSA[1];
+    assert!(!assigner_ecdsa_verify(key, other_hash, other_r, other_s));
+}
+
+// Hashes computed with the reference implementation of the paper at the parameters of the
+// `llvm.assigner.poseidon` gadget, which over the BLS12-381 scalar field is the `x5_255_3`
+// instance of the reference test vectors.
+#[test]
+fn poseidon_pallas_base() {
+    assert_eq!(
+        assigner_poseidon_pallas_base(&[]),
+        0x1ee22ea23ae9592efaa919c0c0f83e05fe0b659e21d767a28c875565069dd14cg
+    );
+    assert_eq!(
+        assigner_poseidon_pallas_base(&[0x1g, 0x2g]),
+        0x00fd60ab97ca5e873f011657a1f015eaf89a05cdccbd6c0b93a280827ca38b62g
+    );
+    assert_eq!(
+        assigner_poseidon_pallas_base(&[0x1g, 0x2g, 0x3g]),
+        0x307ae75f814c6694e9ad1229a9aef5aad5b46d3473fef2ec84218bad144228b8g
+    );
+}
+
+#[test]
+fn poseidon_bls12381_scalar() {
+    assert_eq!(
+        assigner_poseidon_bls12381_scalar(&[]),
+        0x10a9e48afc92bd4669b3a8c08c8c99d4144632da67c6cb9bb19cc8facaf8ed3eg
+    );
+    assert_eq!(
+        assigner_poseidon_bls12381_scalar(&[0x1g, 0x2g]),
+        0x261c25cf30c9266202d48adb9663a74b76592aa5a0d32fe88c57776958606ddag
+    );
+    assert_eq!(
+        assigner_poseidon_bls12381_scalar(&[0x1g, 0x2g, 0x3g]),
+        0x3838a0c7f2129f7004dbb01109172bc1af03c0bf619ba6e545c041821025f129g
+    );
+}
+
+#[test]
+fn msm_matches_naive_sum() {
+    type Scalar = __zkllvm_field_pallas_scalar;
+    let g = __zkllvm_curve_pallas::one();
+    let points: [_; 20] = core::array::from_fn(|i| g * Scalar::from(3 * i as u64 + 1));
+    // Spread the scalars over all the bits of the field.
+    let scalars: [_; 20] =
+        core::array::from_fn(|i| Scalar::from(u128::MAX - i as u128) * Scalar::from(u128::MAX / 3));
+    let mut expected = __zkllvm_curve_pallas::zero();
+    for (&point, &scalar) in points.iter().zip(&scalars) {
+        expected += point * scalar;
+    }
+    assert_eq!(core::intrinsics::assigner_msm(&points, &scalars), expected);
+    assert_eq!(__zkllvm_curve_pallas::msm(&[], &[]), __zkllvm_curve_pallas::zero());
+}
+
+#[test]
+fn msm_spans_chunks() {
+    // More points than the fallback converts to bytes at a time.
+    type Scalar = __zkllvm_field_pallas_scalar;
+    let g = __zkllvm_curve_pallas::one();
+    let points: [_; 300] = core::array::from_fn(|i| g * Scalar::from(i as u64 + 1));
+    let scalars: [_; 300] = core::array::from_fn(|i| Scalar::from(i as u64 + 1));
+    // The sum of the squares of `1..=300`.
+    assert_eq!(__zkllvm_curve_pallas::msm(&points, &scalars), g * Scalar::from(9045050u64));
+}
@@ -1716,15 +1716,23 @@ pub(crate) enum PrimitiveType {
     F32,
     F64,
     Bls12381,
+    Bn254,
+    Secp256k1,
     Curve25519,
     Pallas,
     Vesta,
+    Bls12381G2,
     Bls12381Base,
     Bls12381Scalar,
+    Bn254Base,
+    Bn254Scalar,
+    Secp256k1Base,
+    Secp256k1Scalar,
     Curve25519Base,
     Curve25519Scalar,
     PallasBase,
     PallasScalar,
+    Bls12381Gt,
     Char,
     Bool,
     Str,
@@ -1757,14 +1765,22 @@ pub(crate) fn from_hir(prim: hir::PrimTy) -> PrimitiveType {
             hir::PrimTy::Uint(UintTy::U128) => PrimitiveType::U128,
             hir::PrimTy::Field(FieldTy::Bls12381Base) => PrimitiveType::Bls12381Base,
             hir::PrimTy::Field(FieldTy::Bls12381Scalar) => PrimitiveType::Bls12381Scalar,
+            hir::PrimTy::Field(FieldTy::Bn254Base) => PrimitiveType::Bn254Base,
+            hir::PrimTy::Field(FieldTy::Bn254Scalar) => PrimitiveType::Bn254Scalar,
+            hir::PrimTy::Field(FieldTy::Secp256k1Base) => PrimitiveType::Secp256k1Base,
+            hir::PrimTy::Field(FieldTy::Secp256k1Scalar) => PrimitiveType::Secp256k1Scalar,
             hir::PrimTy::Field(FieldTy::Curve25519Base) => PrimitiveType::Curve25519Base,
             hir::PrimTy::Field(FieldTy::Curve25519Scalar) => PrimitiveType::Curve25519Scalar,
             hir::PrimTy::Field(FieldTy::PallasBase) => PrimitiveType::PallasBase,
             hir::PrimTy::Field(FieldTy::PallasScalar) => PrimitiveType::PallasScalar,
+            hir::PrimTy::Field(FieldTy::Bls12381Gt) => PrimitiveType::Bls12381Gt,
             hir::PrimTy::Curve(CurveTy::Bls12381) => PrimitiveType::Bls12381,
+            hir::PrimTy::Curve(CurveTy::Bn254) => PrimitiveType::Bn254,
+            hir::PrimTy::Curve(CurveTy::Secp256k1) => PrimitiveType::Secp256k1,
             hir::PrimTy::Curve(CurveTy::Curve25519) => PrimitiveType::Curve25519,
             hir::PrimTy::Curve(CurveTy::Pallas) => PrimitiveType::Pallas,
             hir::PrimTy::Curve(CurveTy::Vesta) => PrimitiveType::Vesta,
+            hir::PrimTy::Curve(CurveTy::Bls12381G2) => PrimitiveType::Bls12381G2,
             hir::PrimTy::Float(FloatTy::F32) => PrimitiveType::F32,
             hir::PrimTy::Float(FloatTy::F64) => PrimitiveType::F64,
             hir::PrimTy::Str => PrimitiveType::Str,
@@ -1776,15 +1792,23 @@ pub(crate) fn from_hir(prim: hir::PrimTy) -> PrimitiveType {
     pub(crate) fn from_symbol(s: Symbol) -> Option<PrimitiveType> {
         match s {
             sym::__zkllvm_curve_bls12381 => Some(PrimitiveType::Bls12381),
+            sym::__zkllvm_curve_bn254 => Some(PrimitiveType::Bn254),
+            sym::__zkllvm_curve_secp256k1 => Some(PrimitiveType::Secp256k1),
             sym::__zkllvm_curve_curve25519 => Some(PrimitiveType::Curve25519),
             sym::__zkllvm_curve_pallas => Some(PrimitiveType::Pallas),
             sym::__zkllvm_curve_vesta => Some(PrimitiveType::Vesta),
+            sym::__zkllvm_curve_bls12381_g2 => Some(PrimitiveType::Bls12381G2),
             sym::__zkllvm_field_bls12381_base => Some(PrimitiveType::Bls12381Base),
             sym::__zkllvm_field_bls12381_scalar => Some(PrimitiveType::Bls12381Scalar),
+            sym::__zkllvm_field_bn254_base => Some(PrimitiveType::Bn254Base),
+            sym::__zkllvm_field_bn254_scalar => Some(PrimitiveType::Bn254Scalar),
+            sym::__zkllvm_field_secp256k1_base => Some(PrimitiveType::Secp256k1Base),
+            sym::__zkllvm_field_secp256k1_scalar => Some(PrimitiveType::Secp256k1Scalar),
             sym::__zkllvm_field_curve25519_base => Some(PrimitiveType::Curve25519Base),
             sym::__zkllvm_field_curve25519_scalar => Some(PrimitiveType::Curve25519Scalar),
             sym::__zkllvm_field_pallas_base => Some(PrimitiveType::PallasBase),
             sym::__zkllvm_field_pallas_scalar => Some(PrimitiveType::PallasScalar),
+            sym::__zkllvm_field_bls12381_gt => Some(PrimitiveType::Bls12381Gt),
             sym::isize => Some(PrimitiveType::Isize),
             sym::i8 => Some(PrimitiveType::I8),
             sym::i16 => Some(PrimitiveType::I16),
@@ -1838,15 +1862,23 @@ pub(crate) fn simplified_types() -> &'static SimplifiedTypes {
                 F32 => single(SimplifiedType::Float(FloatTy::F32)),
                 F64 => single(SimplifiedType::Float(FloatTy::F64)),
                 Bls12381 => single(SimplifiedType::Curve(CurveTy::Bls12381)),
+                Bn254 => single(SimplifiedType::Curve(CurveTy::Bn254)),
+                Secp256k1 => single(SimplifiedType::Curve(CurveTy::Secp256k1)),
                 Curve25519 => single(SimplifiedType::Curve(CurveTy::Curve25519)),
                 Pallas => single(SimplifiedType::Curve(CurveTy::Pallas)),
                 Vesta => single(SimplifiedType::Curve(CurveTy::Vesta)),
+                Bls12381G2 => single(SimplifiedType::Curve(CurveTy::Bls12381G2)),
                 Bls12381Base => single(SimplifiedType::Field(FieldTy::Bls12381Base)),
                 Bls12381Scalar => single(SimplifiedType::Field(FieldTy::Bls12381Scalar)),
+                Bn254Base => single(SimplifiedType::Field(FieldTy::Bn254Base)),
+                Bn254Scalar => single(SimplifiedType::Field(FieldTy::Bn254Scalar)),
+                Secp256k1Base => single(SimplifiedType::Field(FieldTy::Secp256k1Base)),
+                Secp256k1Scalar => single(SimplifiedType::Field(FieldTy::Secp256k1Scalar)),
                 Curve25519Base => single(SimplifiedType::Field(FieldTy::Curve25519Base)),
                 Curve25519Scalar => single(SimplifiedType::Field(FieldTy::Curve25519Scalar)),
                 PallasBase => single(SimplifiedType::Field(FieldTy::PallasBase)),
                 PallasScalar => single(SimplifiedType::Field(FieldTy::PallasScalar)),
+                Bls12381Gt => single(SimplifiedType::Field(FieldTy::Bls12381Gt)),
 
                 Str => single(SimplifiedType::Str),
                 Bool => single(SimplifiedType::Bool),
@@ -1916,15 +1948,23 @@ pub(crate) fn as_sym(&self) -> Symbol {
             Fn => kw::Fn,
             Never => sym::never,
             Bls12381 => sym::__zkllvm_curve_bls12381,
+            Bn254 => sym::__zkllvm_curve_bn254,
+            Secp256k1 => sym::__zkllvm_curve_secp256k1,
             Curve25519 => sym::__zkllvm_curve_curve25519,
             Pallas => sym::__zkllvm_curve_pallas,
             Vesta => sym::__zkllvm_curve_vesta,
+            Bls12381G2 => sym::__zkllvm_curve_bls12381_g2,
             Bls12381Base => sym::__zkllvm_field_bls12381_base,
             Bls12381Scalar => sym::__zkllvm_field_bls12381_scalar,
+            Bn254Base => sym::__zkllvm_field_bn254_base,
+            Bn254Scalar => sym::__zkllvm_field_bn254_scalar,
+            Secp256k1Base => sym::__zkllvm_field_secp256k1_base,
+            Secp256k1Scalar => sym::__zkllvm_field_secp256k1_scalar,
             Curve25519Base => sym::__zkllvm_field_curve25519_base,
             Curve25519Scalar => sym::__zkllvm_field_curve25519_scalar,
             PallasBase => sym::__zkllvm_field_pallas_base,
             PallasScalar => sym::__zkllvm_field_pallas_scalar,
+            Bls12381Gt => sym::__zkllvm_field_bls12381_gt,
         }
     }
 
@@ -2006,10 +2046,15 @@ fn from(field_ty: ast::FieldTy) -> PrimitiveType {
         match field_ty {
             ast::FieldTy::Bls12381Base => PrimitiveType::Bls12381Base,
             ast::FieldTy::Bls12381Scalar => PrimitiveType::Bls12381Scalar,
+            ast::FieldTy::Bn254Base => PrimitiveType::Bn254Base,
+            ast::FieldTy::Bn254Scalar => PrimitiveType::Bn254Scalar,
+            ast::FieldTy::Secp256k1Base => PrimitiveType::Secp256k1Base,
+            ast::FieldTy::Secp256k1Scalar => PrimitiveType::Secp256k1Scalar,
             ast::FieldTy::Curve25519Base => PrimitiveType::Curve25519Base,
             ast::FieldTy::Curve25519Scalar => PrimitiveType::Curve25519Scalar,
             ast::FieldTy::PallasBase => PrimitiveType::PallasBase,
             ast::FieldTy::PallasScalar => PrimitiveType::PallasScalar,
+            ast::FieldTy::Bls12381Gt => PrimitiveType::Bls12381Gt,
         }
     }
 }
@@ -2018,9 +2063,12 @@ impl From<ast::CurveTy> for PrimitiveType {
     fn from(curve_ty: ast::CurveTy) -> PrimitiveType {
         match curve_ty {
             ast::CurveTy::Bls12381 => PrimitiveType::Bls12381,
+            ast::CurveTy::Bn254 => PrimitiveType::Bn254,
+            ast::CurveTy::Secp256k1 => PrimitiveType::Secp256k1,
             ast::CurveTy::Curve25519 => PrimitiveType::Curve25519,
             ast::CurveTy::Pallas => PrimitiveType::Pallas,
             ast::CurveTy::Vesta => PrimitiveType::Vesta,
+            ast::CurveTy::Bls12381G2 => PrimitiveType::Bls12381G2,
         }
     }
 }
@@ -2065,10 +2113,15 @@ fn from(field_ty: ty::FieldTy) -> PrimitiveType {
         match field_ty {
             ty::FieldTy::Bls12381Base => PrimitiveType::Bls12381Base,
             ty::FieldTy::Bls12381Scalar => PrimitiveType::Bls12381Scalar,
+            ty::FieldTy::Bn254Base => PrimitiveType::Bn254Base,
+            ty::FieldTy::Bn254Scalar => PrimitiveType::Bn254Scalar,
+            ty::FieldTy::Secp256k1Base => PrimitiveType::Secp256k1Base,
+            ty::FieldTy::Secp256k1Scalar => PrimitiveType::Secp256k1Scalar,
             ty::FieldTy::Curve25519Base => PrimitiveType::Curve25519Base,
             ty::FieldTy::Curve25519Scalar => PrimitiveType::Curve25519Scalar,
             ty::FieldTy::PallasBase => PrimitiveType::PallasBase,
             ty::FieldTy::PallasScalar => PrimitiveType::PallasScalar,
+            ty::FieldTy::Bls12381Gt => PrimitiveType::Bls12381Gt,
         }
     }
 }
@@ -2077,9 +2130,12 @@ impl From<ty::CurveTy> for PrimitiveType {
     fn from(curve_ty: ty::CurveTy) -> PrimitiveType {
         match curve_ty {
             ty::CurveTy::Bls12381 => PrimitiveType::Bls12381,
+            ty::CurveTy::Bn254 => PrimitiveType::Bn254,
+            ty::CurveTy::Secp256k1 => PrimitiveType::Secp256k1,
             ty::CurveTy::Curve25519 => PrimitiveType::Curve25519,
             ty::CurveTy::Pallas => PrimitiveType::Pallas,
             ty::CurveTy::Vesta => PrimitiveType::Vesta,
+            ty::CurveTy::Bls12381G2 => PrimitiveType::Bls12381G2,
         }
     }
 }
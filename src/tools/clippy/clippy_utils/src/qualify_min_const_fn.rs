@@ -116,6 +116,7 @@ fn check_rvalue<'tcx>(
             | CastKind::FloatToInt
             | CastKind::IntToFloat
             | CastKind::FloatToFloat
+            | CastKind::IntToField
             | CastKind::FnPtrToPtr
             | CastKind::PtrToPtr
             | CastKind::PointerCoercion(PointerCoercion::MutToConstPointer | PointerCoercion::ArrayToPointer),
@@ -67,9 +67,8 @@ fn visit_tags(&self, visit: &mut dyn FnMut(BorTag)) {
                 s1.visit_tags(visit);
                 s2.visit_tags(visit);
             }
-            Immediate::Uninit => {}
-            // TODO: (aleasims) handle field types
-            Immediate::Field(_) => todo!("support field immediates in miri"),
+            // Field values are plain numbers and never carry provenance.
+            Immediate::Uninit | Immediate::Field(_) => {}
         }
     }
 }
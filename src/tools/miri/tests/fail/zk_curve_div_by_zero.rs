@@ -0,0 +1,8 @@
+// Dividing a point by a scalar multiplies it by the scalar's inverse, which is not
+// checked for zero the way field division is.
+
+fn main() {
+    let p = __zkllvm_curve_pallas::one();
+    let zero: __zkllvm_field_pallas_scalar = 0g;
+    let _q = p / zero; //~ERROR: dividing by zero
+}
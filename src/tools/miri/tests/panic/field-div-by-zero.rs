@@ -0,0 +1,6 @@
+#![allow(unconditional_panic)]
+
+fn main() {
+    let zero: __zkllvm_field_pallas_base = 0g;
+    let _n = 1g / zero;
+}
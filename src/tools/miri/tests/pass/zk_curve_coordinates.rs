@@ -0,0 +1,16 @@
+use std::hint::black_box;
+
+type Pallas = __zkllvm_curve_pallas;
+type Ed25519 = __zkllvm_curve_curve25519;
+
+fn main() {
+    let g = Pallas::one();
+    assert_eq!(Pallas::try_from_coordinates(black_box(g.x()), black_box(g.y())), Some(g));
+    assert_eq!(Pallas::try_from_coordinates(black_box(g.x()), black_box(g.y() + 1g)), None);
+    assert_eq!(Pallas::try_from_coordinates(black_box(0g), black_box(1g)), Some(Pallas::zero()));
+
+    let g = Ed25519::one().double();
+    assert_eq!(Ed25519::try_from_coordinates(black_box(g.x()), black_box(g.y())), Some(g));
+    assert_eq!(Ed25519::try_from_coordinates(black_box(g.x() + 1g), black_box(g.y())), None);
+    assert_eq!(Ed25519::try_from_coordinates(black_box(0g), black_box(1g)), Some(Ed25519::zero()));
+}
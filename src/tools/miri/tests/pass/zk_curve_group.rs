@@ -0,0 +1,15 @@
+type C = __zkllvm_curve_pallas;
+
+fn main() {
+    let g = C::one();
+    let zero = C::zero();
+
+    assert!(zero.is_zero());
+    assert!(!g.is_zero());
+    assert_eq!(g.double(), g + g);
+    assert_eq!(-g.double(), -g - g);
+
+    assert_eq!(zero.to_affine(), (0g, 1g));
+    assert_eq!(g.to_affine(), (g.x(), g.y()));
+    assert_eq!(g.y(), 2g);
+}
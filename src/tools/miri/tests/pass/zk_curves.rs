@@ -0,0 +1,16 @@
+type C = __zkllvm_curve_pallas;
+type S = __zkllvm_field_pallas_scalar;
+
+fn main() {
+    let g = C::one();
+    let zero = C::zero();
+    let three: S = 3g;
+
+    assert_eq!(g + zero, g);
+    assert_eq!(g - g, zero);
+    assert_eq!(-g + g, zero);
+    assert_eq!(g * three, g + g + g);
+    assert_eq!(three * g, g * three);
+    assert_eq!(g * three / three, g);
+    assert_ne!(g, zero);
+}
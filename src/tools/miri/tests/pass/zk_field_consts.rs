@@ -0,0 +1,16 @@
+type F = __zkllvm_field_pallas_base;
+
+fn main() {
+    assert_eq!(F::ZERO, 0g);
+    assert_eq!(F::ONE, 1g);
+    assert_eq!(F::ONE + F::ZERO, F::ONE);
+    assert_eq!(F::BITS, 255);
+
+    let a: F = 0x1234g;
+    let mut bytes = [0; 32];
+    bytes[0] = 0x34;
+    bytes[1] = 0x12;
+    assert_eq!(a.to_le_bytes(), bytes);
+    assert_eq!(F::from_le_bytes(bytes), Some(a));
+    assert_eq!(F::from_le_bytes(F::MODULUS), None);
+}
@@ -0,0 +1,16 @@
+type F = __zkllvm_field_pallas_base;
+
+fn main() {
+    let a: F = 3g;
+    let b: F = 5g;
+    let zero: F = 0g;
+    let one: F = 1g;
+
+    assert_eq!(a / b * b, a);
+    assert_eq!(a * a.inverse().unwrap(), one);
+    assert_eq!(zero.inverse(), None);
+
+    assert_eq!(a.pow(4), 81g);
+    assert_eq!(a.pow(0), one);
+    assert_eq!(zero.pow(0), one);
+}
@@ -0,0 +1,22 @@
+type F = __zkllvm_field_pallas_base;
+
+fn main() {
+    let a: F = 3g;
+    let b: F = 5g;
+    let zero: F = 0g;
+    let one: F = 1g;
+
+    assert_eq!(a * b + 7g, 22g);
+    assert_eq!(a - b + 2g, zero);
+    assert_eq!(-a + a, zero);
+    assert_eq!(a % b, zero);
+
+    // Arithmetic wraps around the modulus.
+    let minus_one = zero - one;
+    assert_eq!(minus_one + one, zero);
+    assert_eq!(minus_one * minus_one, one);
+
+    assert!(a < b);
+    assert!(minus_one > b);
+    assert_ne!(a, b);
+}
@@ -0,0 +1,35 @@
+// ignore-tidy-linelength
+
+// Checks that conversions between integers and field elements go through the assigner
+// intrinsics.
+
+// compile-flags: --target assigner-unknown-unknown -C no-prepopulate-passes
+// needs-llvm-components: assigner
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, intrinsics)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+extern "rust-intrinsic" {
+    fn assigner_field_to_int<F>(x: F) -> u128;
+}
+
+// CHECK-LABEL: @int_to_field
+#[no_mangle]
+pub fn int_to_field(x: u64) -> __zkllvm_field_pallas_base {
+    // CHECK: [[X:%.*]] = zext i64 %x to i128
+    // CHECK: call{{.*}} @llvm.assigner.int.to.field.__zkllvm_field_pallas_base(i128 [[X]])
+    x as __zkllvm_field_pallas_base
+}
+
+// CHECK-LABEL: @field_to_int
+#[no_mangle]
+pub fn field_to_int(x: __zkllvm_field_pallas_base) -> u128 {
+    // CHECK: call i128 @llvm.assigner.field.to.int.__zkllvm_field_pallas_base(__zkllvm_field_pallas_base %x)
+    unsafe { assigner_field_to_int(x) }
+}
@@ -0,0 +1,27 @@
+// Checks that a library marks all of its circuits for the assigner, and an executable its
+// entry point.
+
+// revisions: lib bin
+// compile-flags: --target assigner-unknown-unknown -C no-prepopulate-passes
+// [lib] compile-flags: --crate-type lib
+// [bin] compile-flags: --crate-type bin
+// needs-llvm-components: assigner
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// CHECK: define{{.*}}void @first(){{.*}} #[[CIRCUIT:[0-9]+]]
+#[circuit]
+#[no_mangle]
+pub fn first() {}
+
+// lib: define{{.*}}void @second(){{.*}} #[[CIRCUIT]]
+#[cfg(lib)]
+#[circuit]
+#[no_mangle]
+pub fn second() {}
+
+// CHECK: attributes #[[CIRCUIT]] = {{.*}} circuit
@@ -0,0 +1,25 @@
+// ignore-tidy-linelength
+
+// Checks that `#[public_input]` and `#[private_input]` reach the assigner as parameter attributes.
+
+// compile-flags: --target assigner-unknown-unknown -C no-prepopulate-passes
+// needs-llvm-components: assigner
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+// CHECK: define{{.*}}void @circuit(i32{{[^,]*}} "public_input"{{[^,]*}}, __zkllvm_field_pallas_base{{[^,]*}} "private_input"{{[^,]*}}, i32{{[^,"]*}})
+#[circuit]
+#[no_mangle]
+pub fn circuit(
+    #[public_input] a: u32,
+    #[private_input] b: __zkllvm_field_pallas_base,
+    c: u32,
+) {
+}
@@ -0,0 +1,7 @@
+// unit-test: DataflowConstProp
+
+// EMIT_MIR field.main.DataflowConstProp.diff
+fn main() {
+    let a: __zkllvm_field_pallas_base = 3g;
+    let b = a * 5g + 7g;
+}
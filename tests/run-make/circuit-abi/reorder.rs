@@ -0,0 +1,17 @@
+// Fields of Rust tuples can be reordered in memory. The circuit reads them in memory
+// order, so the ABI has to list them in that order too. `#[repr(C)]` structs keep the
+// declared order.
+
+#![crate_type = "lib"]
+
+#[repr(C)]
+pub struct S {
+    pub a: u32,
+    pub b: u32,
+    pub c: u64,
+}
+
+#[circuit]
+pub fn circuit(s: S, t: (u32, u64, u32)) -> u32 {
+    s.a + t.0
+}
@@ -0,0 +1,16 @@
+// The input templates list the fields of reordered tuples in memory order, split into
+// public and private inputs.
+
+#![crate_type = "lib"]
+
+#[repr(C)]
+pub struct T {
+    pub a: u32,
+    pub c: u32,
+    pub b: [u64; 2],
+}
+
+#[circuit]
+pub fn circuit(#[public_input] t: (u32, [u64; 2], u32), #[private_input] s: T) -> u32 {
+    t.0 + s.a
+}
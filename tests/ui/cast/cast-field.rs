@@ -0,0 +1,22 @@
+// Only unsigned integers can be cast to field elements, and field elements cannot be cast
+// to anything.
+
+type F = __zkllvm_field_pallas_base;
+
+fn main() {
+    let _ = 1u8 as F;
+    let _ = 1u64 as F;
+    let _ = u128::MAX as F;
+    // Unsuffixed literals are unsigned.
+    let _ = 1 as F;
+    let _ = 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff as F;
+
+    let f: F = 1g;
+    let _ = f as u128; //~ ERROR casting `__zkllvm_field_pallas_base` as `u128` is invalid
+    let _ = f as u8; //~ ERROR casting `__zkllvm_field_pallas_base` as `u8` is invalid
+    let _ = f as i64; //~ ERROR casting `__zkllvm_field_pallas_base` as `i64` is invalid
+    let _ = f as __zkllvm_field_pallas_scalar; //~ ERROR is invalid
+    let _ = 1i32 as F; //~ ERROR casting `i32` as `__zkllvm_field_pallas_base` is invalid
+    let _ = true as F; //~ ERROR casting `bool` as `__zkllvm_field_pallas_base` is invalid
+    let _ = 1.0f64 as F; //~ ERROR casting `f64` as `__zkllvm_field_pallas_base` is invalid
+}
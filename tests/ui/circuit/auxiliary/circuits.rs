@@ -0,0 +1,16 @@
+// compile-flags: --target assigner-unknown-unknown
+
+#![feature(no_core, lang_items)]
+#![no_core]
+#![crate_type = "lib"]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub mod hash {
+    #[circuit]
+    pub fn verify() {}
+
+    #[circuit]
+    fn private() {}
+}
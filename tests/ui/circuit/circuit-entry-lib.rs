@@ -0,0 +1,15 @@
+// Checks that `-Z circuit-entry` is ignored with a warning for libraries.
+
+// check-pass
+// compile-flags: --target assigner-unknown-unknown --crate-type=lib
+// compile-flags: -Z circuit-entry=circuit_entry_lib::circuit
+// needs-llvm-components: assigner
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[circuit]
+pub fn circuit() {}
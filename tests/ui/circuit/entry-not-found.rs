@@ -0,0 +1,15 @@
+// Checks that `-Z circuit-entry` names a public `#[circuit]` function, and that the circuits
+// of the crate and its dependencies are listed otherwise.
+
+// aux-build:circuits.rs
+// compile-flags: --target assigner-unknown-unknown -Z circuit-entry=circuits::hash::private
+// needs-llvm-components: assigner
+// error-pattern: does not name a public `#[circuit]` function
+
+#![feature(no_core)]
+#![no_core]
+
+extern crate circuits;
+
+#[circuit]
+pub fn local() {}
@@ -0,0 +1,28 @@
+// Checks where `#[public_input]` and `#[private_input]` may be used.
+
+#![crate_type = "lib"]
+
+#[circuit]
+pub fn circuit(#[public_input] a: u32, #[private_input] b: u32, c: u32) -> u32 {
+    a + b + c
+}
+
+#[public_input] //~ ERROR may only be used on parameters of a `#[circuit]` function
+pub fn not_a_param() {}
+
+pub struct S {
+    #[private_input] //~ ERROR may only be used on parameters of a `#[circuit]` function
+    pub field: u32,
+}
+
+pub fn not_a_circuit(#[public_input] a: u32, #[private_input] b: u32) -> u32 {
+    //~^ ERROR `#[public_input]` may only be used on parameters of a `#[circuit]` function
+    //~| ERROR `#[private_input]` may only be used on parameters of a `#[circuit]` function
+    a + b
+}
+
+#[circuit]
+pub fn both(#[public_input] #[private_input] a: u32) -> u32 {
+    //~^ ERROR a circuit parameter cannot be both `#[public_input]` and `#[private_input]`
+    a
+}
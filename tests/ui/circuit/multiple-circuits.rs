@@ -0,0 +1,17 @@
+// Checks that an assigner executable has a single circuit, and lists the circuits to pick
+// from with `-Z circuit-entry`.
+
+// compile-flags: --target assigner-unknown-unknown
+// needs-llvm-components: assigner
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[circuit]
+pub fn first() {}
+
+#[circuit]
+pub fn second() {} //~ ERROR multiple `circuit` functions
@@ -0,0 +1,12 @@
+// Checks that an assigner executable needs a circuit.
+
+// compile-flags: --target assigner-unknown-unknown
+// needs-llvm-components: assigner
+
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub fn not_a_circuit() {} //~ ERROR no `#[circuit]` function found in crate `no_circuit`
@@ -0,0 +1,65 @@
+// Checks that `#[circuit]` functions only take and return types the assigner can read.
+
+// compile-flags: --target assigner-unknown-unknown --crate-type=lib
+// needs-llvm-components: assigner
+
+#![feature(no_core, lang_items)]
+#![no_core]
+#![allow(unused)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+pub trait Tr {}
+
+#[repr(C)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[repr(C)]
+pub struct Padded {
+    pub a: u8,
+    pub b: u32,
+}
+
+pub struct Plain {
+    pub a: u32,
+}
+
+#[circuit]
+pub fn supported(a: u32, b: bool, c: [Point; 2], d: (u32, u32)) -> Point {
+    loop {}
+}
+
+#[circuit]
+pub fn by_ref(a: &u32) {} //~ ERROR cannot be of type `&u32`
+
+#[circuit]
+pub fn by_ref_to_unsupported(a: &Plain) {} //~ ERROR cannot be of type `&Plain`
+
+#[circuit]
+pub fn pointer(a: *const u32) {} //~ ERROR cannot be of type `*const u32`
+
+#[circuit]
+pub fn trait_object(a: &dyn Tr) {} //~ ERROR cannot be of type `&dyn Tr`
+
+#[circuit]
+pub fn float(a: [f32; 2]) {} //~ ERROR cannot be of type `[f32; 2]`
+
+#[circuit]
+pub fn padded_struct(a: Padded) {} //~ ERROR cannot be of type `Padded`
+
+#[circuit]
+pub fn padded_tuple(a: (u8, u32)) {} //~ ERROR cannot be of type `(u8, u32)`
+
+#[circuit]
+pub fn not_repr_c(a: Plain) {} //~ ERROR cannot be of type `Plain`
+
+#[circuit]
+pub fn returns_float() -> f64 { //~ ERROR return type of a `#[circuit]` function
+    loop {}
+}
@@ -0,0 +1,32 @@
+// check-pass
+// `try_from_coordinates` checks the curve equation in constants.
+
+#![feature(const_zk_curve)]
+
+type Pallas = __zkllvm_curve_pallas;
+type Ed25519 = __zkllvm_curve_curve25519;
+
+// The generator of Pallas, `y^2 = x^3 + 5`.
+const PALLAS_ON: Option<Pallas> = Pallas::try_from_coordinates(0g - 1g, 2g);
+const PALLAS_OFF: Option<Pallas> = Pallas::try_from_coordinates(1g, 1g);
+const PALLAS_ZERO: Option<Pallas> = Pallas::try_from_coordinates(0g, 1g);
+
+// Twice the base point of Curve25519, in twisted Edwards form.
+const ED25519_ON: Option<Ed25519> = Ed25519::try_from_coordinates(
+    0x36ab384c9f5a046c3d043b7d1833e7ac080d8e4515d7a45f83c5a14e2843ce0eg,
+    0x2260cdf3092329c21da25ee8c9a21f5697390f51643851560e5f46ae6af8a3c9g,
+);
+const ED25519_OFF: Option<Ed25519> = Ed25519::try_from_coordinates(
+    0x36ab384c9f5a046c3d043b7d1833e7ac080d8e4515d7a45f83c5a14e2843ce0eg,
+    0x2260cdf3092329c21da25ee8c9a21f5697390f51643851560e5f46ae6af8a3cag,
+);
+const ED25519_ZERO: Option<Ed25519> = Ed25519::try_from_coordinates(0g, 1g);
+
+const _: () = assert!(PALLAS_ON.is_some());
+const _: () = assert!(PALLAS_OFF.is_none());
+const _: () = assert!(PALLAS_ZERO.is_some());
+const _: () = assert!(ED25519_ON.is_some());
+const _: () = assert!(ED25519_OFF.is_none());
+const _: () = assert!(ED25519_ZERO.is_some());
+
+fn main() {}
@@ -0,0 +1,20 @@
+// check-pass
+// BN254 field arithmetic in constants is evaluated modulo the BN254 moduli.
+
+type Fq = __zkllvm_field_bn254_base;
+type Fr = __zkllvm_field_bn254_scalar;
+
+const P_MINUS_1: Fq = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46g;
+const R_MINUS_1: Fr = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000g;
+const INV_2: Fq = 1g / 2g;
+const INV_3: Fq = 1g / 3g;
+
+const _: () = assert!(P_MINUS_1 + 1g == 0g);
+const _: () = assert!(R_MINUS_1 + 1g == 0g);
+const _: () = assert!(P_MINUS_1 * P_MINUS_1 == 1g);
+const _: () = assert!(INV_2 == 0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea4g);
+const _: () = assert!(INV_3 == 0x2042def740cbc01bd03583cf0100e593ba56470b9af68708d2c05d6490535385g);
+const _: () = assert!(INV_3 * 3g == 1g);
+const _: () = assert!(0g - 1g == P_MINUS_1);
+
+fn main() {}
@@ -0,0 +1,15 @@
+// check-pass
+// Field arithmetic in constants is evaluated modulo the field modulus.
+
+type F = __zkllvm_field_pallas_base;
+
+const K: F = 3g * 5g + 7g;
+const NEG: F = 0g - 1g;
+const INV: F = 1g / 2g;
+
+const _: () = assert!(K == 22g);
+const _: () = assert!(NEG + 1g == 0g);
+const _: () = assert!(INV * 2g == 1g);
+const _: () = assert!(K % 3g == 0g);
+
+fn main() {}
@@ -0,0 +1,10 @@
+// Dividing a field element by zero in a constant is an error, not an ICE.
+
+type F = __zkllvm_field_pallas_base;
+
+const DIV: F = 1g / 0g;
+//~^ ERROR evaluation of constant value failed
+const REM: F = 1g % 0g;
+//~^ ERROR evaluation of constant value failed
+
+fn main() {}
@@ -0,0 +1,16 @@
+// ignore-tidy-linelength
+// BN254 field literals must be smaller than the modulus of their field.
+
+#![deny(overflowing_literals)]
+
+type Base = __zkllvm_field_bn254_base;
+type Scalar = __zkllvm_field_bn254_scalar;
+
+fn main() {
+    let _: Base = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46g;
+    let _: Base = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47g; //~ ERROR literal out of range for `__zkllvm_field_bn254_base`
+    let _: Scalar = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000g;
+    let _: Scalar = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001g; //~ ERROR literal out of range for `__zkllvm_field_bn254_scalar`
+    // The scalar field is smaller than the base field.
+    let _: Scalar = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46g; //~ ERROR literal out of range for `__zkllvm_field_bn254_scalar`
+}